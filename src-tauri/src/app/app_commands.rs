@@ -0,0 +1,102 @@
+//! 内部操作命令面板
+//!
+//! 把"打开设置某一页、重建索引、暂停剪贴板采集、检查更新、切换
+//! 配置档"这类内部能力收敛成带稳定 id 的"应用命令"：搜索框可以
+//! 搜到并执行，热键也能绑定到任意内部操作。前端纯 UI 动作（如打开
+//! 设置页）通过事件转发，后端动作直接执行。
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+/// 前端 UI 动作事件（打开设置页等）
+pub const UI_ACTION_EVENT: &str = "app://ui-action";
+
+/// 一条应用命令；id 稳定，可被热键绑定持久引用
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppCommand {
+    pub id: String,
+    pub title: String,
+    /// 额外搜索关键词（含英文别名）
+    pub keywords: Vec<String>,
+    pub category: String,
+}
+
+fn command(id: &str, title: &str, keywords: &[&str], category: &str) -> AppCommand {
+    AppCommand {
+        id: id.to_string(),
+        title: title.to_string(),
+        keywords: keywords.iter().map(|k| k.to_string()).collect(),
+        category: category.to_string(),
+    }
+}
+
+/// 内置命令目录；新增内部能力时在这里登记
+pub fn catalog() -> Vec<AppCommand> {
+    vec![
+        command("settings.open.general", "打开通用设置", &["settings", "preferences"], "设置"),
+        command("settings.open.hotkeys", "打开快捷键设置", &["hotkey", "shortcut"], "设置"),
+        command("settings.open.plugins", "打开插件管理", &["plugins", "extensions"], "设置"),
+        command("index.rebuild", "重建文件索引", &["rebuild", "index", "reindex"], "索引"),
+        command("clipboard.toggle-capture", "暂停/恢复剪贴板记录", &["clipboard", "privacy", "pause"], "剪贴板"),
+        command("updates.check", "检查插件更新", &["update", "upgrade"], "插件"),
+        command("profile.switch", "切换配置档", &["profile", "workspace"], "配置"),
+        command("database.maintenance", "数据库维护", &["vacuum", "database"], "维护"),
+        command("window.toggle", "显示/隐藏主窗口", &["toggle", "show", "hide"], "窗口"),
+    ]
+}
+
+/// 执行应用命令；带参数的命令（如切换配置档）通过 arg 传入
+#[tauri::command]
+pub async fn execute_app_command(
+    app: AppHandle,
+    id: String,
+    arg: Option<String>,
+) -> Result<(), String> {
+    log::info!("[AppCommands] execute '{}'", id);
+    match id.as_str() {
+        // 设置页导航由前端完成，后端只负责转发
+        _ if id.starts_with("settings.open.") => {
+            let _ = app.emit(UI_ACTION_EVENT, serde_json::json!({ "action": id }));
+            Ok(())
+        }
+        "index.rebuild" => {
+            let _ = app.emit(UI_ACTION_EVENT, serde_json::json!({ "action": id }));
+            Ok(())
+        }
+        "clipboard.toggle-capture" => {
+            if crate::services::privacy_session::is_recording_paused() {
+                crate::services::privacy_session::stop_privacy_session(app)
+            } else {
+                crate::services::privacy_session::start_privacy_session(app, None).map(|_| ())
+            }
+        }
+        "updates.check" => {
+            let plugins_dir = arg.ok_or("updates.check 需要插件目录参数")?;
+            crate::marketplace::update_checker::check_updates(plugins_dir)
+                .await
+                .map(|updates| {
+                    let _ = app.emit(
+                        UI_ACTION_EVENT,
+                        serde_json::json!({ "action": "updates.result", "count": updates.len() }),
+                    );
+                })
+        }
+        "profile.switch" => {
+            let name = arg.ok_or("profile.switch 需要配置档名称")?;
+            crate::services::profiles::switch_profile(app, name)
+        }
+        "database.maintenance" => crate::db::maintenance::run_database_maintenance().map(|_| ()),
+        "window.toggle" => {
+            let _ = app.emit(UI_ACTION_EVENT, serde_json::json!({ "action": id }));
+            Ok(())
+        }
+        other => Err(format!("未知的应用命令: {}", other)),
+    }
+}
+
+/// 列出全部应用命令（搜索框与热键绑定页共用）
+#[tauri::command]
+pub fn list_app_commands() -> Vec<AppCommand> {
+    catalog()
+}
@@ -0,0 +1,104 @@
+//! 全局取消令牌
+//!
+//! 索引、市场安装、下载、搜索等长耗时操作统一在这里登记一个操作 id，
+//! 前端只需调用通用的 `cancel_operation(id)`，不必为每类操作各写一个
+//! cancel 命令。被取消的操作以 `CANCELLED:` 前缀的错误串返回
+//! （约定同 fs_guard 的 `CONSENT_REQUIRED:`），前端据此静默收尾而非报错。
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// 取消错误前缀；前端用它区分"用户取消"与真实失败
+pub const CANCELLED_PREFIX: &str = "CANCELLED:";
+
+/// 构造统一的取消错误
+pub fn cancelled_err(operation_id: &str) -> String {
+    format!("{}{}", CANCELLED_PREFIX, operation_id)
+}
+
+static OPERATIONS: Lazy<Mutex<HashMap<String, Arc<AtomicBool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 取消令牌；RAII，Drop 时自动从注册表摘除
+pub struct CancelToken {
+    id: String,
+    flag: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// 登记一个操作；同 id 已在运行时返回 Err
+    pub fn register(id: &str) -> Result<Self, String> {
+        let mut ops = OPERATIONS.lock().map_err(|e| e.to_string())?;
+        if ops.contains_key(id) {
+            return Err(format!("操作 {} 已在运行", id));
+        }
+        let flag = Arc::new(AtomicBool::new(false));
+        ops.insert(id.to_string(), flag.clone());
+        Ok(Self {
+            id: id.to_string(),
+            flag,
+        })
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+
+    /// 在循环/阶段边界调用；已取消时返回统一的取消错误
+    pub fn check(&self) -> Result<(), String> {
+        if self.is_cancelled() {
+            Err(cancelled_err(&self.id))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 拿到底层标志的克隆，供已有 AtomicBool 取消逻辑（如下载循环）复用
+    pub fn flag(&self) -> Arc<AtomicBool> {
+        self.flag.clone()
+    }
+
+    /// 复用调用方已有的取消标志登记操作（下载管理器等自带标志的场景）
+    pub fn register_with_flag(id: &str, flag: Arc<AtomicBool>) -> Result<Self, String> {
+        let mut ops = OPERATIONS.lock().map_err(|e| e.to_string())?;
+        if ops.contains_key(id) {
+            return Err(format!("操作 {} 已在运行", id));
+        }
+        ops.insert(id.to_string(), flag.clone());
+        Ok(Self {
+            id: id.to_string(),
+            flag,
+        })
+    }
+}
+
+impl Drop for CancelToken {
+    fn drop(&mut self) {
+        if let Ok(mut ops) = OPERATIONS.lock() {
+            ops.remove(&self.id);
+        }
+    }
+}
+
+/// 取消一个运行中的操作；返回是否找到
+#[tauri::command]
+pub fn cancel_operation(id: String) -> Result<bool, String> {
+    let ops = OPERATIONS.lock().map_err(|e| e.to_string())?;
+    match ops.get(&id) {
+        Some(flag) => {
+            flag.store(true, Ordering::SeqCst);
+            log::info!("[Cancellation] operation '{}' cancelled", id);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// 列出当前可取消的操作 id
+#[tauri::command]
+pub fn list_cancellable_operations() -> Result<Vec<String>, String> {
+    let ops = OPERATIONS.lock().map_err(|e| e.to_string())?;
+    Ok(ops.keys().cloned().collect())
+}
@@ -0,0 +1,157 @@
+//! 事件目录
+//!
+//! 后端发往前端/插件的事件一直是散落各模块的字符串常量，插件作者只能
+//! 翻源码猜。这里给出带类型的事件目录：`AppEvent` 枚举覆盖所有事件，
+//! `as_str` 复用各模块原有常量（字符串仍以原模块为准，目录不另造一份），
+//! `emit` 包装器统一打点，`list_events` 把名称、载荷说明和引入版本
+//! 暴露给插件做能力发现。新增事件时必须同步在这里登记。
+//!
+//! 事件名按稳定性承诺遵循 semver：已登记事件的名称与载荷字段只增不改。
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+/// 全部后端事件；变体顺序与登记时间一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppEvent {
+    SearchPartial,
+    SearchDone,
+    PluginSearchRequest,
+    PluginViewUpdate,
+    PluginBusMessage,
+    PluginDeepLink,
+    PluginBackgroundTask,
+    PluginHealthChanged,
+    InstallProgress,
+    DownloadProgress,
+    FileChange,
+    ProfileSwitched,
+    TimerFinished,
+    Dictation,
+    PrivacySessionChanged,
+    UiAction,
+    Power,
+    ScreenChanged,
+    HudContent,
+    CommandBusy,
+    HotkeyFallback,
+}
+
+impl AppEvent {
+    /// 事件名；直接复用各模块的常量，保证目录与发送方不漂移
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SearchPartial => crate::search::streaming::SEARCH_PARTIAL_EVENT,
+            Self::SearchDone => crate::search::streaming::SEARCH_DONE_EVENT,
+            Self::PluginSearchRequest => crate::search::pipeline::PLUGIN_SEARCH_EVENT,
+            Self::PluginViewUpdate => crate::plugins::view_schema::PLUGIN_VIEW_EVENT,
+            Self::PluginBusMessage => crate::plugins::plugin_bus::BUS_MESSAGE_EVENT,
+            Self::PluginDeepLink => crate::plugins::deep_link::DEEP_LINK_EVENT,
+            Self::PluginBackgroundTask => crate::plugins::scheduler::BACKGROUND_TASK_EVENT,
+            Self::PluginHealthChanged => crate::plugins::health::HEALTH_CHANGED_EVENT,
+            Self::InstallProgress => crate::marketplace::commands::INSTALL_PROGRESS_EVENT,
+            Self::DownloadProgress => crate::services::download_manager::DOWNLOAD_PROGRESS_EVENT,
+            Self::FileChange => crate::services::file_watcher::FILE_CHANGE_EVENT,
+            Self::ProfileSwitched => crate::services::profiles::PROFILE_SWITCHED_EVENT,
+            Self::TimerFinished => crate::services::timers::TIMER_FINISHED_EVENT,
+            Self::Dictation => crate::services::speech::DICTATION_EVENT,
+            Self::PrivacySessionChanged => crate::services::privacy_session::PRIVACY_SESSION_EVENT,
+            Self::UiAction => crate::app::app_commands::UI_ACTION_EVENT,
+            Self::Power => crate::app::power::POWER_EVENT,
+            Self::ScreenChanged => crate::window::screen_events::SCREEN_CHANGED_EVENT,
+            Self::HudContent => crate::window::hud::HUD_CONTENT_EVENT,
+            Self::CommandBusy => crate::cmds::busy_guard::BUSY_EVENT,
+            Self::HotkeyFallback => crate::hotkey::fallback::HOTKEY_FALLBACK_EVENT,
+        }
+    }
+
+    /// 载荷形状的简要说明（面向插件作者，不是机器校验的 schema）
+    fn payload_doc(&self) -> &'static str {
+        match self {
+            Self::SearchPartial => "{ generation, provider, results: SearchResult[] }",
+            Self::SearchDone => "{ generation, providerCount }",
+            Self::PluginSearchRequest => "{ requestId, pluginId, query }",
+            Self::PluginViewUpdate => "{ pluginId, sequence, view }",
+            Self::PluginBusMessage => "{ subscriberId, message }",
+            Self::PluginDeepLink => "{ pluginId, path, url }",
+            Self::PluginBackgroundTask => "{ pluginId, taskId, command }",
+            Self::PluginHealthChanged => "PluginHealth 对象 { pluginId, status, message? }",
+            Self::InstallProgress => "{ pluginId, stage }",
+            Self::DownloadProgress => "DownloadInfo 对象 { id, url, dest, state, downloadedBytes, totalBytes }",
+            Self::FileChange => "{ id, kind, paths: string[] }",
+            Self::ProfileSwitched => "{ name }",
+            Self::TimerFinished => "Timer 对象 { id, label, startedAt, endsAt }",
+            Self::Dictation => "{ text }",
+            Self::PrivacySessionChanged => "PrivacySessionState 对象",
+            Self::UiAction => "{ action, ... }",
+            Self::Power => "{ state, sleptSecs?, needsRevalidation? }",
+            Self::ScreenChanged => "屏幕信息对象（detect_screen_info 的返回值）",
+            Self::HudContent => "{ text, icon? }",
+            Self::CommandBusy => "{ key, busy }",
+            Self::HotkeyFallback => "{ configured, active }",
+        }
+    }
+
+    /// 引入版本（semver 稳定性承诺的起点）
+    fn since(&self) -> &'static str {
+        match self {
+            Self::SearchPartial | Self::SearchDone => "0.4.0",
+            Self::PluginSearchRequest | Self::PluginHealthChanged => "0.4.0",
+            Self::InstallProgress | Self::TimerFinished | Self::Dictation => "0.4.0",
+            Self::UiAction => "0.4.0",
+            _ => "0.3.0",
+        }
+    }
+
+    const ALL: &'static [AppEvent] = &[
+        Self::SearchPartial,
+        Self::SearchDone,
+        Self::PluginSearchRequest,
+        Self::PluginViewUpdate,
+        Self::PluginBusMessage,
+        Self::PluginDeepLink,
+        Self::PluginBackgroundTask,
+        Self::PluginHealthChanged,
+        Self::InstallProgress,
+        Self::DownloadProgress,
+        Self::FileChange,
+        Self::ProfileSwitched,
+        Self::TimerFinished,
+        Self::Dictation,
+        Self::PrivacySessionChanged,
+        Self::UiAction,
+        Self::Power,
+        Self::ScreenChanged,
+        Self::HudContent,
+        Self::CommandBusy,
+        Self::HotkeyFallback,
+    ];
+}
+
+/// `list_events` 返回的事件元数据
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventDescriptor {
+    pub name: String,
+    pub payload: String,
+    pub since: String,
+}
+
+/// 统一发送入口：类型化事件名 + 统一 debug 打点
+pub fn emit<P: Serialize + Clone>(app: &AppHandle, event: AppEvent, payload: P) {
+    log::debug!("[Events] emit {}", event.as_str());
+    let _ = app.emit(event.as_str(), payload);
+}
+
+/// 列出全部已登记事件（插件能力发现用）
+#[tauri::command]
+pub fn list_events() -> Vec<EventDescriptor> {
+    AppEvent::ALL
+        .iter()
+        .map(|e| EventDescriptor {
+            name: e.as_str().to_string(),
+            payload: e.payload_doc().to_string(),
+            since: e.since().to_string(),
+        })
+        .collect()
+}
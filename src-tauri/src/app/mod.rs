@@ -0,0 +1,7 @@
+pub mod app_commands;
+pub mod cancellation;
+pub mod events;
+pub mod power;
+pub mod shutdown;
+pub mod single_instance;
+pub mod state_snapshot;
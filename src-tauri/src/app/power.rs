@@ -0,0 +1,102 @@
+//! 系统休眠/唤醒感知
+//!
+//! 监听系统睡眠与唤醒事件：睡眠时暂停各 watcher、调度任务和 LAN 同步，
+//! 唤醒后恢复；长时间睡眠后唤醒还会触发文件索引与浏览器缓存的重新校验。
+
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// 睡眠/唤醒事件，前端与插件可监听
+pub const POWER_EVENT: &str = "system://power";
+/// 睡眠超过该时长视为"长睡眠"，唤醒后触发重校验
+const LONG_SLEEP_SECS: i64 = 30 * 60;
+/// 无系统事件 API 时的兜底：时钟跳变检测间隔
+const CLOCK_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 暂停/恢复回调：(名称, 暂停, 恢复)
+type PauseHook = (String, Box<dyn Fn() + Send + Sync>, Box<dyn Fn() + Send + Sync>);
+
+static HOOKS: Lazy<Mutex<Vec<PauseHook>>> = Lazy::new(|| Mutex::new(Vec::new()));
+static SUSPENDED: AtomicBool = AtomicBool::new(false);
+static LAST_TICK: AtomicI64 = AtomicI64::new(0);
+
+/// 注册暂停/恢复回调；watcher、调度器、LAN 同步在启动时各自注册
+pub fn register_suspend_hooks<P, R>(name: &str, on_suspend: P, on_resume: R)
+where
+    P: Fn() + Send + Sync + 'static,
+    R: Fn() + Send + Sync + 'static,
+{
+    if let Ok(mut hooks) = HOOKS.lock() {
+        hooks.push((name.to_string(), Box::new(on_suspend), Box::new(on_resume)));
+    }
+}
+
+/// 当前是否处于挂起状态（后台循环可据此跳过一轮）
+pub fn is_suspended() -> bool {
+    SUSPENDED.load(Ordering::SeqCst)
+}
+
+fn run_hooks(suspend: bool) {
+    let Ok(hooks) = HOOKS.lock() else { return };
+    for (name, on_suspend, on_resume) in hooks.iter() {
+        if suspend {
+            log::info!("[Power] suspending '{}'", name);
+            on_suspend();
+        } else {
+            log::info!("[Power] resuming '{}'", name);
+            on_resume();
+        }
+    }
+}
+
+/// 进入睡眠：暂停所有已注册服务
+pub fn handle_suspend(app: &AppHandle) {
+    if SUSPENDED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    run_hooks(true);
+    let _ = app.emit(POWER_EVENT, serde_json::json!({ "state": "suspend" }));
+}
+
+/// 唤醒：恢复服务；`slept_secs` 超过阈值时广播需要重校验
+pub fn handle_resume(app: &AppHandle, slept_secs: i64) {
+    if !SUSPENDED.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    run_hooks(false);
+    let needs_revalidation = slept_secs >= LONG_SLEEP_SECS;
+    if needs_revalidation {
+        log::info!("[Power] long sleep ({}s), requesting index revalidation", slept_secs);
+    }
+    let _ = app.emit(
+        POWER_EVENT,
+        serde_json::json!({
+            "state": "resume",
+            "sleptSecs": slept_secs,
+            "needsRevalidation": needs_revalidation,
+        }),
+    );
+}
+
+/// 启动时钟跳变监测：检测到大幅时间跳跃说明经历了一次睡眠。
+/// 在系统电源事件不可靠的平台（部分 Linux 桌面）上作为兜底。
+pub fn spawn_clock_monitor(app: AppHandle) {
+    LAST_TICK.store(chrono::Utc::now().timestamp(), Ordering::SeqCst);
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(CLOCK_CHECK_INTERVAL).await;
+            let now = chrono::Utc::now().timestamp();
+            let last = LAST_TICK.swap(now, Ordering::SeqCst);
+            let gap = now - last - CLOCK_CHECK_INTERVAL.as_secs() as i64;
+            // 超过两个检查周期的空洞视为睡眠补偿
+            if gap > CLOCK_CHECK_INTERVAL.as_secs() as i64 * 2 {
+                log::info!("[Power] clock jump of {}s detected, treating as sleep/wake", gap);
+                handle_suspend(&app);
+                handle_resume(&app, gap);
+            }
+        }
+    });
+}
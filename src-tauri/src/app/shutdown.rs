@@ -0,0 +1,70 @@
+//! 协调关闭
+//!
+//! 此前各退出路径直接 drop 所有东西。现在退出统一走 `run_shutdown`：
+//! 停掉剪贴板监听、flush 文件索引与数据库写入、落盘进行中的使用统计、
+//! 注销全局快捷键，最后写一条 `shutdown_status` 日志。
+
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// 关闭钩子：各服务在启动时注册自己的清理动作
+type ShutdownHook = Box<dyn FnOnce() -> Result<(), String> + Send>;
+
+static HOOKS: Lazy<Mutex<Vec<(String, ShutdownHook)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+/// 防止重入：窗口关闭事件与退出菜单可能先后触发
+static SHUTDOWN_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// 注册关闭钩子；`name` 用于日志定位哪个钩子失败
+pub fn register_hook<F>(name: &str, hook: F)
+where
+    F: FnOnce() -> Result<(), String> + Send + 'static,
+{
+    if let Ok(mut hooks) = HOOKS.lock() {
+        hooks.push((name.to_string(), Box::new(hook)));
+    }
+}
+
+/// 执行协调关闭。按注册顺序执行钩子，单个失败不阻断后续。
+/// 返回 false 表示已经执行过（调用方不应重复收尾）。
+pub fn run_shutdown() -> bool {
+    if SHUTDOWN_STARTED.swap(true, Ordering::SeqCst) {
+        return false;
+    }
+    log::info!("[Shutdown] coordinated shutdown started");
+
+    let hooks = match HOOKS.lock() {
+        Ok(mut h) => std::mem::take(&mut *h),
+        Err(_) => Vec::new(),
+    };
+    let total = hooks.len();
+    let mut failed = 0usize;
+    for (name, hook) in hooks {
+        match hook() {
+            Ok(()) => log::info!("[Shutdown] hook '{}' completed", name),
+            Err(e) => {
+                failed += 1;
+                log::error!("[Shutdown] hook '{}' failed: {}", name, e);
+            }
+        }
+    }
+
+    // WAL checkpoint：把未落盘的写入合并回主库文件
+    if let Ok(conn) = crate::db::pool::get() {
+        if let Err(e) = conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);") {
+            log::warn!("[Shutdown] wal checkpoint failed: {}", e);
+        }
+    }
+
+    log::info!(
+        "[Shutdown] shutdown_status: {} hooks run, {} failed",
+        total,
+        failed
+    );
+    true
+}
+
+/// 是否已进入关闭流程（后台任务可据此提前退出循环）
+pub fn is_shutting_down() -> bool {
+    SHUTDOWN_STARTED.load(Ordering::SeqCst)
+}
@@ -0,0 +1,72 @@
+//! 单实例保证
+//!
+//! 应用已运行时再次启动，只激活现有实例并转发深链参数，
+//! 而不是起第二个进程去抢全局快捷键和 SQLite 文件。
+//! 实现：本地回环 TCP 端口既当实例锁又当参数转发通道。
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// 实例锁端口；绑定失败即说明已有实例在运行
+const INSTANCE_PORT: u16 = 38917;
+const FORWARD_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 启动时调用。返回 Ok(listener) 表示本进程是首个实例；
+/// 返回 Err 表示已有实例，参数已转发，调用方应直接退出。
+pub fn acquire_or_forward(args: &[String]) -> Result<TcpListener, String> {
+    match TcpListener::bind(("127.0.0.1", INSTANCE_PORT)) {
+        Ok(listener) => Ok(listener),
+        Err(_) => {
+            forward_to_existing(args)?;
+            Err("已有实例在运行，参数已转发".into())
+        }
+    }
+}
+
+/// 把启动参数（深链 URL 等）发给已运行的实例
+fn forward_to_existing(args: &[String]) -> Result<(), String> {
+    let mut stream = TcpStream::connect(("127.0.0.1", INSTANCE_PORT))
+        .map_err(|e| format!("连接现有实例失败: {}", e))?;
+    stream
+        .set_write_timeout(Some(FORWARD_TIMEOUT))
+        .map_err(|e| e.to_string())?;
+    let payload = serde_json::to_string(&args).map_err(|e| e.to_string())?;
+    stream
+        .write_all(payload.as_bytes())
+        .map_err(|e| format!("转发参数失败: {}", e))?;
+    log::info!("[SingleInstance] forwarded args to running instance");
+    Ok(())
+}
+
+/// 首个实例调用：监听后续启动的转发请求，激活主窗口并分发深链
+pub fn spawn_listener(app: AppHandle, listener: TcpListener) {
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut buf = String::new();
+            let _ = stream.set_read_timeout(Some(FORWARD_TIMEOUT));
+            if stream.read_to_string(&mut buf).is_err() {
+                continue;
+            }
+            let args: Vec<String> = serde_json::from_str(&buf).unwrap_or_default();
+            log::info!("[SingleInstance] second launch detected, activating window");
+
+            // 激活主窗口
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+                let _ = window.unminimize();
+            }
+            // 转发来的深链参数走正常分发流程
+            for arg in args {
+                if arg.starts_with("etools://") {
+                    if let Err(e) = crate::plugins::deep_link::dispatch_url(&app, &arg) {
+                        log::warn!("[SingleInstance] deep link dispatch failed: {}", e);
+                    }
+                }
+            }
+        }
+    });
+}
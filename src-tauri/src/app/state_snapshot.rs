@@ -0,0 +1,125 @@
+//! 应用状态快照与恢复
+//!
+//! 把主窗口几何、活跃档案和隐私会话暂停状态采成一份可序列化的快照，
+//! 落盘到 `app_data/app-state.json`。崩溃重启后可以从最近一次快照
+//! 恢复会话；QA 复现窗口行为时也可以导出/导入快照。
+//! 快照只含运行态，不碰设置存储——设置有自己的持久化与校验。
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, LogicalPosition, LogicalSize, Manager};
+
+/// 快照文件名
+const SNAPSHOT_FILE: &str = "app-state.json";
+
+/// 主窗口几何与可见性
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowSnapshot {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub visible: bool,
+    pub always_on_top: bool,
+}
+
+/// 完整应用状态快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppStateSnapshot {
+    /// 主窗口不存在时为 None（托盘常驻状态）
+    pub window: Option<WindowSnapshot>,
+    pub active_profile: String,
+    /// 隐私会话剩余分钟数；未开启时为 None
+    pub privacy_minutes_left: Option<u64>,
+    /// 采集时间（Unix 毫秒）
+    pub captured_at: i64,
+}
+
+fn snapshot_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join(SNAPSHOT_FILE))
+}
+
+fn capture_window(app: &AppHandle) -> Option<WindowSnapshot> {
+    let window = app.get_webview_window("main")?;
+    let scale = window.scale_factor().ok()?;
+    let pos = window.outer_position().ok()?.to_logical::<f64>(scale);
+    let size = window.outer_size().ok()?.to_logical::<f64>(scale);
+    Some(WindowSnapshot {
+        x: pos.x,
+        y: pos.y,
+        width: size.width,
+        height: size.height,
+        visible: window.is_visible().unwrap_or(false),
+        always_on_top: window.is_always_on_top().unwrap_or(false),
+    })
+}
+
+/// 采集当前应用状态并落盘
+#[tauri::command]
+pub fn get_app_state_snapshot(app: AppHandle) -> Result<AppStateSnapshot, String> {
+    let privacy = crate::services::privacy_session::get_privacy_session();
+    let privacy_minutes_left = privacy.expires_at.and_then(|expires| {
+        let left_ms = expires - chrono::Utc::now().timestamp_millis();
+        (left_ms > 0).then(|| (left_ms as u64 / 60_000).max(1))
+    });
+    let snapshot = AppStateSnapshot {
+        window: capture_window(&app),
+        active_profile: crate::services::profiles::get_active_profile(),
+        privacy_minutes_left,
+        captured_at: chrono::Utc::now().timestamp_millis(),
+    };
+    let path = snapshot_path(&app)?;
+    std::fs::write(
+        &path,
+        serde_json::to_string_pretty(&snapshot).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| format!("写入快照失败: {}", e))?;
+    Ok(snapshot)
+}
+
+/// 恢复应用状态；`snapshot` 缺省时读最近一次落盘的快照
+#[tauri::command]
+pub fn restore_app_state(
+    app: AppHandle,
+    snapshot: Option<AppStateSnapshot>,
+) -> Result<(), String> {
+    let snapshot = match snapshot {
+        Some(s) => s,
+        None => {
+            let content = std::fs::read_to_string(snapshot_path(&app)?)
+                .map_err(|_| "没有可恢复的状态快照".to_string())?;
+            serde_json::from_str(&content).map_err(|e| format!("快照损坏: {}", e))?
+        }
+    };
+
+    if let Some(win) = &snapshot.window {
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.set_position(LogicalPosition::new(win.x, win.y));
+            let _ = window.set_size(LogicalSize::new(win.width, win.height));
+            let _ = window.set_always_on_top(win.always_on_top);
+            let _ = if win.visible {
+                window.show()
+            } else {
+                window.hide()
+            };
+        }
+    }
+
+    if snapshot.active_profile != crate::services::profiles::get_active_profile() {
+        crate::services::profiles::switch_profile(app.clone(), snapshot.active_profile.clone())?;
+    }
+
+    // 崩溃前开着的隐私会话按剩余时长续上，而不是按完整时长重开
+    if let Some(minutes) = snapshot.privacy_minutes_left {
+        if !crate::services::privacy_session::is_recording_paused() {
+            crate::services::privacy_session::start_privacy_session(app, Some(minutes))?;
+        }
+    }
+    log::info!("[StateSnapshot] restored state captured at {}", snapshot.captured_at);
+    Ok(())
+}
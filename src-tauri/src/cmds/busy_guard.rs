@@ -0,0 +1,60 @@
+//! 命令忙碌状态守卫
+//!
+//! 长耗时异步命令用它标记"进行中"：同名操作不允许并发重入，
+//! 前端通过事件拿到忙碌状态来显示 spinner。
+
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+/// 忙碌状态变更事件
+pub const BUSY_EVENT: &str = "command://busy";
+
+static BUSY: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// RAII 守卫：创建即标记忙碌，Drop 自动解除
+pub struct BusyGuard {
+    app: AppHandle,
+    key: String,
+}
+
+impl BusyGuard {
+    /// 尝试获取；同 key 已在执行时返回 Err
+    pub fn acquire(app: &AppHandle, key: &str) -> Result<Self, String> {
+        {
+            let mut busy = BUSY.lock().map_err(|e| e.to_string())?;
+            if !busy.insert(key.to_string()) {
+                return Err(format!("操作 '{}' 正在进行中", key));
+            }
+        }
+        let guard = Self {
+            app: app.clone(),
+            key: key.to_string(),
+        };
+        guard.emit(true);
+        Ok(guard)
+    }
+
+    fn emit(&self, busy: bool) {
+        let _ = self.app.emit(
+            BUSY_EVENT,
+            serde_json::json!({ "key": self.key, "busy": busy }),
+        );
+    }
+}
+
+impl Drop for BusyGuard {
+    fn drop(&mut self) {
+        if let Ok(mut busy) = BUSY.lock() {
+            busy.remove(&self.key);
+        }
+        self.emit(false);
+    }
+}
+
+/// 查询某操作是否忙碌（前端初始化时同步状态）
+#[tauri::command]
+pub fn is_command_busy(key: String) -> bool {
+    BUSY.lock().map(|b| b.contains(&key)).unwrap_or(false)
+}
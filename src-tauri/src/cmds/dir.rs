@@ -0,0 +1,133 @@
+//! 目录浏览命令
+//!
+//! `list_dir` / `stat_path` 为文件浏览模式和插件提供结构化条目
+//! （大小、修改时间、类型、隐藏标记），超大目录支持分页。
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::UNIX_EPOCH;
+use tauri::AppHandle;
+
+use super::fs_guard::{self, Access};
+
+/// 单页条目上限
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// 目录条目/文件元信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirEntry {
+    pub name: String,
+    pub path: String,
+    /// "file" / "dir" / "symlink"
+    pub kind: String,
+    pub size: u64,
+    /// 修改时间（Unix 秒），取不到为 0
+    pub mtime: i64,
+    pub hidden: bool,
+}
+
+/// `list_dir` 的选项
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListDirOptions {
+    /// 是否包含隐藏文件
+    #[serde(default)]
+    pub include_hidden: bool,
+    /// 分页偏移
+    #[serde(default)]
+    pub offset: usize,
+    /// 每页条数；缺省与上限相同
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListDirResult {
+    pub entries: Vec<DirEntry>,
+    /// 目录总条目数（过滤隐藏后），用于前端分页
+    pub total: usize,
+}
+
+fn is_hidden(name: &str, path: &std::path::Path) -> bool {
+    if name.starts_with('.') {
+        return true;
+    }
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::fs::MetadataExt;
+        if let Ok(meta) = path.metadata() {
+            // FILE_ATTRIBUTE_HIDDEN
+            return meta.file_attributes() & 0x2 != 0;
+        }
+    }
+    let _ = path;
+    false
+}
+
+fn entry_from_path(path: &std::path::Path) -> Option<DirEntry> {
+    let name = path.file_name()?.to_string_lossy().to_string();
+    let meta = path.symlink_metadata().ok()?;
+    let kind = if meta.file_type().is_symlink() {
+        "symlink"
+    } else if meta.is_dir() {
+        "dir"
+    } else {
+        "file"
+    };
+    let mtime = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    Some(DirEntry {
+        hidden: is_hidden(&name, path),
+        name,
+        path: path.display().to_string(),
+        kind: kind.to_string(),
+        size: if meta.is_dir() { 0 } else { meta.len() },
+        mtime,
+    })
+}
+
+/// 列出目录内容；目录优先、按名称排序，支持分页
+#[tauri::command]
+pub fn list_dir(app: AppHandle, path: String, options: Option<ListDirOptions>) -> Result<ListDirResult, String> {
+    let options = options.unwrap_or_default();
+    let canonical = fs_guard::check_access(&app, &path, Access::Read)?;
+    if !canonical.is_dir() {
+        return Err(format!("{} 不是目录", canonical.display()));
+    }
+
+    let mut entries: Vec<DirEntry> = fs::read_dir(&canonical)
+        .map_err(|e| format!("读取目录失败: {}", e))?
+        .flatten()
+        .filter(|e| !crate::services::do_not_index::is_excluded(&e.path()))
+        .filter_map(|e| entry_from_path(&e.path()))
+        .filter(|e| options.include_hidden || !e.hidden)
+        .collect();
+    entries.sort_by(|a, b| {
+        // 目录排在文件前，同类按本地化规则排序
+        (b.kind == "dir")
+            .cmp(&(a.kind == "dir"))
+            .then_with(|| crate::search::collation::compare(&a.name, &b.name))
+    });
+
+    let total = entries.len();
+    let limit = options.limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+    let page = entries
+        .into_iter()
+        .skip(options.offset)
+        .take(limit)
+        .collect();
+    Ok(ListDirResult { entries: page, total })
+}
+
+/// 查询单个路径的元信息
+#[tauri::command]
+pub fn stat_path(app: AppHandle, path: String) -> Result<DirEntry, String> {
+    let canonical = fs_guard::check_access(&app, &path, Access::Read)?;
+    entry_from_path(&canonical).ok_or_else(|| format!("无法读取 {}", canonical.display()))
+}
@@ -0,0 +1,129 @@
+//! 文件读写命令
+//!
+//! 所有路径先经过 `fs_guard` 的允许列表检查与规范化，
+//! 写入同时记审计日志。文本读取带编码探测（UTF-16/GBK 回退），
+//! 二进制读取走 `read_file_bytes`（base64，带大小上限）。
+
+use base64::Engine;
+use std::fs;
+use tauri::AppHandle;
+
+use super::fs_guard::{self, Access};
+
+/// `read_file_bytes` 的大小上限：16 MB，避免把大文件整个塞进 IPC
+const MAX_BINARY_READ_BYTES: u64 = 16 * 1024 * 1024;
+
+/// 按字节特征探测编码并解码为字符串。
+/// 顺序：BOM → UTF-8 → UTF-16LE/BE → GBK，全失败按 UTF-8 lossy
+fn decode_text(bytes: &[u8]) -> String {
+    // BOM 判断
+    if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        return String::from_utf8_lossy(&bytes[3..]).into_owned();
+    }
+    if bytes.starts_with(&[0xFF, 0xFE]) {
+        let (text, _, _) = encoding_rs::UTF_16LE.decode(&bytes[2..]);
+        return text.into_owned();
+    }
+    if bytes.starts_with(&[0xFE, 0xFF]) {
+        let (text, _, _) = encoding_rs::UTF_16BE.decode(&bytes[2..]);
+        return text.into_owned();
+    }
+    // 无 BOM：先按 UTF-8 严格解析
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return text.to_string();
+    }
+    // GBK 回退（中文用户的遗留文本文件大多是 GBK）
+    let (text, _, had_errors) = encoding_rs::GBK.decode(bytes);
+    if !had_errors {
+        return text.into_owned();
+    }
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+/// 读取文本文件；非 UTF-8 内容自动按探测到的编码解码而不是报错
+#[tauri::command]
+pub fn read_file(app: AppHandle, path: String) -> Result<String, String> {
+    let canonical = fs_guard::check_access(&app, &path, Access::Read)?;
+    let bytes = fs::read(&canonical).map_err(|e| format!("读取失败: {}", e))?;
+    Ok(decode_text(&bytes))
+}
+
+/// 读取二进制文件，返回 base64；超过大小上限时报错
+#[tauri::command]
+pub fn read_file_bytes(app: AppHandle, path: String) -> Result<String, String> {
+    let canonical = fs_guard::check_access(&app, &path, Access::Read)?;
+    let meta = fs::metadata(&canonical).map_err(|e| format!("读取元数据失败: {}", e))?;
+    if meta.len() > MAX_BINARY_READ_BYTES {
+        return Err(format!(
+            "文件大小 {} 超过二进制读取上限 {} 字节",
+            meta.len(),
+            MAX_BINARY_READ_BYTES
+        ));
+    }
+    let bytes = fs::read(&canonical).map_err(|e| format!("读取失败: {}", e))?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
+}
+
+/// 写入文本文件（覆盖）
+#[tauri::command]
+pub fn write_file(app: AppHandle, path: String, content: String) -> Result<(), String> {
+    let canonical = fs_guard::check_access(&app, &path, Access::Write)?;
+    fs::write(&canonical, content).map_err(|e| format!("写入失败: {}", e))?;
+    crate::services::audit_log::record(
+        &app,
+        "fileWrite",
+        &format!("write_file: {}", canonical.display()),
+    );
+    Ok(())
+}
+
+/// 追加写入文本文件
+#[tauri::command]
+pub fn append_file(app: AppHandle, path: String, content: String) -> Result<(), String> {
+    use std::io::Write;
+    let canonical = fs_guard::check_access(&app, &path, Access::Write)?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&canonical)
+        .map_err(|e| format!("打开失败: {}", e))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| format!("追加写入失败: {}", e))?;
+    crate::services::audit_log::record(
+        &app,
+        "fileWrite",
+        &format!("append_file: {}", canonical.display()),
+    );
+    Ok(())
+}
+
+/// 复制文件；源需读权限，目标需写权限
+#[tauri::command]
+pub fn copy_file(app: AppHandle, from: String, to: String) -> Result<(), String> {
+    let src = fs_guard::check_access(&app, &from, Access::Read)?;
+    let dst = fs_guard::check_access(&app, &to, Access::Write)?;
+    fs::copy(&src, &dst).map_err(|e| format!("复制失败: {}", e))?;
+    crate::services::audit_log::record(
+        &app,
+        "fileWrite",
+        &format!("copy_file: {} -> {}", src.display(), dst.display()),
+    );
+    Ok(())
+}
+
+/// 移动/重命名文件；跨设备时退化为复制+删除
+#[tauri::command]
+pub fn move_file(app: AppHandle, from: String, to: String) -> Result<(), String> {
+    let src = fs_guard::check_access(&app, &from, Access::Write)?;
+    let dst = fs_guard::check_access(&app, &to, Access::Write)?;
+    if fs::rename(&src, &dst).is_err() {
+        fs::copy(&src, &dst).map_err(|e| format!("移动失败（复制阶段）: {}", e))?;
+        fs::remove_file(&src).map_err(|e| format!("移动失败（删除源阶段）: {}", e))?;
+    }
+    crate::services::audit_log::record(
+        &app,
+        "fileWrite",
+        &format!("move_file: {} -> {}", src.display(), dst.display()),
+    );
+    Ok(())
+}
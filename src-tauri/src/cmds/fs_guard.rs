@@ -0,0 +1,156 @@
+//! 文件访问路径守卫
+//!
+//! `read_file`/`write_file` 之前允许 webview 访问任意路径。
+//! 现在所有文件命令先经过这里：可配置的允许/拒绝列表
+//! （默认只放行应用数据目录和用户显式选择过的文件夹）、
+//! 路径规范化防 `..` 穿越、允许范围外的写入需要用户逐次确认。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+/// 访问类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    Read,
+    Write,
+}
+
+#[derive(Debug, Default)]
+struct GuardState {
+    /// 用户添加的允许根目录
+    allowed_roots: Vec<PathBuf>,
+    /// 拒绝列表（优先级高于允许列表）
+    denied_roots: Vec<PathBuf>,
+    /// 本次会话中用户已确认过的单次写入路径
+    session_grants: HashSet<PathBuf>,
+}
+
+static STATE: Lazy<RwLock<GuardState>> = Lazy::new(|| RwLock::new(GuardState::default()));
+
+/// 规范化路径：解析符号链接与 `..`。文件不存在时规范化父目录
+pub fn canonicalize_lenient(path: &Path) -> Result<PathBuf, String> {
+    if let Ok(canonical) = path.canonicalize() {
+        return Ok(canonical);
+    }
+    // 写入新文件时目标还不存在：规范化父目录 + 文件名
+    let parent = path
+        .parent()
+        .ok_or_else(|| format!("无效路径: {}", path.display()))?;
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("无效路径: {}", path.display()))?;
+    let canonical_parent = parent
+        .canonicalize()
+        .map_err(|e| format!("路径不存在: {} ({})", parent.display(), e))?;
+    Ok(canonical_parent.join(file_name))
+}
+
+fn is_under(path: &Path, roots: &[PathBuf]) -> bool {
+    roots.iter().any(|root| path.starts_with(root))
+}
+
+/// 检查访问是否放行。写入越界时返回 Err，携带需要用户确认的提示；
+/// 前端确认后调用 `grant_write_access` 再重试
+pub fn check_access(app: &AppHandle, raw_path: &str, access: Access) -> Result<PathBuf, String> {
+    let canonical = canonicalize_lenient(Path::new(raw_path))?;
+    let state = STATE.read().map_err(|e| e.to_string())?;
+
+    if is_under(&canonical, &state.denied_roots) {
+        return Err(format!("路径 {} 在拒绝列表中", canonical.display()));
+    }
+
+    // 应用数据目录始终允许
+    let mut allowed = state.allowed_roots.clone();
+    if let Ok(data_dir) = app.path().app_data_dir() {
+        allowed.push(data_dir);
+    }
+    if is_under(&canonical, &allowed) {
+        return Ok(canonical);
+    }
+
+    match access {
+        // 读取范围稍宽：用户主目录内默认可读（文件搜索需要）
+        Access::Read => {
+            if let Some(home) = app.path().home_dir().ok().filter(|h| canonical.starts_with(h)) {
+                let _ = home;
+                return Ok(canonical);
+            }
+            Err(format!("读取 {} 未被授权", canonical.display()))
+        }
+        Access::Write => {
+            if state.session_grants.contains(&canonical) {
+                return Ok(canonical);
+            }
+            Err(format!(
+                "CONSENT_REQUIRED:写入 {} 超出允许范围，需要用户确认",
+                canonical.display()
+            ))
+        }
+    }
+}
+
+/// 用户在确认弹窗中批准一次越界写入（仅本次会话有效）
+#[tauri::command]
+pub fn grant_write_access(app: AppHandle, path: String) -> Result<(), String> {
+    let canonical = canonicalize_lenient(Path::new(&path))?;
+    crate::services::audit_log::record(
+        &app,
+        "permissionGrant",
+        &format!("write access granted: {}", canonical.display()),
+    );
+    STATE
+        .write()
+        .map_err(|e| e.to_string())?
+        .session_grants
+        .insert(canonical);
+    Ok(())
+}
+
+/// 添加允许根目录（用户在设置页选择文件夹后调用）
+#[tauri::command]
+pub fn add_allowed_root(app: AppHandle, path: String) -> Result<(), String> {
+    let canonical = Path::new(&path)
+        .canonicalize()
+        .map_err(|e| format!("目录不存在: {}", e))?;
+    if !canonical.is_dir() {
+        return Err("允许根必须是目录".into());
+    }
+    crate::services::audit_log::record(
+        &app,
+        "permissionGrant",
+        &format!("allowed root added: {}", canonical.display()),
+    );
+    STATE
+        .write()
+        .map_err(|e| e.to_string())?
+        .allowed_roots
+        .push(canonical);
+    Ok(())
+}
+
+/// 移除允许根目录
+#[tauri::command]
+pub fn remove_allowed_root(path: String) -> Result<(), String> {
+    let canonical = canonicalize_lenient(Path::new(&path))?;
+    STATE
+        .write()
+        .map_err(|e| e.to_string())?
+        .allowed_roots
+        .retain(|r| *r != canonical);
+    Ok(())
+}
+
+/// 当前允许的根目录列表（设置页展示）
+#[tauri::command]
+pub fn list_allowed_roots() -> Result<Vec<String>, String> {
+    let state = STATE.read().map_err(|e| e.to_string())?;
+    Ok(state
+        .allowed_roots
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect())
+}
@@ -0,0 +1,5 @@
+pub mod busy_guard;
+pub mod dir;
+pub mod files;
+pub mod fs_guard;
+pub mod open_url;
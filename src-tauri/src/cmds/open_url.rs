@@ -0,0 +1,86 @@
+//! open_url 加固
+//!
+//! 打开外部 URL 前做校验与归一化：危险 scheme（file://、javascript:）
+//! 默认拦截，只放行允许列表内的 scheme；支持原子化打开多个 URL
+//! （任何一个校验失败则全部不打开）；每次打开记入审计日志。
+
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+use std::sync::RwLock;
+use tauri::AppHandle;
+
+/// 默认允许的 scheme；插件经用户同意认领的 scheme 会动态加入
+static ALLOWED_SCHEMES: Lazy<RwLock<HashSet<String>>> = Lazy::new(|| {
+    RwLock::new(
+        ["http", "https", "mailto", "tel"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    )
+});
+
+/// 永远拒绝的 scheme，即使被加入允许列表也无效
+const BLOCKED_SCHEMES: &[&str] = &["javascript", "data", "vbscript"];
+
+/// 动态放行 scheme（深链关联获批后由 deep_link 模块调用）
+pub fn allow_scheme(scheme: &str) {
+    if BLOCKED_SCHEMES.contains(&scheme) {
+        log::warn!("[OpenUrl] refusing to allow blocked scheme '{}'", scheme);
+        return;
+    }
+    if let Ok(mut allowed) = ALLOWED_SCHEMES.write() {
+        allowed.insert(scheme.to_string());
+    }
+}
+
+/// 校验并归一化一个 URL；失败返回用户可读的原因
+fn validate(raw: &str) -> Result<url::Url, String> {
+    let trimmed = raw.trim();
+    // 无 scheme 的裸域名补 https
+    let candidate = if !trimmed.contains("://") && !trimmed.contains(':') {
+        format!("https://{}", trimmed)
+    } else {
+        trimmed.to_string()
+    };
+    let parsed = url::Url::parse(&candidate).map_err(|e| format!("URL 无效: {} ({})", raw, e))?;
+    let scheme = parsed.scheme().to_ascii_lowercase();
+
+    if BLOCKED_SCHEMES.contains(&scheme.as_str()) {
+        return Err(format!("scheme '{}' 被禁止打开", scheme));
+    }
+    if scheme == "file" {
+        return Err("file:// 请使用文件命令而不是 open_url".into());
+    }
+    let allowed = ALLOWED_SCHEMES
+        .read()
+        .map(|a| a.contains(&scheme))
+        .unwrap_or(false);
+    if !allowed {
+        return Err(format!("scheme '{}' 不在允许列表中", scheme));
+    }
+    Ok(parsed)
+}
+
+/// 打开单个 URL
+#[tauri::command]
+pub fn open_url(app: AppHandle, url: String) -> Result<(), String> {
+    open_urls(app, vec![url])
+}
+
+/// 原子化打开多个 URL：先全部校验，任何一个失败则都不打开
+#[tauri::command]
+pub fn open_urls(app: AppHandle, urls: Vec<String>) -> Result<(), String> {
+    if urls.is_empty() {
+        return Err("URL 列表为空".into());
+    }
+    let validated: Vec<url::Url> = urls
+        .iter()
+        .map(|u| validate(u))
+        .collect::<Result<_, _>>()?;
+
+    for parsed in &validated {
+        open::that_detached(parsed.as_str()).map_err(|e| format!("打开 {} 失败: {}", parsed, e))?;
+        crate::services::audit_log::record(&app, "urlOpen", parsed.as_str());
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! 数据库备份与恢复
+//!
+//! 用 SQLite 在线备份 API 产出一致性快照（直接拷贝 WAL 模式下的
+//! 数据库文件会丢掉未 checkpoint 的写入）。备份覆盖剪贴板历史、
+//! 使用统计和插件状态等全部表。
+
+use rusqlite::backup::Backup;
+use rusqlite::Connection;
+use std::path::Path;
+use std::time::Duration;
+
+use super::pool;
+
+/// 每批拷贝的页数；-1 表示一次拷完，这里分批以免长时间占用源库
+const BACKUP_PAGES_PER_STEP: std::os::raw::c_int = 256;
+const BACKUP_STEP_PAUSE: Duration = Duration::from_millis(10);
+
+/// 备份数据库到指定路径
+#[tauri::command]
+pub fn backup_database(dest: String) -> Result<(), String> {
+    let dest_path = Path::new(&dest);
+    if let Some(parent) = dest_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let src = pool::get()?;
+    let mut dst = Connection::open(dest_path).map_err(|e| format!("打开备份目标失败: {}", e))?;
+    let backup = Backup::new(&src, &mut dst).map_err(|e| format!("创建备份任务失败: {}", e))?;
+    backup
+        .run_to_completion(BACKUP_PAGES_PER_STEP, BACKUP_STEP_PAUSE, None)
+        .map_err(|e| format!("备份执行失败: {}", e))?;
+
+    log::info!("[Db] database backed up to {}", dest);
+    Ok(())
+}
+
+/// 从备份文件恢复数据库；恢复前会校验备份文件完整性
+#[tauri::command]
+pub fn restore_database(src: String) -> Result<(), String> {
+    let src_path = Path::new(&src);
+    if !src_path.exists() {
+        return Err(format!("备份文件不存在: {}", src));
+    }
+
+    // 先做完整性检查，损坏的备份直接拒绝
+    let src_conn = Connection::open(src_path).map_err(|e| format!("打开备份文件失败: {}", e))?;
+    let integrity: String = src_conn
+        .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+        .map_err(|e| format!("完整性检查失败: {}", e))?;
+    if integrity != "ok" {
+        return Err(format!("备份文件已损坏: {}", integrity));
+    }
+
+    // 反向备份：备份文件 -> 当前库。走备份 API 保证 WAL 状态一致
+    let mut dst = pool::get()?;
+    let backup = Backup::new(&src_conn, &mut dst).map_err(|e| format!("创建恢复任务失败: {}", e))?;
+    backup
+        .run_to_completion(BACKUP_PAGES_PER_STEP, BACKUP_STEP_PAUSE, None)
+        .map_err(|e| format!("恢复执行失败: {}", e))?;
+
+    log::info!("[Db] database restored from {}", src);
+    Ok(())
+}
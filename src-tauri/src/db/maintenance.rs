@@ -0,0 +1,104 @@
+//! 数据库健康检查与后台维护
+//!
+//! `get_database_health` 在 `get_database_stats` 的基础上补充页统计、
+//! 碎片率和 integrity_check 结果；另有一个空闲窗口执行的
+//! VACUUM/ANALYZE 维护任务。
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+use super::pool;
+
+/// 维护任务的检查间隔
+const MAINTENANCE_CHECK_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// 距上次用户活动超过该时长视为空闲
+const IDLE_THRESHOLD_SECS: i64 = 10 * 60;
+/// 两次 VACUUM 之间的最小间隔（秒）
+const VACUUM_MIN_INTERVAL_SECS: i64 = 24 * 60 * 60;
+
+/// 最近一次用户活动时间（Unix 秒），由搜索/窗口事件更新
+static LAST_ACTIVITY: AtomicI64 = AtomicI64::new(0);
+static LAST_VACUUM: AtomicI64 = AtomicI64::new(0);
+
+/// 记录用户活动（主窗口显示、执行搜索时调用）
+pub fn touch_activity() {
+    LAST_ACTIVITY.store(chrono::Utc::now().timestamp(), Ordering::Relaxed);
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseHealth {
+    /// 数据库总页数
+    pub page_count: i64,
+    /// 空闲页数；高值意味着碎片化
+    pub freelist_count: i64,
+    /// 碎片率（freelist / page_count）
+    pub fragmentation: f64,
+    pub page_size: i64,
+    /// integrity_check 的第一行结果，"ok" 为健康
+    pub integrity: String,
+    /// 上次 VACUUM 时间（Unix 秒），0 表示本次启动内未执行
+    pub last_vacuum_at: i64,
+}
+
+/// 汇总数据库健康指标
+#[tauri::command]
+pub fn get_database_health() -> Result<DatabaseHealth, String> {
+    let conn = pool::get()?;
+    let page_count: i64 = conn
+        .query_row("PRAGMA page_count", [], |r| r.get(0))
+        .map_err(|e| e.to_string())?;
+    let freelist_count: i64 = conn
+        .query_row("PRAGMA freelist_count", [], |r| r.get(0))
+        .map_err(|e| e.to_string())?;
+    let page_size: i64 = conn
+        .query_row("PRAGMA page_size", [], |r| r.get(0))
+        .map_err(|e| e.to_string())?;
+    let integrity: String = conn
+        .query_row("PRAGMA integrity_check", [], |r| r.get(0))
+        .map_err(|e| e.to_string())?;
+
+    Ok(DatabaseHealth {
+        page_count,
+        freelist_count,
+        fragmentation: if page_count > 0 {
+            freelist_count as f64 / page_count as f64
+        } else {
+            0.0
+        },
+        page_size,
+        integrity,
+        last_vacuum_at: LAST_VACUUM.load(Ordering::Relaxed),
+    })
+}
+
+/// 立即执行 VACUUM + ANALYZE（设置页"立即整理"按钮）
+#[tauri::command]
+pub fn run_database_maintenance() -> Result<(), String> {
+    let conn = pool::get()?;
+    conn.execute_batch("VACUUM; ANALYZE;")
+        .map_err(|e| format!("数据库维护失败: {}", e))?;
+    LAST_VACUUM.store(chrono::Utc::now().timestamp(), Ordering::Relaxed);
+    log::info!("[DbMaintenance] VACUUM/ANALYZE completed");
+    Ok(())
+}
+
+/// 启动后台维护循环：仅在用户空闲且距上次整理足够久时执行
+pub fn spawn_maintenance_task() {
+    touch_activity();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(MAINTENANCE_CHECK_INTERVAL).await;
+            let now = chrono::Utc::now().timestamp();
+            let idle = now - LAST_ACTIVITY.load(Ordering::Relaxed) >= IDLE_THRESHOLD_SECS;
+            let due = now - LAST_VACUUM.load(Ordering::Relaxed) >= VACUUM_MIN_INTERVAL_SECS;
+            if !idle || !due {
+                continue;
+            }
+            if let Err(e) = run_database_maintenance() {
+                log::warn!("[DbMaintenance] scheduled maintenance failed: {}", e);
+            }
+        }
+    });
+}
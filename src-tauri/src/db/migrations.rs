@@ -0,0 +1,113 @@
+//! Schema 迁移
+//!
+//! 按版本号顺序执行迁移，已执行的版本记录在 `schema_version` 表里。
+//! 新迁移追加到 `MIGRATIONS` 末尾即可，禁止修改已发布的迁移内容。
+
+use rusqlite::Connection;
+
+/// (版本号, SQL)；版本号必须严格递增
+const MIGRATIONS: &[(i64, &str)] = &[
+    (
+        1,
+        "CREATE TABLE IF NOT EXISTS plugins (
+             id TEXT PRIMARY KEY,
+             version TEXT NOT NULL,
+             enabled INTEGER NOT NULL DEFAULT 1,
+             installed_at INTEGER NOT NULL
+         );",
+    ),
+    (
+        2,
+        "CREATE TABLE IF NOT EXISTS app_usage (
+             id INTEGER PRIMARY KEY AUTOINCREMENT,
+             item_id TEXT NOT NULL,
+             item_type TEXT NOT NULL,
+             used_at INTEGER NOT NULL
+         );
+         CREATE INDEX IF NOT EXISTS idx_app_usage_item ON app_usage(item_id);",
+    ),
+    (
+        3,
+        "CREATE TABLE IF NOT EXISTS clipboard_history (
+             id INTEGER PRIMARY KEY AUTOINCREMENT,
+             content TEXT,
+             content_type TEXT NOT NULL DEFAULT 'text',
+             is_sensitive INTEGER NOT NULL DEFAULT 0,
+             expires_at INTEGER,
+             created_at INTEGER NOT NULL
+         );
+         CREATE INDEX IF NOT EXISTS idx_clipboard_created ON clipboard_history(created_at);",
+    ),
+    (
+        4,
+        "CREATE TABLE IF NOT EXISTS search_history (
+             id INTEGER PRIMARY KEY AUTOINCREMENT,
+             query TEXT NOT NULL,
+             searched_at INTEGER NOT NULL
+         );",
+    ),
+    (
+        5,
+        "CREATE TABLE IF NOT EXISTS rss_feeds (
+             id INTEGER PRIMARY KEY AUTOINCREMENT,
+             url TEXT NOT NULL UNIQUE,
+             title TEXT,
+             last_fetched_at INTEGER
+         );
+         CREATE TABLE IF NOT EXISTS rss_items (
+             id INTEGER PRIMARY KEY AUTOINCREMENT,
+             feed_id INTEGER NOT NULL REFERENCES rss_feeds(id) ON DELETE CASCADE,
+             guid TEXT NOT NULL,
+             title TEXT NOT NULL,
+             link TEXT,
+             published_at INTEGER,
+             is_read INTEGER NOT NULL DEFAULT 0,
+             UNIQUE(feed_id, guid)
+         );
+         CREATE INDEX IF NOT EXISTS idx_rss_items_unread ON rss_items(is_read, published_at);",
+    ),
+    (
+        6,
+        "CREATE VIRTUAL TABLE IF NOT EXISTS file_contents USING fts5(
+             path UNINDEXED,
+             content,
+             tokenize = 'unicode61'
+         );
+         CREATE TABLE IF NOT EXISTS file_contents_meta (
+             path TEXT PRIMARY KEY,
+             mtime INTEGER NOT NULL,
+             indexed_at INTEGER NOT NULL
+         );",
+    ),
+];
+
+/// 执行所有未应用的迁移
+pub fn run(conn: &mut Connection) -> Result<(), rusqlite::Error> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_version (
+             version INTEGER PRIMARY KEY,
+             applied_at INTEGER NOT NULL
+         );",
+    )?;
+
+    let current: i64 = conn.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_version",
+        [],
+        |row| row.get(0),
+    )?;
+
+    for (version, sql) in MIGRATIONS {
+        if *version <= current {
+            continue;
+        }
+        let tx = conn.transaction()?;
+        tx.execute_batch(sql)?;
+        tx.execute(
+            "INSERT INTO schema_version (version, applied_at) VALUES (?1, ?2)",
+            rusqlite::params![version, chrono::Utc::now().timestamp()],
+        )?;
+        tx.commit()?;
+        log::info!("[Db] applied migration v{}", version);
+    }
+    Ok(())
+}
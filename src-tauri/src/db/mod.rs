@@ -0,0 +1,4 @@
+pub mod backup;
+pub mod maintenance;
+pub mod migrations;
+pub mod pool;
@@ -0,0 +1,65 @@
+//! 应用级 SQLite 连接池
+//!
+//! 用 r2d2 + rusqlite 替换各处临时创建的 Connection。
+//! 所有连接统一开启 WAL、外键约束和 busy timeout，
+//! 初始化时跑一遍 schema 迁移。
+
+use once_cell::sync::OnceCell;
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use std::path::Path;
+use std::time::Duration;
+
+use super::migrations;
+
+/// busy timeout：写锁竞争时等待而不是直接 SQLITE_BUSY
+const BUSY_TIMEOUT_MS: u64 = 5_000;
+/// 池上限；桌面场景下够用，避免句柄浪费
+const MAX_POOL_SIZE: u32 = 8;
+
+pub type DbPool = Pool<SqliteConnectionManager>;
+pub type DbConnection = r2d2::PooledConnection<SqliteConnectionManager>;
+
+static POOL: OnceCell<DbPool> = OnceCell::new();
+
+/// 初始化连接池并执行迁移；应用启动时调用一次
+pub fn init(db_path: &Path) -> Result<(), String> {
+    if POOL.get().is_some() {
+        return Ok(());
+    }
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let manager = SqliteConnectionManager::file(db_path).with_init(|conn| {
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             PRAGMA synchronous = NORMAL;
+             PRAGMA foreign_keys = ON;",
+        )?;
+        conn.busy_timeout(Duration::from_millis(BUSY_TIMEOUT_MS))?;
+        Ok(())
+    });
+    let pool = Pool::builder()
+        .max_size(MAX_POOL_SIZE)
+        .build(manager)
+        .map_err(|e| format!("创建连接池失败: {}", e))?;
+
+    {
+        let mut conn = pool.get().map_err(|e| e.to_string())?;
+        migrations::run(&mut conn).map_err(|e| format!("数据库迁移失败: {}", e))?;
+    }
+
+    POOL.set(pool)
+        .map_err(|_| "连接池已初始化".to_string())?;
+    log::info!("[Db] pool initialized at {}", db_path.display());
+    Ok(())
+}
+
+/// 从池里取一个连接
+pub fn get() -> Result<DbConnection, String> {
+    POOL.get()
+        .ok_or_else(|| "数据库未初始化".to_string())?
+        .get()
+        .map_err(|e| format!("获取数据库连接失败: {}", e))
+}
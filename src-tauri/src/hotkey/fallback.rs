@@ -0,0 +1,86 @@
+//! 快捷键注册失败时的自动回退
+//!
+//! 启动时配置的快捷键若已被占用，按偏好顺序尝试备选快捷键，
+//! 成功后通知用户，并通过 `get_hotkey` 暴露实际生效的快捷键，
+//! 避免设置页显示的和真正注册的不一致。
+
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+use tauri::{AppHandle, Emitter};
+
+/// 回退发生时通知前端弹提示
+pub const HOTKEY_FALLBACK_EVENT: &str = "hotkey://fallback";
+
+/// 备选快捷键偏好顺序；排在前面的更不容易和常见应用冲突
+const FALLBACK_HOTKEYS: &[&str] = &[
+    "Cmd+Shift+K",
+    "Cmd+Shift+Space",
+    "Alt+Space",
+    "Cmd+Alt+K",
+    "Ctrl+Shift+Space",
+];
+
+/// 实际注册成功的快捷键
+static ACTIVE_HOTKEY: Lazy<RwLock<Option<String>>> = Lazy::new(|| RwLock::new(None));
+
+/// 记录注册成功的快捷键
+pub fn set_active(hotkey: &str) {
+    if let Ok(mut active) = ACTIVE_HOTKEY.write() {
+        *active = Some(hotkey.to_string());
+    }
+}
+
+/// 尝试注册：先用配置的快捷键，失败则按顺序回退。
+/// `register` 是实际执行注册的闭包（由全局快捷键管理器提供），
+/// 返回最终生效的快捷键。
+pub fn register_with_fallback<F>(
+    app: &AppHandle,
+    configured: &str,
+    mut register: F,
+) -> Result<String, String>
+where
+    F: FnMut(&str) -> Result<(), String>,
+{
+    match register(configured) {
+        Ok(()) => {
+            set_active(configured);
+            return Ok(configured.to_string());
+        }
+        Err(e) => {
+            log::warn!("[Hotkey] failed to register configured '{}': {}", configured, e);
+        }
+    }
+
+    for candidate in FALLBACK_HOTKEYS {
+        if *candidate == configured {
+            continue;
+        }
+        match register(candidate) {
+            Ok(()) => {
+                set_active(candidate);
+                log::info!("[Hotkey] fell back to '{}'", candidate);
+                let _ = app.emit(
+                    HOTKEY_FALLBACK_EVENT,
+                    serde_json::json!({
+                        "configured": configured,
+                        "active": candidate,
+                    }),
+                );
+                return Ok(candidate.to_string());
+            }
+            Err(e) => {
+                log::warn!("[Hotkey] fallback '{}' also failed: {}", candidate, e);
+            }
+        }
+    }
+    Err(format!(
+        "快捷键 '{}' 及全部备选均注册失败，请在设置中手动选择",
+        configured
+    ))
+}
+
+/// 当前实际生效的快捷键；None 表示全部注册失败
+#[tauri::command]
+pub fn get_hotkey() -> Option<String> {
+    ACTIVE_HOTKEY.read().ok().and_then(|h| h.clone())
+}
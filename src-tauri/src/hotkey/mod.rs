@@ -0,0 +1,3 @@
+pub mod conflict;
+pub mod fallback;
+pub mod toggle_service;
@@ -0,0 +1,88 @@
+//! 窗口切换服务
+//!
+//! 此前 `reregister_hotkey` 会把整个 toggle 闭包再构建一份，
+//! 导致一次按键触发两次显隐。现在窗口显隐逻辑集中在
+//! `WindowToggleService`（tauri 管理状态），启动注册和重注册
+//! 都只引用同一个服务，保证任意时刻只有一个生效的处理器。
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use super::fallback;
+
+/// 窗口切换的统一入口；注册为 tauri 管理状态
+pub struct WindowToggleService {
+    /// 当前生效的处理器代数。快捷键回调携带注册时的代数，
+    /// 与当前值不一致说明是被替换下来的旧处理器，直接忽略。
+    generation: AtomicU64,
+    /// 重注册过程的互斥：注销旧键与注册新键必须原子完成
+    reregister_lock: Mutex<()>,
+}
+
+impl Default for WindowToggleService {
+    fn default() -> Self {
+        Self {
+            generation: AtomicU64::new(0),
+            reregister_lock: Mutex::new(()),
+        }
+    }
+}
+
+impl WindowToggleService {
+    /// 切换主窗口显隐；所有触发路径（快捷键、托盘、命令）都走这里
+    pub fn toggle(&self, app: &AppHandle) {
+        let Some(window) = app.get_webview_window("main") else {
+            log::warn!("[WindowToggle] main window not found");
+            return;
+        };
+        let visible = window.is_visible().unwrap_or(false);
+        let focused = window.is_focused().unwrap_or(false);
+        if visible && focused {
+            let _ = window.hide();
+        } else {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+        crate::db::maintenance::touch_activity();
+    }
+
+    /// 发放新一代处理器的代数；旧代处理器随即失效
+    pub fn next_generation(&self) -> u64 {
+        self.generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// 快捷键回调入口：只有当前代的处理器才真正执行
+    pub fn on_hotkey(&self, app: &AppHandle, generation: u64) {
+        if generation != self.generation.load(Ordering::SeqCst) {
+            log::debug!("[WindowToggle] ignoring stale handler (gen {})", generation);
+            return;
+        }
+        self.toggle(app);
+    }
+
+    /// 重注册快捷键：注销旧的、注册新的，整个过程持锁
+    pub fn reregister<U, R>(
+        &self,
+        app: &AppHandle,
+        new_hotkey: &str,
+        unregister_all: U,
+        register: R,
+    ) -> Result<String, String>
+    where
+        U: FnOnce() -> Result<(), String>,
+        R: FnMut(&str) -> Result<(), String>,
+    {
+        let _guard = self.reregister_lock.lock().map_err(|e| e.to_string())?;
+        unregister_all()?;
+        // 旧处理器立即失效，即使底层注销有延迟也不会重复触发
+        self.next_generation();
+        fallback::register_with_fallback(app, new_hotkey, register)
+    }
+}
+
+/// 手动触发窗口切换（托盘菜单/命令面板用）
+#[tauri::command]
+pub fn toggle_main_window(app: AppHandle, service: tauri::State<'_, WindowToggleService>) {
+    service.toggle(&app);
+}
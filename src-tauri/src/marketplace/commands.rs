@@ -0,0 +1,143 @@
+//! 市场异步命令
+//!
+//! `marketplace_install` / `npm_search` 之前在同步命令里做网络请求和
+//! npm 子进程调用，会把 invoke 冻住。现在都是 async 命令：网络走共享
+//! 客户端，子进程放到阻塞线程池，安装过程发进度事件，并用
+//! `BusyGuard` 防止重入（前端据此显示 spinner）。
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use super::http_client;
+use crate::cmds::busy_guard::BusyGuard;
+
+/// 安装进度事件
+pub const INSTALL_PROGRESS_EVENT: &str = "marketplace://install-progress";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InstallProgress {
+    plugin_id: String,
+    /// "resolving" / "downloading" / "installing" / "validating" / "done"
+    stage: String,
+}
+
+fn emit_progress(app: &AppHandle, plugin_id: &str, stage: &str) {
+    let _ = app.emit(
+        INSTALL_PROGRESS_EVENT,
+        InstallProgress {
+            plugin_id: plugin_id.to_string(),
+            stage: stage.to_string(),
+        },
+    );
+}
+
+/// 搜索 npm 注册表；纯网络 IO，直接 await 共享客户端
+#[tauri::command]
+pub async fn npm_search(app: AppHandle, query: String, size: Option<u32>) -> Result<serde_json::Value, String> {
+    let _guard = BusyGuard::acquire(&app, "npm_search")?;
+    if crate::services::policy::is_feature_disabled("marketplace") {
+        return Err("插件市场已被管理员策略禁用".into());
+    }
+    let registry = crate::services::policy::registry_override().unwrap_or("https://registry.npmjs.org");
+    let encoded: String = url::form_urlencoded::byte_serialize(query.as_bytes()).collect();
+    let url = format!(
+        "{}/-/v1/search?text={}&size={}",
+        registry,
+        encoded,
+        size.unwrap_or(20).min(50)
+    );
+    http_client::get_json(&url).await
+}
+
+/// 安装插件；npm 子进程放到阻塞线程池执行，阶段性发进度事件
+#[tauri::command]
+pub async fn marketplace_install(
+    app: AppHandle,
+    plugin_id: String,
+    plugins_dir: String,
+) -> Result<(), String> {
+    let _guard = BusyGuard::acquire(&app, &format!("install:{}", plugin_id))?;
+    if crate::services::policy::is_feature_disabled("marketplace") {
+        return Err("插件市场已被管理员策略禁用".into());
+    }
+    let token = crate::app::cancellation::CancelToken::register(&format!("install:{}", plugin_id))?;
+
+    emit_progress(&app, &plugin_id, "resolving");
+    let registry = crate::services::policy::registry_override().unwrap_or("https://registry.npmjs.org");
+    // 先确认包存在且兼容，避免 npm 白跑
+    let meta_url = format!("{}/{}/latest", registry, plugin_id);
+    let meta = http_client::get_json(&meta_url).await?;
+    if let Some(etools) = meta.get("etools") {
+        if let Ok(constraints) =
+            serde_json::from_value::<crate::plugins::compat::CompatConstraints>(etools.clone())
+        {
+            crate::plugins::compat::ensure_installable(&plugin_id, &constraints)?;
+        }
+    }
+
+    token.check()?;
+    emit_progress(&app, &plugin_id, "downloading");
+    let plugin_id_clone = plugin_id.clone();
+    let registry = registry.to_string();
+    // npm 子进程是阻塞调用，放到 blocking 池里跑
+    let staging_result = tauri::async_runtime::spawn_blocking(move || {
+        let plugins_root = std::path::Path::new(&plugins_dir);
+        let txn = crate::plugins::install_txn::InstallTransaction::begin(plugins_root, &plugin_id_clone)?;
+
+        // 回放模式下不跑 npm，由夹具展开 staging 内容
+        if crate::services::test_harness::mode()
+            == crate::services::test_harness::MockMode::Replay
+        {
+            let fixture = crate::services::test_harness::replay(
+                "npm-install",
+                &plugin_id_clone,
+            )?;
+            crate::services::test_harness::expand_files(txn.staging_dir(), &fixture)?;
+            return Ok(txn);
+        }
+
+        let status = std::process::Command::new("npm")
+            .args([
+                "install",
+                &plugin_id_clone,
+                "--prefix",
+                &txn.staging_dir().display().to_string(),
+                "--registry",
+                &registry,
+                "--no-audit",
+                "--no-fund",
+            ])
+            .status()
+            .map_err(|e| format!("启动 npm 失败: {}", e))?;
+        if !status.success() {
+            return Err(format!("npm install 退出码 {:?}", status.code()));
+        }
+        if crate::services::test_harness::mode()
+            == crate::services::test_harness::MockMode::Record
+        {
+            let _ = crate::services::test_harness::record_fixture(
+                "npm-install",
+                &plugin_id_clone,
+                &crate::services::test_harness::collect_files(txn.staging_dir())?,
+            );
+        }
+        Ok(txn)
+    })
+    .await
+    .map_err(|e| format!("安装任务异常: {}", e))?;
+
+    let txn = staging_result?;
+    // npm 子进程本身无法中途打断，在阶段边界检查取消；丢弃 txn 即回滚 staging
+    token.check()?;
+    emit_progress(&app, &plugin_id, "validating");
+    txn.validate()?;
+
+    emit_progress(&app, &plugin_id, "installing");
+    txn.commit()?;
+
+    crate::services::audit_log::record(&app, "pluginInstall", &plugin_id);
+    super::service::service().invalidate_listing().await;
+    emit_progress(&app, &plugin_id, "done");
+    Ok(())
+}
@@ -0,0 +1,197 @@
+//! GitHub Release 插件源
+//!
+//! `PluginSource::GithubRelease` 之前只是个枚举值，没有对应的安装路径。
+//! 现在 `plugin_install_from_github(repo, tag)` 会拉取 release 元数据、
+//! 下载 .etpkg 资产并走离线包校验安装；安装记录落盘（repo + tag），
+//! `check_github_updates` 对照最新 release 发现新版本。
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::{AppHandle, Manager};
+
+use super::http_client;
+
+/// 已从 GitHub 安装的插件记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GithubInstall {
+    pub plugin_id: String,
+    /// "owner/repo"
+    pub repo: String,
+    pub tag: String,
+    /// Unix 毫秒
+    pub installed_at: i64,
+}
+
+/// 可用的 GitHub 更新
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GithubUpdate {
+    pub plugin_id: String,
+    pub repo: String,
+    pub current_tag: String,
+    pub latest_tag: String,
+}
+
+fn installs_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("github-installs.json"))
+}
+
+fn load_installs(app: &AppHandle) -> HashMap<String, GithubInstall> {
+    installs_path(app)
+        .ok()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_installs(app: &AppHandle, installs: &HashMap<String, GithubInstall>) -> Result<(), String> {
+    let path = installs_path(app)?;
+    let json = serde_json::to_string_pretty(installs).map_err(|e| e.to_string())?;
+    std::fs::write(&path, json).map_err(|e| format!("保存 GitHub 安装记录失败: {}", e))
+}
+
+/// 校验 "owner/repo" 形式，防止拼进 URL 的路径穿越
+fn validate_repo(repo: &str) -> Result<(), String> {
+    let parts: Vec<&str> = repo.split('/').collect();
+    let valid = parts.len() == 2
+        && parts.iter().all(|p| {
+            !p.is_empty()
+                && p.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("仓库名 '{}' 不是合法的 owner/repo", repo))
+    }
+}
+
+/// 拉取 release 元数据；tag 为 None 时取 latest
+async fn fetch_release(repo: &str, tag: Option<&str>) -> Result<serde_json::Value, String> {
+    let url = match tag {
+        Some(tag) => format!("https://api.github.com/repos/{}/releases/tags/{}", repo, tag),
+        None => format!("https://api.github.com/repos/{}/releases/latest", repo),
+    };
+    http_client::get_json(&url).await
+}
+
+/// 从 release 资产中找 .etpkg（其次 .tgz）的下载地址
+fn pick_asset(release: &serde_json::Value) -> Result<(String, String), String> {
+    let assets = release
+        .get("assets")
+        .and_then(|v| v.as_array())
+        .ok_or("release 无资产列表")?;
+    for ext in [".etpkg", ".tgz"] {
+        for asset in assets {
+            let name = asset.get("name").and_then(|v| v.as_str()).unwrap_or("");
+            if name.ends_with(ext) {
+                let url = asset
+                    .get("browser_download_url")
+                    .and_then(|v| v.as_str())
+                    .ok_or("资产缺少下载地址")?;
+                return Ok((name.to_string(), url.to_string()));
+            }
+        }
+    }
+    Err("release 中没有 .etpkg 或 .tgz 资产".into())
+}
+
+/// 从 GitHub release 安装插件；tag 缺省为最新 release
+#[tauri::command]
+pub async fn plugin_install_from_github(
+    app: AppHandle,
+    repo: String,
+    tag: Option<String>,
+    plugins_dir: String,
+) -> Result<String, String> {
+    validate_repo(&repo)?;
+    if crate::services::policy::is_feature_disabled("marketplace") {
+        return Err("插件市场已被管理员策略禁用".into());
+    }
+
+    let release = fetch_release(&repo, tag.as_deref()).await?;
+    let resolved_tag = release
+        .get("tag_name")
+        .and_then(|v| v.as_str())
+        .ok_or("release 缺少 tag_name")?
+        .to_string();
+    let (asset_name, asset_url) = pick_asset(&release)?;
+    if !asset_name.ends_with(".etpkg") {
+        return Err("目前仅支持 .etpkg 资产；npm tarball 请通过市场安装".into());
+    }
+
+    // 下载到临时文件再走离线包校验
+    let tmp_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("tmp");
+    std::fs::create_dir_all(&tmp_dir).map_err(|e| e.to_string())?;
+    let tmp_path = tmp_dir.join(&asset_name);
+    let resp = http_client::client()
+        .get(&asset_url)
+        .send()
+        .await
+        .map_err(|e| format!("下载资产失败: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("GitHub 返回 {}", resp.status()));
+    }
+    let bytes = resp.bytes().await.map_err(|e| format!("下载中断: {}", e))?;
+    std::fs::write(&tmp_path, &bytes).map_err(|e| format!("写入临时文件失败: {}", e))?;
+
+    let result = crate::plugins::etpkg::plugin_install_from_etpkg(
+        app.clone(),
+        tmp_path.display().to_string(),
+        plugins_dir,
+    );
+    let _ = std::fs::remove_file(&tmp_path);
+    let plugin_id = result?;
+
+    let mut installs = load_installs(&app);
+    installs.insert(
+        plugin_id.clone(),
+        GithubInstall {
+            plugin_id: plugin_id.clone(),
+            repo,
+            tag: resolved_tag,
+            installed_at: chrono::Utc::now().timestamp_millis(),
+        },
+    );
+    save_installs(&app, &installs)?;
+    Ok(plugin_id)
+}
+
+/// 检查所有 GitHub 安装的插件是否有新 release
+#[tauri::command]
+pub async fn check_github_updates(app: AppHandle) -> Result<Vec<GithubUpdate>, String> {
+    let installs = load_installs(&app);
+    let mut updates = Vec::new();
+    for install in installs.values() {
+        let latest = match fetch_release(&install.repo, None).await {
+            Ok(release) => release,
+            Err(e) => {
+                log::warn!("[GithubSource] update check for {} failed: {}", install.repo, e);
+                continue;
+            }
+        };
+        let latest_tag = latest
+            .get("tag_name")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if !latest_tag.is_empty() && latest_tag != install.tag {
+            updates.push(GithubUpdate {
+                plugin_id: install.plugin_id.clone(),
+                repo: install.repo.clone(),
+                current_tag: install.tag.clone(),
+                latest_tag,
+            });
+        }
+    }
+    Ok(updates)
+}
@@ -0,0 +1,148 @@
+//! 市场共享 HTTP 客户端
+//!
+//! 所有注册表请求走同一个带连接池的 reqwest 客户端：
+//! 统一超时、瞬时错误指数退避重试、按 host 限流。
+//! 这样弱网环境下请求会快速失败重试，而不是长时间占着市场锁。
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// 单次请求超时
+const REQUEST_TIMEOUT_SECS: u64 = 15;
+/// 连接超时
+const CONNECT_TIMEOUT_SECS: u64 = 5;
+/// 最大重试次数（不含首次请求）
+const MAX_RETRIES: u32 = 3;
+/// 首次重试的退避基数（毫秒），之后按 2 的幂递增
+const BACKOFF_BASE_MS: u64 = 300;
+/// 同一 host 两次请求之间的最小间隔
+const PER_HOST_MIN_INTERVAL_MS: u64 = 100;
+
+fn build_client() -> reqwest::Client {
+    let builder = reqwest::Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .connect_timeout(Duration::from_secs(CONNECT_TIMEOUT_SECS))
+        .pool_max_idle_per_host(4)
+        .user_agent(concat!("etools/", env!("CARGO_PKG_VERSION")));
+    crate::services::proxy::apply(builder)
+        .build()
+        .expect("failed to build shared http client")
+}
+
+/// 全局共享客户端；reqwest 内部自带连接池。
+/// 代理设置变更时通过 `rebuild_client` 原地替换。
+static HTTP_CLIENT: Lazy<RwLock<reqwest::Client>> = Lazy::new(|| RwLock::new(build_client()));
+
+/// 获取共享客户端的克隆（reqwest::Client 内部是 Arc，克隆代价很小）
+pub fn client() -> reqwest::Client {
+    HTTP_CLIENT
+        .read()
+        .map(|c| c.clone())
+        .unwrap_or_else(|_| build_client())
+}
+
+/// 代理配置变更后重建客户端
+pub fn rebuild_client() {
+    if let Ok(mut guard) = HTTP_CLIENT.write() {
+        *guard = build_client();
+    }
+}
+
+/// 各 host 上一次放行请求的时间
+static HOST_LAST_REQUEST: Lazy<Mutex<HashMap<String, Instant>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 按 host 限流：距上次请求不足最小间隔时先等待
+async fn throttle_host(host: &str) {
+    let wait = {
+        let mut map = match HOST_LAST_REQUEST.lock() {
+            Ok(m) => m,
+            Err(_) => return,
+        };
+        let now = Instant::now();
+        let wait = map
+            .get(host)
+            .and_then(|last| {
+                let elapsed = now.duration_since(*last);
+                let min = Duration::from_millis(PER_HOST_MIN_INTERVAL_MS);
+                (elapsed < min).then(|| min - elapsed)
+            })
+            .unwrap_or(Duration::ZERO);
+        map.insert(host.to_string(), now + wait);
+        wait
+    };
+    if !wait.is_zero() {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// 该错误/状态码是否值得重试
+fn is_transient(result: &Result<reqwest::Response, reqwest::Error>) -> bool {
+    match result {
+        Err(e) => e.is_timeout() || e.is_connect(),
+        Ok(resp) => {
+            let status = resp.status();
+            status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+        }
+    }
+}
+
+/// 发起带重试与限流的 GET 请求，返回响应体文本
+pub async fn get_with_retry(url: &str) -> Result<String, String> {
+    // 回放模式下所有出口 HTTP 都走夹具，缺失即报错
+    if crate::services::test_harness::mode() == crate::services::test_harness::MockMode::Replay {
+        return crate::services::test_harness::replay("http", url).map(|v| match v {
+            serde_json::Value::String(body) => body,
+            other => other.to_string(),
+        });
+    }
+
+    let host = reqwest::Url::parse(url)
+        .map_err(|e| format!("无效 URL {}: {}", url, e))?
+        .host_str()
+        .unwrap_or("unknown")
+        .to_string();
+
+    let mut attempt = 0u32;
+    loop {
+        throttle_host(&host).await;
+        let result = client().get(url).send().await;
+
+        if is_transient(&result) && attempt < MAX_RETRIES {
+            let backoff = Duration::from_millis(BACKOFF_BASE_MS * (1u64 << attempt));
+            log::warn!(
+                "[MarketplaceHttp] transient failure for {} (attempt {}), retrying in {:?}",
+                url,
+                attempt + 1,
+                backoff
+            );
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+            continue;
+        }
+
+        let resp = result.map_err(|e| format!("请求失败: {}", e))?;
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(format!("注册表返回 {}: {}", status, url));
+        }
+        let body = resp.text().await.map_err(|e| format!("读取响应失败: {}", e))?;
+        if crate::services::test_harness::mode() == crate::services::test_harness::MockMode::Record
+        {
+            let _ = crate::services::test_harness::record_fixture(
+                "http",
+                url,
+                &serde_json::Value::String(body.clone()),
+            );
+        }
+        return Ok(body);
+    }
+}
+
+/// GET 并反序列化为 JSON
+pub async fn get_json(url: &str) -> Result<serde_json::Value, String> {
+    let body = get_with_retry(url).await?;
+    serde_json::from_str(&body).map_err(|e| format!("响应不是合法 JSON: {}", e))
+}
@@ -0,0 +1,7 @@
+pub mod commands;
+pub mod github_source;
+pub mod http_client;
+pub mod offline_cache;
+pub mod ratings;
+pub mod service;
+pub mod update_checker;
@@ -0,0 +1,113 @@
+//! 插件市场离线缓存
+//!
+//! 每次成功的市场请求都会把结果落盘；断网（飞行模式）时
+//! `marketplace_list` / `marketplace_get_plugin` 返回缓存数据并带上
+//! `stale: true` 标记，而不是直接报错。
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+/// 缓存条目超过该时长仍会返回，但会提示用户刷新（毫秒）
+const STALE_WARN_AGE_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// 带新鲜度标记的市场响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedResponse {
+    pub data: Value,
+    /// true 表示来自离线缓存而非实时请求
+    pub stale: bool,
+    /// 缓存写入时间（Unix 毫秒），实时数据为当前时间
+    pub fetched_at: i64,
+}
+
+fn cache_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("marketplace-cache");
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// 缓存文件名：列表用固定名，详情按插件 ID 区分
+fn cache_path(app: &AppHandle, key: &str) -> Result<PathBuf, String> {
+    // 插件 ID 可能含 @scope/ 分隔符，落盘前做文件名安全化
+    let safe: String = key
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+        .collect();
+    Ok(cache_dir(app)?.join(format!("{}.json", safe)))
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    fetched_at: i64,
+    data: Value,
+}
+
+/// 写入缓存；失败只记日志，不影响正常响应
+pub fn store(app: &AppHandle, key: &str, data: &Value) {
+    let write = || -> Result<(), String> {
+        let path = cache_path(app, key)?;
+        let file = CacheFile {
+            fetched_at: chrono::Utc::now().timestamp_millis(),
+            data: data.clone(),
+        };
+        let json = serde_json::to_string(&file).map_err(|e| e.to_string())?;
+        fs::write(&path, json).map_err(|e| e.to_string())
+    };
+    if let Err(e) = write() {
+        log::warn!("[MarketplaceCache] failed to store cache for '{}': {}", key, e);
+    }
+}
+
+/// 读取缓存；不存在或损坏时返回 None
+pub fn load(app: &AppHandle, key: &str) -> Option<CachedResponse> {
+    let path = cache_path(app, key).ok()?;
+    let content = fs::read_to_string(&path).ok()?;
+    let file: CacheFile = serde_json::from_str(&content).ok()?;
+    let age = chrono::Utc::now().timestamp_millis() - file.fetched_at;
+    if age > STALE_WARN_AGE_MS {
+        log::info!("[MarketplaceCache] cache for '{}' is {}h old", key, age / 3_600_000);
+    }
+    Some(CachedResponse {
+        data: file.data,
+        stale: true,
+        fetched_at: file.fetched_at,
+    })
+}
+
+/// 包装市场请求：成功则写缓存返回实时数据，网络失败则回退到缓存
+pub fn with_fallback<F>(app: &AppHandle, key: &str, fetch: F) -> Result<CachedResponse, String>
+where
+    F: FnOnce() -> Result<Value, String>,
+{
+    match fetch() {
+        Ok(data) => {
+            store(app, key, &data);
+            Ok(CachedResponse {
+                data,
+                stale: false,
+                fetched_at: chrono::Utc::now().timestamp_millis(),
+            })
+        }
+        Err(network_err) => load(app, key).ok_or_else(|| {
+            format!("网络不可用且无本地缓存: {}", network_err)
+        }),
+    }
+}
+
+/// 清空市场缓存（存储管理页调用）
+#[tauri::command]
+pub fn clear_marketplace_cache(app: AppHandle) -> Result<(), String> {
+    let dir = cache_dir(&app)?;
+    fs::remove_dir_all(&dir).map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    log::info!("[MarketplaceCache] cache cleared");
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! 市场评分服务集成（可选）
+//!
+//! npm 注册表没有评分数据，`MarketplacePlugin` 的 rating 字段一直是 0。
+//! 现在支持配置一个评分服务地址：列表加载时批量拉取 rating /
+//! ratingCount 填充；提交评分用本地匿名身份（首次生成并落盘的随机
+//! ID），同一身份对同一插件重复提交视为修改。未配置地址时全部静默跳过。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+use super::http_client;
+use super::service::MarketplacePlugin;
+
+/// 评分服务基础地址；None 表示功能关闭
+static RATINGS_URL: Lazy<RwLock<Option<String>>> = Lazy::new(|| RwLock::new(None));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginRating {
+    pub rating: f64,
+    pub rating_count: u64,
+}
+
+/// 从设置/策略加载评分服务地址（启动时调用）
+pub fn configure(url: Option<String>) {
+    if let Ok(mut current) = RATINGS_URL.write() {
+        *current = url.filter(|u| !u.trim().is_empty());
+    }
+}
+
+fn base_url() -> Option<String> {
+    RATINGS_URL.read().ok()?.clone()
+}
+
+/// 本地匿名评分身份：首次生成 32 字节随机量的哈希并落盘复用
+fn local_identity(app: &AppHandle) -> Result<String, String> {
+    let path = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("rating-identity");
+    if let Ok(existing) = std::fs::read_to_string(&path) {
+        let trimmed = existing.trim().to_string();
+        if !trimmed.is_empty() {
+            return Ok(trimmed);
+        }
+    }
+    let mut hasher = Sha256::new();
+    hasher.update(std::process::id().to_le_bytes());
+    hasher.update(chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0).to_le_bytes());
+    let id = format!("{:x}", hasher.finalize());
+    std::fs::write(&path, &id).map_err(|e| format!("保存评分身份失败: {}", e))?;
+    Ok(id)
+}
+
+/// 批量拉取评分并填充到列表条目；服务不可用时保持 0 值不报错
+pub async fn apply(plugins: &mut [MarketplacePlugin]) {
+    let Some(base) = base_url() else { return };
+    if plugins.is_empty() {
+        return;
+    }
+    let ids: Vec<&str> = plugins.iter().map(|p| p.id.as_str()).collect();
+    let encoded: String = url::form_urlencoded::byte_serialize(ids.join(",").as_bytes()).collect();
+    let url = format!("{}/ratings?ids={}", base.trim_end_matches('/'), encoded);
+    let data = match http_client::get_json(&url).await {
+        Ok(data) => data,
+        Err(e) => {
+            log::warn!("[Ratings] fetch failed, keeping zero ratings: {}", e);
+            return;
+        }
+    };
+    let Ok(ratings) = serde_json::from_value::<HashMap<String, PluginRating>>(data) else {
+        log::warn!("[Ratings] unexpected response shape");
+        return;
+    };
+    for plugin in plugins.iter_mut() {
+        if let Some(r) = ratings.get(&plugin.id) {
+            plugin.rating = r.rating;
+            plugin.rating_count = r.rating_count;
+        }
+    }
+}
+
+/// 提交评分（1-5 星）；需要已配置评分服务
+#[tauri::command]
+pub async fn submit_plugin_rating(
+    app: AppHandle,
+    plugin_id: String,
+    stars: u8,
+) -> Result<(), String> {
+    if !(1..=5).contains(&stars) {
+        return Err("评分必须是 1-5 星".into());
+    }
+    let base = base_url().ok_or("未配置评分服务")?;
+    let identity = local_identity(&app)?;
+    let url = format!("{}/ratings/{}", base.trim_end_matches('/'), plugin_id);
+    let resp = http_client::client()
+        .post(&url)
+        .json(&serde_json::json!({ "identity": identity, "stars": stars }))
+        .send()
+        .await
+        .map_err(|e| format!("提交评分失败: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("评分服务返回 {}", resp.status()));
+    }
+    super::service::service().invalidate_listing().await;
+    Ok(())
+}
+
+/// 配置评分服务地址（设置页；传空字符串关闭）
+#[tauri::command]
+pub fn set_ratings_service_url(url: String) -> Result<(), String> {
+    let trimmed = url.trim();
+    if !trimmed.is_empty() && !trimmed.starts_with("https://") {
+        return Err("评分服务地址必须是 https".into());
+    }
+    configure(if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    });
+    Ok(())
+}
@@ -0,0 +1,151 @@
+//! 市场服务（无全局互斥锁版本）
+//!
+//! 旧实现把整个服务包在 `OnceLock<Mutex<MarketplaceService>>` 里，
+//! 一次慢安装会把 `marketplace_list` 也堵死。现在服务本身无共享可变
+//! 状态，以 `Arc` 共享；唯一的缓存用 `tokio::sync::RwLock` 做内部
+//! 可变性，读路径不被写路径长期占用，独立操作可以并发执行。
+//! 安装流程完全不经过本服务的锁（见 `commands.rs`）。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::http_client;
+use super::offline_cache;
+
+/// 列表缓存有效期
+const LISTING_TTL: Duration = Duration::from_secs(300);
+
+/// 市场插件条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketplacePlugin {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub version: String,
+    #[serde(default)]
+    pub downloads: u64,
+    /// 平均评分；暂无评分服务时为 0
+    #[serde(default)]
+    pub rating: f64,
+    #[serde(default)]
+    pub rating_count: u64,
+}
+
+pub struct MarketplaceService {
+    /// 列表缓存；RwLock 读不阻塞读，刷新时才短暂独占
+    listing_cache: tokio::sync::RwLock<Option<(Instant, Vec<MarketplacePlugin>)>>,
+    /// 刷新去重：并发的缓存失效请求只打一次注册表
+    refresh_lock: tokio::sync::Mutex<()>,
+}
+
+static SERVICE: Lazy<Arc<MarketplaceService>> = Lazy::new(|| {
+    Arc::new(MarketplaceService {
+        listing_cache: tokio::sync::RwLock::new(None),
+        refresh_lock: tokio::sync::Mutex::new(()),
+    })
+});
+
+/// 共享服务句柄
+pub fn service() -> Arc<MarketplaceService> {
+    SERVICE.clone()
+}
+
+impl MarketplaceService {
+    /// 列出市场插件；命中缓存直接返回，过期时单飞刷新
+    pub async fn list(&self, app: &tauri::AppHandle) -> Result<Vec<MarketplacePlugin>, String> {
+        {
+            let cache = self.listing_cache.read().await;
+            if let Some((fetched_at, plugins)) = cache.as_ref() {
+                if fetched_at.elapsed() < LISTING_TTL {
+                    return Ok(plugins.clone());
+                }
+            }
+        }
+
+        let _refresh = self.refresh_lock.lock().await;
+        // 拿到刷新锁后再查一次：可能别的调用刚刷新完
+        {
+            let cache = self.listing_cache.read().await;
+            if let Some((fetched_at, plugins)) = cache.as_ref() {
+                if fetched_at.elapsed() < LISTING_TTL {
+                    return Ok(plugins.clone());
+                }
+            }
+        }
+
+        let plugins = self.fetch_listing(app).await?;
+        *self.listing_cache.write().await = Some((Instant::now(), plugins.clone()));
+        Ok(plugins)
+    }
+
+    async fn fetch_listing(&self, app: &tauri::AppHandle) -> Result<Vec<MarketplacePlugin>, String> {
+        let registry =
+            crate::services::policy::registry_override().unwrap_or("https://registry.npmjs.org");
+        let url = format!("{}/-/v1/search?text=keywords:etools-plugin&size=100", registry);
+        // 异步请求成功写离线缓存，失败回退读缓存（with_fallback 是同步闭包，手动展开）
+        let data = match http_client::get_json(&url).await {
+            Ok(data) => {
+                offline_cache::store(app, "listing", &data);
+                data
+            }
+            Err(network_err) => {
+                offline_cache::load(app, "listing")
+                    .ok_or_else(|| format!("网络不可用且无本地缓存: {}", network_err))?
+                    .data
+            }
+        };
+
+        let objects = data
+            .get("objects")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+        let mut plugins: Vec<MarketplacePlugin> = objects
+            .iter()
+            .filter_map(|obj| {
+                let pkg = obj.get("package")?;
+                Some(MarketplacePlugin {
+                    id: pkg.get("name")?.as_str()?.to_string(),
+                    name: pkg
+                        .get("displayName")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or(pkg.get("name")?.as_str()?)
+                        .to_string(),
+                    description: pkg
+                        .get("description")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                    version: pkg.get("version")?.as_str()?.to_string(),
+                    downloads: obj
+                        .get("downloads")
+                        .and_then(|d| d.get("monthly"))
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(0),
+                    rating: 0.0,
+                    rating_count: 0,
+                })
+            })
+            .collect();
+        super::ratings::apply(&mut plugins).await;
+        Ok(plugins)
+    }
+
+    /// 主动失效缓存（安装/卸载后调用）
+    pub async fn invalidate_listing(&self) {
+        *self.listing_cache.write().await = None;
+    }
+}
+
+/// 市场插件列表；与安装互不阻塞
+#[tauri::command]
+pub async fn marketplace_list(app: tauri::AppHandle) -> Result<Vec<MarketplacePlugin>, String> {
+    if crate::services::policy::is_feature_disabled("marketplace") {
+        return Err("插件市场已被管理员策略禁用".into());
+    }
+    service().list(&app).await
+}
@@ -0,0 +1,131 @@
+//! 插件更新检查
+//!
+//! 旧实现拿依赖声明字符串（经常是 "latest"）和注册表版本比较，还直接调 curl。
+//! 现在从每个插件目录的 package.json 读取实际安装版本，走共享 HTTP 客户端
+//! 查询注册表，并做正确的语义化版本比较，返回更新级别（patch/minor/major）。
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::http_client;
+
+/// 单个插件的更新检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginUpdate {
+    pub plugin_id: String,
+    pub installed_version: String,
+    pub latest_version: String,
+    /// "patch" / "minor" / "major"
+    pub update_type: String,
+}
+
+/// 语义化版本号（只处理 x.y.z + 可选预发布标签，etools 插件不用 build metadata）
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SemVer {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl SemVer {
+    /// 解析版本字符串；容忍前缀 v 与预发布后缀
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let core = input
+            .trim()
+            .trim_start_matches(['v', '=', '^', '~'])
+            .split(['-', '+'])
+            .next()
+            .unwrap_or("");
+        let mut parts = core.split('.');
+        let parse_part = |p: Option<&str>, name: &str| -> Result<u64, String> {
+            p.unwrap_or("0")
+                .parse()
+                .map_err(|_| format!("无效版本号 '{}'（{} 部分）", input, name))
+        };
+        Ok(Self {
+            major: parse_part(parts.next(), "major")?,
+            minor: parse_part(parts.next(), "minor")?,
+            patch: parse_part(parts.next(), "patch")?,
+        })
+    }
+
+    /// 升级到 `other` 属于哪一级更新
+    pub fn update_type_to(&self, other: &SemVer) -> Option<&'static str> {
+        if other <= self {
+            return None;
+        }
+        if other.major > self.major {
+            Some("major")
+        } else if other.minor > self.minor {
+            Some("minor")
+        } else {
+            Some("patch")
+        }
+    }
+}
+
+/// 从插件目录的 package.json 读取实际安装版本
+fn installed_version(plugin_dir: &Path) -> Result<String, String> {
+    let package_json = plugin_dir.join("package.json");
+    let content = fs::read_to_string(&package_json)
+        .map_err(|e| format!("读取 {} 失败: {}", package_json.display(), e))?;
+    let parsed: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("package.json 解析失败: {}", e))?;
+    parsed
+        .get("version")
+        .and_then(|v| v.as_str())
+        .map(String::from)
+        .ok_or_else(|| format!("{} 缺少 version 字段", package_json.display()))
+}
+
+/// 查询注册表获取最新版本号
+async fn registry_latest_version(plugin_id: &str) -> Result<String, String> {
+    let url = format!("https://registry.npmjs.org/{}/latest", plugin_id);
+    let data = http_client::get_json(&url).await?;
+    data.get("version")
+        .and_then(|v| v.as_str())
+        .map(String::from)
+        .ok_or_else(|| format!("注册表响应缺少 version: {}", plugin_id))
+}
+
+/// 检查单个插件是否有更新；无更新返回 None
+pub async fn check_plugin(plugin_id: &str, plugin_dir: &Path) -> Result<Option<PluginUpdate>, String> {
+    let installed = installed_version(plugin_dir)?;
+    let latest = registry_latest_version(plugin_id).await?;
+
+    let installed_v = SemVer::parse(&installed)?;
+    let latest_v = SemVer::parse(&latest)?;
+    Ok(installed_v.update_type_to(&latest_v).map(|update_type| PluginUpdate {
+        plugin_id: plugin_id.to_string(),
+        installed_version: installed,
+        latest_version: latest,
+        update_type: update_type.to_string(),
+    }))
+}
+
+/// 检查全部已安装插件的更新
+#[tauri::command]
+pub async fn check_updates(plugins_dir: String) -> Result<Vec<PluginUpdate>, String> {
+    let dir = Path::new(&plugins_dir);
+    let entries = fs::read_dir(dir).map_err(|e| format!("读取插件目录失败: {}", e))?;
+
+    let mut updates = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() || !path.join("package.json").exists() {
+            continue;
+        }
+        let plugin_id = entry.file_name().to_string_lossy().to_string();
+        match check_plugin(&plugin_id, &path).await {
+            Ok(Some(update)) => updates.push(update),
+            Ok(None) => {}
+            Err(e) => {
+                // 单个插件检查失败不阻断整体：记录后继续
+                log::warn!("[UpdateChecker] failed to check {}: {}", plugin_id, e);
+            }
+        }
+    }
+    Ok(updates)
+}
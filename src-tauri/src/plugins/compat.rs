@@ -0,0 +1,139 @@
+//! ETP 元数据兼容性约束
+//!
+//! `EtoolsMetadata` 新增 `minAppVersion`、`platforms`、`arch` 三个可选字段
+//! （以 `CompatConstraints` 形式 flatten 进元数据）。市场列表据此隐藏或
+//! 标记不兼容插件，安装器在安装前调用 `check_compatibility` 并拒绝安装。
+
+use serde::{Deserialize, Serialize};
+
+/// 当前应用版本（编译期注入）
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 插件声明的运行环境约束；全部字段可缺省，缺省表示不限
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatConstraints {
+    /// 要求的最低应用版本（语义化版本号，如 "0.4.0"）
+    #[serde(default)]
+    pub min_app_version: Option<String>,
+    /// 支持的平台列表："macos" / "windows" / "linux"
+    #[serde(default)]
+    pub platforms: Vec<String>,
+    /// 支持的 CPU 架构："aarch64" / "x86_64"
+    #[serde(default)]
+    pub arch: Vec<String>,
+}
+
+/// 兼容性检查结果；`reason` 供市场卡片与安装错误弹窗直接展示
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatReport {
+    pub compatible: bool,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// 解析 "x.y.z" 为可比较的元组；无法解析时返回 None
+fn parse_version(v: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = v.trim_start_matches('v').splitn(3, '.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+    let patch = parts
+        .next()
+        .unwrap_or("0")
+        .split(|c: char| !c.is_ascii_digit())
+        .next()
+        .unwrap_or("0")
+        .parse()
+        .ok()?;
+    Some((major, minor, patch))
+}
+
+fn current_platform() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "macos"
+    } else if cfg!(target_os = "windows") {
+        "windows"
+    } else {
+        "linux"
+    }
+}
+
+fn current_arch() -> &'static str {
+    std::env::consts::ARCH
+}
+
+/// 检查约束是否与当前运行环境兼容
+pub fn check(constraints: &CompatConstraints) -> CompatReport {
+    if let Some(min) = &constraints.min_app_version {
+        match (parse_version(min), parse_version(APP_VERSION)) {
+            (Some(required), Some(current)) if current < required => {
+                return CompatReport {
+                    compatible: false,
+                    reason: Some(format!(
+                        "需要 etools {} 或更高版本（当前 {}），请先更新应用",
+                        min, APP_VERSION
+                    )),
+                };
+            }
+            (None, _) => {
+                // 无法解析的约束按不兼容处理，避免静默放行
+                return CompatReport {
+                    compatible: false,
+                    reason: Some(format!("minAppVersion '{}' 不是合法的版本号", min)),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    if !constraints.platforms.is_empty() {
+        let platform = current_platform();
+        if !constraints.platforms.iter().any(|p| p == platform) {
+            return CompatReport {
+                compatible: false,
+                reason: Some(format!(
+                    "该插件不支持当前平台 {}（支持: {}）",
+                    platform,
+                    constraints.platforms.join(", ")
+                )),
+            };
+        }
+    }
+
+    if !constraints.arch.is_empty() {
+        let arch = current_arch();
+        if !constraints.arch.iter().any(|a| a == arch) {
+            return CompatReport {
+                compatible: false,
+                reason: Some(format!(
+                    "该插件不支持当前架构 {}（支持: {}）",
+                    arch,
+                    constraints.arch.join(", ")
+                )),
+            };
+        }
+    }
+
+    CompatReport {
+        compatible: true,
+        reason: None,
+    }
+}
+
+/// 安装前的硬性校验；不兼容时返回 Err，错误信息可直接展示
+pub fn ensure_installable(plugin_id: &str, constraints: &CompatConstraints) -> Result<(), String> {
+    let report = check(constraints);
+    if !report.compatible {
+        let reason = report.reason.unwrap_or_else(|| "不兼容".into());
+        log::warn!("[Compat] refusing to install {}: {}", plugin_id, reason);
+        return Err(format!("无法安装 {}: {}", plugin_id, reason));
+    }
+    Ok(())
+}
+
+/// 市场列表批量检查：返回每个插件的兼容报告
+#[tauri::command]
+pub fn check_plugin_compatibility(constraints: CompatConstraints) -> CompatReport {
+    check(&constraints)
+}
@@ -0,0 +1,181 @@
+//! 插件深链与协议注册
+//!
+//! 插件可以声明 `etools://plugin/<id>/...` 路由；经用户同意后还可以
+//! 认领外部 URL scheme（如 `magnet:`、`obsidian://`）或文件类型关联，
+//! 点击对应链接时由本模块路由到插件处理器。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tauri::{AppHandle, Emitter};
+
+/// 深链分发事件，插件运行时监听
+pub const DEEP_LINK_EVENT: &str = "plugin://deep-link";
+
+/// 插件声明的外部关联（scheme 或文件扩展名）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalAssociation {
+    pub plugin_id: String,
+    /// "scheme"（如 magnet、obsidian）或 "extension"（如 torrent、md）
+    pub kind: String,
+    pub value: String,
+    /// 是否已获用户明确同意
+    pub approved: bool,
+}
+
+#[derive(Debug, Default)]
+struct DeepLinkRegistry {
+    /// 插件内部路由：plugin_id -> 已声明的路由前缀
+    routes: HashMap<String, Vec<String>>,
+    /// 外部 scheme/文件类型关联
+    associations: Vec<ExternalAssociation>,
+}
+
+static REGISTRY: Lazy<RwLock<DeepLinkRegistry>> =
+    Lazy::new(|| RwLock::new(DeepLinkRegistry::default()));
+
+/// 注册插件深链路由（插件加载时由 plugin_service 调用）
+pub fn register_routes(plugin_id: &str, routes: Vec<String>) -> Result<(), String> {
+    for route in &routes {
+        if route.contains("..") || route.starts_with('/') {
+            return Err(format!("非法路由前缀: {}", route));
+        }
+    }
+    let mut registry = REGISTRY.write().map_err(|e| e.to_string())?;
+    registry.routes.insert(plugin_id.to_string(), routes);
+    Ok(())
+}
+
+/// 注销插件的路由与关联（卸载时调用）
+pub fn unregister_plugin(plugin_id: &str) {
+    if let Ok(mut registry) = REGISTRY.write() {
+        registry.routes.remove(plugin_id);
+        registry.associations.retain(|a| a.plugin_id != plugin_id);
+    }
+}
+
+/// 解析 etools:// URL，返回 (plugin_id, 路由余下部分)
+fn parse_plugin_url(url: &str) -> Option<(String, String)> {
+    let rest = url.strip_prefix("etools://plugin/")?;
+    let (plugin_id, path) = match rest.split_once('/') {
+        Some((id, path)) => (id, path),
+        None => (rest, ""),
+    };
+    if plugin_id.is_empty() {
+        return None;
+    }
+    Some((plugin_id.to_string(), path.to_string()))
+}
+
+/// 处理收到的深链 URL；返回是否已被某个处理器接收
+pub fn dispatch_url(app: &AppHandle, url: &str) -> Result<bool, String> {
+    let registry = REGISTRY.read().map_err(|e| e.to_string())?;
+
+    // 1. etools://plugin/<id>/... 内部路由
+    if let Some((plugin_id, path)) = parse_plugin_url(url) {
+        let matched = registry
+            .routes
+            .get(&plugin_id)
+            .map(|routes| routes.iter().any(|r| path.starts_with(r.as_str()) || r == "*"))
+            .unwrap_or(false);
+        if !matched {
+            log::warn!("[DeepLink] no route in plugin {} for path '{}'", plugin_id, path);
+            return Ok(false);
+        }
+        app.emit(
+            DEEP_LINK_EVENT,
+            serde_json::json!({ "pluginId": plugin_id, "path": path, "url": url }),
+        )
+        .map_err(|e| e.to_string())?;
+        return Ok(true);
+    }
+
+    // 2. 外部 scheme 关联（仅分发给已获同意的关联）
+    if let Some(scheme) = url.split(':').next() {
+        if let Some(assoc) = registry
+            .associations
+            .iter()
+            .find(|a| a.kind == "scheme" && a.value == scheme && a.approved)
+        {
+            app.emit(
+                DEEP_LINK_EVENT,
+                serde_json::json!({ "pluginId": assoc.plugin_id, "path": "", "url": url }),
+            )
+            .map_err(|e| e.to_string())?;
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// 插件申请外部关联；写入注册表但处于未同意状态，等待用户确认
+#[tauri::command]
+pub fn request_external_association(
+    plugin_id: String,
+    kind: String,
+    value: String,
+) -> Result<(), String> {
+    if kind != "scheme" && kind != "extension" {
+        return Err(format!("未知关联类型: {}", kind));
+    }
+    if value == "etools" || value == "http" || value == "https" {
+        return Err(format!("scheme '{}' 不允许被插件认领", value));
+    }
+    let mut registry = REGISTRY.write().map_err(|e| e.to_string())?;
+    if registry
+        .associations
+        .iter()
+        .any(|a| a.kind == kind && a.value == value && a.plugin_id != plugin_id)
+    {
+        return Err(format!("'{}' 已被其他插件认领", value));
+    }
+    registry.associations.retain(|a| !(a.plugin_id == plugin_id && a.kind == kind && a.value == value));
+    registry.associations.push(ExternalAssociation {
+        plugin_id,
+        kind,
+        value,
+        approved: false,
+    });
+    Ok(())
+}
+
+/// 用户在确认弹窗中批准/拒绝某个关联
+#[tauri::command]
+pub fn resolve_external_association(
+    plugin_id: String,
+    kind: String,
+    value: String,
+    approved: bool,
+) -> Result<(), String> {
+    let mut registry = REGISTRY.write().map_err(|e| e.to_string())?;
+    if approved {
+        if let Some(assoc) = registry
+            .associations
+            .iter_mut()
+            .find(|a| a.plugin_id == plugin_id && a.kind == kind && a.value == value)
+        {
+            assoc.approved = true;
+            log::info!("[DeepLink] association {}:{} approved for {}", kind, value, plugin_id);
+        }
+    } else {
+        registry
+            .associations
+            .retain(|a| !(a.plugin_id == plugin_id && a.kind == kind && a.value == value));
+    }
+    Ok(())
+}
+
+/// 列出全部外部关联（设置页展示）
+#[tauri::command]
+pub fn list_external_associations() -> Result<Vec<ExternalAssociation>, String> {
+    let registry = REGISTRY.read().map_err(|e| e.to_string())?;
+    Ok(registry.associations.clone())
+}
+
+/// 由系统深链回调触发的分发入口
+#[tauri::command]
+pub fn handle_deep_link(app: AppHandle, url: String) -> Result<bool, String> {
+    dispatch_url(&app, &url)
+}
@@ -0,0 +1,293 @@
+//! .etpkg 单文件插件包格式
+//!
+//! zip 容器 + 根部 `etpkg.json` 清单：清单逐文件记录 SHA-256，
+//! 自身可附 ed25519 签名（对清单去掉 signature 字段后的规范化 JSON
+//! 签名）。安装器可以完全离线地验证并解压，开发者用 `plugin_pack`
+//! 把 dev-link 的插件目录导出成 .etpkg 分发。
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+/// 清单文件名（必须位于包根部）
+pub const MANIFEST_NAME: &str = "etpkg.json";
+/// 当前格式版本
+const FORMAT_VERSION: u32 = 1;
+/// 单包解压后大小上限：200 MB（防 zip 炸弹）
+const MAX_UNPACKED_BYTES: u64 = 200 * 1024 * 1024;
+
+/// 清单中的单个文件条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackedFile {
+    /// 包内相对路径（正斜杠分隔）
+    pub path: String,
+    /// 内容 SHA-256（hex）
+    pub sha256: String,
+    pub size: u64,
+}
+
+/// .etpkg 清单
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtpkgManifest {
+    pub format_version: u32,
+    pub plugin_id: String,
+    pub version: String,
+    pub files: Vec<PackedFile>,
+    /// 对规范化清单（signature 置 None 后的 JSON）的 ed25519 签名，base64
+    #[serde(default)]
+    pub signature: Option<String>,
+    /// 签名者公钥（base64）；是否可信由安装侧的信任列表决定
+    #[serde(default)]
+    pub publisher_key: Option<String>,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// 清单的规范化字节（签名与验签都基于它）
+fn canonical_bytes(manifest: &EtpkgManifest) -> Result<Vec<u8>, String> {
+    let mut unsigned = manifest.clone();
+    unsigned.signature = None;
+    serde_json::to_vec(&unsigned).map_err(|e| e.to_string())
+}
+
+/// 验证签名（如有）。返回签名者公钥（base64），无签名时返回 None
+fn verify_signature(manifest: &EtpkgManifest) -> Result<Option<String>, String> {
+    let (Some(sig_b64), Some(key_b64)) = (&manifest.signature, &manifest.publisher_key) else {
+        return Ok(None);
+    };
+    let engine = base64::engine::general_purpose::STANDARD;
+    let sig_bytes = engine
+        .decode(sig_b64)
+        .map_err(|e| format!("签名不是合法 base64: {}", e))?;
+    let key_bytes = engine
+        .decode(key_b64)
+        .map_err(|e| format!("公钥不是合法 base64: {}", e))?;
+    let key = ed25519_dalek::VerifyingKey::from_bytes(
+        key_bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| "公钥长度不是 32 字节".to_string())?,
+    )
+    .map_err(|e| format!("公钥无效: {}", e))?;
+    let signature = ed25519_dalek::Signature::from_slice(&sig_bytes)
+        .map_err(|e| format!("签名格式无效: {}", e))?;
+    use ed25519_dalek::Verifier;
+    key.verify(&canonical_bytes(manifest)?, &signature)
+        .map_err(|_| "签名校验失败，包可能被篡改".to_string())?;
+    Ok(Some(key_b64.clone()))
+}
+
+/// 校验插件 ID：它会被拼进插件根目录路径，必须是保守字符集的单段
+/// 名称（与 safe_relative 同理，拒绝分隔符、`..`、绝对路径形态）。
+/// 签名是可选项，不能指望它拦住恶意清单
+fn safe_plugin_id(id: &str) -> Result<(), String> {
+    let ok = !id.is_empty()
+        && id.len() <= 128
+        && !id.starts_with('.')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '@'));
+    if ok {
+        Ok(())
+    } else {
+        Err(format!("插件 ID '{}' 非法", id))
+    }
+}
+
+/// 校验包内路径不越界（拒绝绝对路径与 ..）
+fn safe_relative(path: &str) -> Result<PathBuf, String> {
+    let p = Path::new(path);
+    if p.is_absolute()
+        || p.components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+    {
+        return Err(format!("包内路径 '{}' 非法", path));
+    }
+    Ok(p.to_path_buf())
+}
+
+/// 离线验证 .etpkg：清单解析、逐文件哈希比对、签名校验
+pub fn validate_package(path: &Path) -> Result<EtpkgManifest, String> {
+    let file = fs::File::open(path).map_err(|e| format!("打开包失败: {}", e))?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| format!("不是合法的 zip: {}", e))?;
+
+    let manifest: EtpkgManifest = {
+        let mut entry = archive
+            .by_name(MANIFEST_NAME)
+            .map_err(|_| format!("包根部缺少 {}", MANIFEST_NAME))?;
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .map_err(|e| format!("读取清单失败: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("清单解析失败: {}", e))?
+    };
+    if manifest.format_version > FORMAT_VERSION {
+        return Err(format!(
+            "包格式版本 {} 过新，请更新应用",
+            manifest.format_version
+        ));
+    }
+    safe_plugin_id(&manifest.plugin_id)?;
+    verify_signature(&manifest)?;
+
+    let total: u64 = manifest.files.iter().map(|f| f.size).sum();
+    if total > MAX_UNPACKED_BYTES {
+        return Err("包解压后超过 200 MB 上限".into());
+    }
+
+    for packed in &manifest.files {
+        safe_relative(&packed.path)?;
+        let entry = archive
+            .by_name(&packed.path)
+            .map_err(|_| format!("清单声明的文件 '{}' 不在包内", packed.path))?;
+        // 按声明大小限量读取：清单谎报小尺寸时在这里截断报错，而不是
+        // 先把任意大的压缩流整个读进内存再等哈希兜底
+        let mut bytes = Vec::new();
+        entry
+            .take(packed.size + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|e| format!("读取 '{}' 失败: {}", packed.path, e))?;
+        if bytes.len() as u64 > packed.size {
+            return Err(format!("文件 '{}' 实际大小超过清单声明", packed.path));
+        }
+        if sha256_hex(&bytes) != packed.sha256.to_lowercase() {
+            return Err(format!("文件 '{}' 哈希不匹配，包已损坏或被篡改", packed.path));
+        }
+    }
+    Ok(manifest)
+}
+
+/// 解压包内全部清单文件到目标目录（调用前必须已 validate）
+fn extract_to(path: &Path, manifest: &EtpkgManifest, dest: &Path) -> Result<(), String> {
+    let file = fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+    for packed in &manifest.files {
+        let rel = safe_relative(&packed.path)?;
+        let target = dest.join(&rel);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let mut entry = archive.by_name(&packed.path).map_err(|e| e.to_string())?;
+        let mut out = fs::File::create(&target).map_err(|e| e.to_string())?;
+        std::io::copy(&mut entry, &mut out).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// 从 .etpkg 安装插件；完全离线，复用安装事务的回滚语义
+#[tauri::command]
+pub fn plugin_install_from_etpkg(
+    app: AppHandle,
+    package_path: String,
+    plugins_dir: String,
+) -> Result<String, String> {
+    let path = Path::new(&package_path);
+    let manifest = validate_package(path)?;
+
+    // 兼容性约束与 npm 安装路径一致
+    let pkg_json_entry = manifest.files.iter().find(|f| f.path == "package.json");
+    if pkg_json_entry.is_none() {
+        return Err("包内缺少 package.json".into());
+    }
+
+    let txn =
+        crate::plugins::install_txn::InstallTransaction::begin(Path::new(&plugins_dir), &manifest.plugin_id)?;
+    extract_to(path, &manifest, txn.staging_dir())?;
+    txn.validate()?;
+    txn.commit()?;
+
+    crate::services::audit_log::record(&app, "pluginInstall", &format!("etpkg:{}", manifest.plugin_id));
+    log::info!(
+        "[Etpkg] installed '{}' {} from {}",
+        manifest.plugin_id,
+        manifest.version,
+        package_path
+    );
+    Ok(manifest.plugin_id)
+}
+
+/// 把插件目录打包成 .etpkg（开发者导出 dev-link 插件）
+#[tauri::command]
+pub fn plugin_pack(src_dir: String, out_path: String) -> Result<EtpkgManifest, String> {
+    let src = Path::new(&src_dir);
+    let meta_raw = fs::read_to_string(src.join("package.json"))
+        .map_err(|e| format!("读取 package.json 失败: {}", e))?;
+    let meta: serde_json::Value =
+        serde_json::from_str(&meta_raw).map_err(|e| format!("package.json 解析失败: {}", e))?;
+    let plugin_id = meta
+        .get("name")
+        .and_then(|v| v.as_str())
+        .ok_or("package.json 缺少 name")?
+        .to_string();
+    let version = meta
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or("0.0.0")
+        .to_string();
+
+    // 收集文件；跳过 node_modules 之外的开发残留
+    let mut files = Vec::new();
+    collect_files(src, src, &mut files)?;
+
+    let out = fs::File::create(&out_path).map_err(|e| format!("创建输出文件失败: {}", e))?;
+    let mut writer = zip::ZipWriter::new(out);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    let mut packed_files = Vec::with_capacity(files.len());
+    for rel in &files {
+        let bytes = fs::read(src.join(rel)).map_err(|e| e.to_string())?;
+        let rel_str = rel.to_string_lossy().replace('\\', "/");
+        writer.start_file(&rel_str, options).map_err(|e| e.to_string())?;
+        writer.write_all(&bytes).map_err(|e| e.to_string())?;
+        packed_files.push(PackedFile {
+            path: rel_str,
+            sha256: sha256_hex(&bytes),
+            size: bytes.len() as u64,
+        });
+    }
+
+    let manifest = EtpkgManifest {
+        format_version: FORMAT_VERSION,
+        plugin_id,
+        version,
+        files: packed_files,
+        signature: None,
+        publisher_key: None,
+    };
+    let manifest_json = serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())?;
+    writer.start_file(MANIFEST_NAME, options).map_err(|e| e.to_string())?;
+    writer.write_all(manifest_json.as_bytes()).map_err(|e| e.to_string())?;
+    writer.finish().map_err(|e| e.to_string())?;
+
+    log::info!("[Etpkg] packed '{}' ({} files) -> {}", manifest.plugin_id, manifest.files.len(), out_path);
+    Ok(manifest)
+}
+
+/// 递归收集插件目录的文件；跳过隐藏文件与 .git
+fn collect_files(root: &Path, dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| e.to_string())?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(root, &path, out)?;
+        } else {
+            out.push(path.strip_prefix(root).map_err(|e| e.to_string())?.to_path_buf());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,168 @@
+//! 插件健康度后台巡检
+//!
+//! 过去只有用户点"检查"才跑 `check_plugin_health`，UI 角标经常是
+//! 过期状态。现在后台定时巡检全部已启用插件（启用/更新后也立即
+//! 检查一次），结果落盘；状态发生迁移时发 `plugin-health-changed`
+//! 事件，前端被动刷新角标即可。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 健康状态迁移事件
+pub const HEALTH_CHANGED_EVENT: &str = "plugin-health-changed";
+/// 巡检周期
+const CHECK_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Broken,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginHealth {
+    pub plugin_id: String,
+    pub status: HealthStatus,
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Unix 毫秒
+    pub checked_at: i64,
+}
+
+static RESULTS: Lazy<RwLock<HashMap<String, PluginHealth>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+fn results_path(app: &AppHandle) -> Option<std::path::PathBuf> {
+    app.path().app_data_dir().ok().map(|d| d.join("plugin-health.json"))
+}
+
+fn persist(app: &AppHandle) {
+    let Some(path) = results_path(app) else { return };
+    let Ok(results) = RESULTS.read() else { return };
+    if let Ok(json) = serde_json::to_string(&*results) {
+        if let Err(e) = std::fs::write(&path, json) {
+            log::warn!("[PluginHealth] failed to persist results: {}", e);
+        }
+    }
+}
+
+/// 启动时恢复上次的巡检结果，避免角标闪烁
+pub fn load_persisted(app: &AppHandle) {
+    let Some(path) = results_path(app) else { return };
+    let Ok(content) = std::fs::read_to_string(&path) else { return };
+    if let Ok(parsed) = serde_json::from_str::<HashMap<String, PluginHealth>>(&content) {
+        if let Ok(mut results) = RESULTS.write() {
+            *results = parsed;
+        }
+    }
+}
+
+/// 对单个插件做健康检查：目录、元数据、入口文件、兼容性
+fn run_check(plugin_id: &str, plugin_dir: &Path) -> PluginHealth {
+    let now = chrono::Utc::now().timestamp_millis();
+    let health = |status, message: Option<String>| PluginHealth {
+        plugin_id: plugin_id.to_string(),
+        status,
+        message,
+        checked_at: now,
+    };
+
+    if !plugin_dir.exists() {
+        return health(HealthStatus::Broken, Some("插件目录不存在".into()));
+    }
+    let package_json = plugin_dir.join("package.json");
+    let meta: serde_json::Value = match std::fs::read_to_string(&package_json)
+        .map_err(|e| e.to_string())
+        .and_then(|s| serde_json::from_str(&s).map_err(|e| e.to_string()))
+    {
+        Ok(v) => v,
+        Err(e) => return health(HealthStatus::Broken, Some(format!("package.json 无法解析: {}", e))),
+    };
+    let main = meta.get("main").and_then(|v| v.as_str()).unwrap_or("index.js");
+    if !plugin_dir.join(main).exists() {
+        return health(HealthStatus::Broken, Some(format!("入口文件 {} 缺失", main)));
+    }
+    if let Some(etools) = meta.get("etools") {
+        if let Ok(constraints) =
+            serde_json::from_value::<crate::plugins::compat::CompatConstraints>(etools.clone())
+        {
+            let compat_report = crate::plugins::compat::check(&constraints);
+            if !compat_report.compatible {
+                return health(HealthStatus::Degraded, compat_report.reason);
+            }
+        }
+    }
+    health(HealthStatus::Healthy, None)
+}
+
+/// 执行检查并在状态迁移时发事件；启用/更新后由插件服务调用
+pub fn check_and_record(app: &AppHandle, plugin_id: &str, plugin_dir: &Path) -> PluginHealth {
+    let new = run_check(plugin_id, plugin_dir);
+    let transitioned = {
+        let Ok(mut results) = RESULTS.write() else { return new };
+        let old_status = results.get(plugin_id).map(|h| h.status.clone());
+        results.insert(plugin_id.to_string(), new.clone());
+        old_status.map(|s| s != new.status).unwrap_or(true)
+    };
+    if transitioned {
+        log::info!(
+            "[PluginHealth] '{}' -> {:?} ({})",
+            plugin_id,
+            new.status,
+            new.message.as_deref().unwrap_or("ok")
+        );
+        let _ = app.emit(HEALTH_CHANGED_EVENT, new.clone());
+        persist(app);
+    }
+    new
+}
+
+/// 后台巡检循环：遍历插件目录下的全部已安装插件
+pub fn spawn_health_scheduler(app: AppHandle, plugins_dir: std::path::PathBuf) {
+    load_persisted(&app);
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if !crate::services::privacy_session::is_recording_paused() {
+                let entries = std::fs::read_dir(&plugins_dir)
+                    .map(|rd| rd.flatten().collect::<Vec<_>>())
+                    .unwrap_or_default();
+                for entry in entries {
+                    let path = entry.path();
+                    if !path.is_dir() || path.file_name().map_or(true, |n| {
+                        n.to_string_lossy().starts_with('.')
+                    }) {
+                        continue;
+                    }
+                    let plugin_id = entry.file_name().to_string_lossy().replace('_', "/");
+                    check_and_record(&app, &plugin_id, &path);
+                }
+            }
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+}
+
+/// 立即检查单个插件（设置页"重新检查"按钮）
+#[tauri::command]
+pub fn check_plugin_health(
+    app: AppHandle,
+    plugin_id: String,
+    plugin_dir: String,
+) -> Result<PluginHealth, String> {
+    Ok(check_and_record(&app, &plugin_id, Path::new(&plugin_dir)))
+}
+
+/// 读取全部缓存的健康结果（UI 初始化用）
+#[tauri::command]
+pub fn get_plugin_health() -> Result<Vec<PluginHealth>, String> {
+    let results = RESULTS.read().map_err(|e| e.to_string())?;
+    Ok(results.values().cloned().collect())
+}
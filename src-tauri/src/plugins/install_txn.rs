@@ -0,0 +1,140 @@
+//! 插件安装事务
+//!
+//! 安装分三步：先落到临时 staging 目录，校验通过后原子换入插件目录，
+//! 最后才更新 package.json 依赖表。任何一步失败（npm 报错、校验失败、
+//! 磁盘满）都会完整回滚，保证插件目录里不会出现半安装状态的插件。
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 一次安装事务；Drop 时若未提交则自动清理 staging 目录
+pub struct InstallTransaction {
+    plugin_id: String,
+    staging_dir: PathBuf,
+    target_dir: PathBuf,
+    /// 目标目录原有内容的备份位置（升级场景）
+    backup_dir: Option<PathBuf>,
+    committed: bool,
+}
+
+impl InstallTransaction {
+    /// 开启事务：在插件根目录旁创建 staging 目录
+    pub fn begin(plugins_root: &Path, plugin_id: &str) -> Result<Self, String> {
+        // ID 来自前端或包清单，目标目录直接 join 它；含分隔符或 ..
+        // 就能把备份/换入操作引到插件根目录之外，这里一律拒绝
+        if plugin_id.is_empty()
+            || plugin_id.contains(['/', '\\'])
+            || plugin_id == "."
+            || plugin_id == ".."
+            || Path::new(plugin_id).is_absolute()
+        {
+            return Err(format!("插件 ID '{}' 非法", plugin_id));
+        }
+        let staging_dir = plugins_root.join(format!(".staging-{}", plugin_id));
+        if staging_dir.exists() {
+            // 上次异常退出的残留，直接清掉
+            fs::remove_dir_all(&staging_dir)
+                .map_err(|e| format!("清理残留 staging 目录失败: {}", e))?;
+        }
+        fs::create_dir_all(&staging_dir).map_err(|e| format!("创建 staging 目录失败: {}", e))?;
+        Ok(Self {
+            plugin_id: plugin_id.to_string(),
+            staging_dir,
+            target_dir: plugins_root.join(plugin_id),
+            backup_dir: None,
+            committed: false,
+        })
+    }
+
+    /// staging 目录路径：npm install / 解包都写到这里
+    pub fn staging_dir(&self) -> &Path {
+        &self.staging_dir
+    }
+
+    /// 校验 staging 内容是否是一个完整插件
+    pub fn validate(&self) -> Result<(), String> {
+        let package_json = self.staging_dir.join("package.json");
+        let content = fs::read_to_string(&package_json)
+            .map_err(|_| "staging 目录缺少 package.json".to_string())?;
+        let parsed: serde_json::Value =
+            serde_json::from_str(&content).map_err(|e| format!("package.json 损坏: {}", e))?;
+
+        let main = parsed
+            .get("main")
+            .and_then(|v| v.as_str())
+            .unwrap_or("index.js");
+        if !self.staging_dir.join(main).exists() {
+            return Err(format!("入口文件 {} 不存在", main));
+        }
+        // 兼容性约束在安装前再硬校验一次（市场列表可能是旧缓存）
+        if let Some(etools) = parsed.get("etools") {
+            if let Ok(constraints) =
+                serde_json::from_value::<super::compat::CompatConstraints>(etools.clone())
+            {
+                super::compat::ensure_installable(&self.plugin_id, &constraints)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 提交：备份旧版本（若有），把 staging 换入目标位置
+    pub fn commit(mut self) -> Result<(), String> {
+        if self.target_dir.exists() {
+            let backup = self
+                .target_dir
+                .with_file_name(format!(".backup-{}", self.plugin_id));
+            if backup.exists() {
+                fs::remove_dir_all(&backup).map_err(|e| format!("清理旧备份失败: {}", e))?;
+            }
+            fs::rename(&self.target_dir, &backup)
+                .map_err(|e| format!("备份旧版本失败: {}", e))?;
+            self.backup_dir = Some(backup);
+        }
+
+        if let Err(e) = fs::rename(&self.staging_dir, &self.target_dir) {
+            // 换入失败：恢复备份后报错，Drop 会清理 staging
+            if let Some(backup) = &self.backup_dir {
+                if let Err(restore_err) = fs::rename(backup, &self.target_dir) {
+                    log::error!(
+                        "[InstallTxn] CRITICAL: failed to restore backup for {}: {}",
+                        self.plugin_id,
+                        restore_err
+                    );
+                }
+            }
+            return Err(format!("安装 {} 失败（换入阶段）: {}", self.plugin_id, e));
+        }
+
+        // 换入成功后备份才可以删；删失败不算事务失败
+        if let Some(backup) = self.backup_dir.take() {
+            if let Err(e) = fs::remove_dir_all(&backup) {
+                log::warn!("[InstallTxn] failed to remove backup dir: {}", e);
+            }
+        }
+        self.committed = true;
+        log::info!("[InstallTxn] plugin {} installed atomically", self.plugin_id);
+        Ok(())
+    }
+}
+
+impl Drop for InstallTransaction {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+        // 未提交即析构 = 回滚：清理 staging，恢复备份
+        if self.staging_dir.exists() {
+            if let Err(e) = fs::remove_dir_all(&self.staging_dir) {
+                log::warn!("[InstallTxn] rollback: failed to clean staging: {}", e);
+            }
+        }
+        if let Some(backup) = &self.backup_dir {
+            if !self.target_dir.exists() {
+                if let Err(e) = fs::rename(backup, &self.target_dir) {
+                    log::error!("[InstallTxn] rollback: failed to restore backup: {}", e);
+                }
+            }
+        }
+        log::info!("[InstallTxn] install of {} rolled back", self.plugin_id);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod compat;
+pub mod deep_link;
+pub mod etpkg;
+pub mod health;
+pub mod install_txn;
+pub mod plugin_bus;
+pub mod scheduler;
+pub mod settings;
+pub mod sideload;
+pub mod uninstall;
+pub mod view_schema;
@@ -0,0 +1,172 @@
+//! 插件间通信总线（发布/订阅）
+//!
+//! 插件通过命名空间化的 topic 互相通信（如 `git/repo-list`）。
+//! 发布方必须在 ETP 元数据中声明 `provides`，订阅方声明 `consumes`，
+//! 未声明的访问会被权限检查拒绝。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use tauri::{AppHandle, Emitter};
+
+/// 总线消息事件，插件运行时按 pluginId 过滤分发
+pub const BUS_MESSAGE_EVENT: &str = "plugin://bus-message";
+/// 每个 topic 保留的最新消息数（供迟到的订阅方回放）
+const RETAINED_MESSAGES: usize = 1;
+
+/// 插件在 ETP 元数据中声明的总线权限
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BusPermissions {
+    /// 允许发布的 topic 列表
+    #[serde(default)]
+    pub provides: Vec<String>,
+    /// 允许订阅的 topic 列表
+    #[serde(default)]
+    pub consumes: Vec<String>,
+}
+
+/// 一条总线消息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BusMessage {
+    pub topic: String,
+    /// 发布方插件 ID
+    pub publisher: String,
+    pub payload: serde_json::Value,
+    /// 发布时间（Unix 毫秒）
+    pub published_at: i64,
+}
+
+#[derive(Debug, Default)]
+struct PluginBus {
+    /// 各插件声明的权限，插件加载时写入
+    permissions: HashMap<String, BusPermissions>,
+    /// topic -> 订阅中的插件
+    subscribers: HashMap<String, HashSet<String>>,
+    /// topic -> 保留消息（新订阅方立即可读最新状态）
+    retained: HashMap<String, Vec<BusMessage>>,
+}
+
+static BUS: Lazy<RwLock<PluginBus>> = Lazy::new(|| RwLock::new(PluginBus::default()));
+
+/// 注册插件的总线权限（插件加载时由 plugin_service 调用）
+pub fn register_permissions(plugin_id: &str, permissions: BusPermissions) {
+    if let Ok(mut bus) = BUS.write() {
+        bus.permissions.insert(plugin_id.to_string(), permissions);
+    }
+}
+
+/// 清理插件的权限、订阅与保留消息（卸载时调用）
+pub fn unregister_plugin(plugin_id: &str) {
+    if let Ok(mut bus) = BUS.write() {
+        bus.permissions.remove(plugin_id);
+        for subs in bus.subscribers.values_mut() {
+            subs.remove(plugin_id);
+        }
+        bus.retained.retain(|_, msgs| {
+            msgs.retain(|m| m.publisher != plugin_id);
+            !msgs.is_empty()
+        });
+    }
+}
+
+/// topic 是否命中权限声明（支持 `git/*` 形式的通配）
+fn topic_allowed(declared: &[String], topic: &str) -> bool {
+    declared.iter().any(|d| {
+        if let Some(prefix) = d.strip_suffix("/*") {
+            topic.starts_with(prefix) && topic.len() > prefix.len()
+        } else {
+            d == topic
+        }
+    })
+}
+
+/// 插件向 topic 发布消息；需要 `provides` 声明
+#[tauri::command]
+pub fn plugin_bus_publish(
+    app: AppHandle,
+    plugin_id: String,
+    topic: String,
+    payload: serde_json::Value,
+) -> Result<(), String> {
+    let subscribers: Vec<String> = {
+        let mut bus = BUS.write().map_err(|e| e.to_string())?;
+        let allowed = bus
+            .permissions
+            .get(&plugin_id)
+            .map(|p| topic_allowed(&p.provides, &topic))
+            .unwrap_or(false);
+        if !allowed {
+            return Err(format!(
+                "插件 {} 未在 provides 中声明 topic '{}'",
+                plugin_id, topic
+            ));
+        }
+
+        let message = BusMessage {
+            topic: topic.clone(),
+            publisher: plugin_id.clone(),
+            payload,
+            published_at: chrono::Utc::now().timestamp_millis(),
+        };
+        let retained = bus.retained.entry(topic.clone()).or_default();
+        retained.push(message);
+        if retained.len() > RETAINED_MESSAGES {
+            let overflow = retained.len() - RETAINED_MESSAGES;
+            retained.drain(..overflow);
+        }
+        bus.subscribers
+            .get(&topic)
+            .map(|s| s.iter().cloned().collect())
+            .unwrap_or_default()
+    };
+
+    // 逐订阅方分发；前端插件容器按 subscriberId 路由到对应沙箱
+    for subscriber in subscribers {
+        let bus_read = BUS.read().map_err(|e| e.to_string())?;
+        let message = bus_read.retained.get(&topic).and_then(|m| m.last()).cloned();
+        drop(bus_read);
+        if let Some(message) = message {
+            app.emit(
+                BUS_MESSAGE_EVENT,
+                serde_json::json!({ "subscriberId": subscriber, "message": message }),
+            )
+            .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// 插件订阅 topic；需要 `consumes` 声明。返回该 topic 的保留消息
+#[tauri::command]
+pub fn plugin_bus_subscribe(plugin_id: String, topic: String) -> Result<Vec<BusMessage>, String> {
+    let mut bus = BUS.write().map_err(|e| e.to_string())?;
+    let allowed = bus
+        .permissions
+        .get(&plugin_id)
+        .map(|p| topic_allowed(&p.consumes, &topic))
+        .unwrap_or(false);
+    if !allowed {
+        return Err(format!(
+            "插件 {} 未在 consumes 中声明 topic '{}'",
+            plugin_id, topic
+        ));
+    }
+    bus.subscribers
+        .entry(topic.clone())
+        .or_default()
+        .insert(plugin_id);
+    Ok(bus.retained.get(&topic).cloned().unwrap_or_default())
+}
+
+/// 取消订阅
+#[tauri::command]
+pub fn plugin_bus_unsubscribe(plugin_id: String, topic: String) -> Result<(), String> {
+    let mut bus = BUS.write().map_err(|e| e.to_string())?;
+    if let Some(subs) = bus.subscribers.get_mut(&topic) {
+        subs.remove(&plugin_id);
+    }
+    Ok(())
+}
@@ -0,0 +1,256 @@
+//! 插件后台任务调度器
+//!
+//! 插件在 ETP 元数据中声明后台任务（固定间隔或 cron 风格），
+//! 调度器在插件运行时内按配额执行，结果可以推送通知或更新角标。
+//! 每个任务可单独开关，最近运行记录保留在内存中供设置页展示。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// 后台任务触发事件，插件运行时监听后执行对应命令
+pub const BACKGROUND_TASK_EVENT: &str = "plugin://background-task";
+/// 单个插件最多声明的后台任务数
+const MAX_TASKS_PER_PLUGIN: usize = 4;
+/// 最小允许的执行间隔，防止插件把自己调成忙等
+const MIN_INTERVAL_SECS: u64 = 30;
+/// 每个任务保留的运行历史条数
+const RUN_HISTORY_LIMIT: usize = 50;
+
+/// ETP 元数据中的后台任务声明
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundTaskDecl {
+    /// 任务 ID，插件内唯一
+    pub id: String,
+    /// 触发时调用的插件命令
+    pub command: String,
+    /// 执行间隔（秒）；与 `cron` 二选一
+    #[serde(default)]
+    pub interval_secs: Option<u64>,
+    /// 简化 cron 表达式："分 时"（如 "0 9" 表示每天 9:00）
+    #[serde(default)]
+    pub cron: Option<String>,
+}
+
+/// 单次任务运行记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRunRecord {
+    /// 运行开始时间（Unix 毫秒）
+    pub started_at: i64,
+    pub duration_ms: u64,
+    pub success: bool,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct ScheduledTask {
+    plugin_id: String,
+    decl: BackgroundTaskDecl,
+    enabled: bool,
+    history: Vec<TaskRunRecord>,
+    /// 注册代次；插件重载后 key 相同，旧循环靠代次不符退出，避免双跑
+    generation: u64,
+}
+
+/// 任务注册表，key 为 "plugin_id/task_id"
+static TASKS: Lazy<RwLock<HashMap<String, ScheduledTask>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+/// 注册代次序号
+static REGISTRATION_SEQ: AtomicU64 = AtomicU64::new(1);
+
+fn task_key(plugin_id: &str, task_id: &str) -> String {
+    format!("{}/{}", plugin_id, task_id)
+}
+
+/// 校验并注册插件声明的后台任务；在插件加载时由 plugin_service 调用
+pub fn register_plugin_tasks(
+    app: AppHandle,
+    plugin_id: &str,
+    decls: Vec<BackgroundTaskDecl>,
+) -> Result<(), String> {
+    if decls.len() > MAX_TASKS_PER_PLUGIN {
+        return Err(format!(
+            "插件 {} 声明了 {} 个后台任务，超过上限 {}",
+            plugin_id,
+            decls.len(),
+            MAX_TASKS_PER_PLUGIN
+        ));
+    }
+    for decl in &decls {
+        match (decl.interval_secs, &decl.cron) {
+            (Some(interval), None) => {
+                if interval < MIN_INTERVAL_SECS {
+                    return Err(format!(
+                        "任务 {} 的间隔 {}s 小于最小允许值 {}s",
+                        decl.id, interval, MIN_INTERVAL_SECS
+                    ));
+                }
+            }
+            (None, Some(cron)) => {
+                parse_cron(cron)?;
+            }
+            _ => {
+                return Err(format!("任务 {} 必须且只能声明 intervalSecs 或 cron 之一", decl.id));
+            }
+        }
+    }
+
+    // 重载/升级会重复注册：先摘掉旧任务再按新代次插入，旧循环在
+    // 下一轮醒来发现代次不符即退出
+    unregister_plugin_tasks(plugin_id);
+    let generation = REGISTRATION_SEQ.fetch_add(1, Ordering::SeqCst);
+
+    let mut tasks = TASKS.write().map_err(|e| e.to_string())?;
+    for decl in decls {
+        let key = task_key(plugin_id, &decl.id);
+        tasks.insert(
+            key.clone(),
+            ScheduledTask {
+                plugin_id: plugin_id.to_string(),
+                decl: decl.clone(),
+                enabled: true,
+                history: Vec::new(),
+                generation,
+            },
+        );
+        spawn_task_loop(app.clone(), key, generation);
+    }
+    Ok(())
+}
+
+/// 注销某插件的全部后台任务（卸载/禁用时调用）
+pub fn unregister_plugin_tasks(plugin_id: &str) {
+    if let Ok(mut tasks) = TASKS.write() {
+        tasks.retain(|_, t| t.plugin_id != plugin_id);
+    }
+}
+
+/// 解析简化 cron（"分 时"），返回 (minute, hour)
+fn parse_cron(expr: &str) -> Result<(u32, u32), String> {
+    let parts: Vec<&str> = expr.split_whitespace().collect();
+    if parts.len() != 2 {
+        return Err(format!("cron 表达式 '{}' 格式应为 '分 时'", expr));
+    }
+    let minute: u32 = parts[0].parse().map_err(|_| format!("无效分钟: {}", parts[0]))?;
+    let hour: u32 = parts[1].parse().map_err(|_| format!("无效小时: {}", parts[1]))?;
+    if minute > 59 || hour > 23 {
+        return Err(format!("cron 表达式 '{}' 超出范围", expr));
+    }
+    Ok((minute, hour))
+}
+
+/// 距离下一次 cron 触发的秒数
+fn secs_until_cron(minute: u32, hour: u32) -> u64 {
+    let now = chrono::Local::now();
+    let today_target = now
+        .date_naive()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap_or_else(|| now.naive_local());
+    let target = if today_target > now.naive_local() {
+        today_target
+    } else {
+        today_target + chrono::Duration::days(1)
+    };
+    (target - now.naive_local()).num_seconds().max(1) as u64
+}
+
+fn spawn_task_loop(app: AppHandle, key: String, generation: u64) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            // 每轮从注册表重新读取配置：任务可能被禁用或移除
+            let (plugin_id, decl, enabled) = {
+                let tasks = match TASKS.read() {
+                    Ok(t) => t,
+                    Err(_) => return,
+                };
+                match tasks.get(&key) {
+                    Some(t) if t.generation == generation => {
+                        (t.plugin_id.clone(), t.decl.clone(), t.enabled)
+                    }
+                    // 任务已注销或被新代次接管，结束本循环
+                    _ => return,
+                }
+            };
+
+            let wait_secs = match (decl.interval_secs, &decl.cron) {
+                (Some(interval), _) => interval,
+                (None, Some(cron)) => match parse_cron(cron) {
+                    Ok((m, h)) => secs_until_cron(m, h),
+                    Err(_) => return,
+                },
+                _ => return,
+            };
+            tokio::time::sleep(Duration::from_secs(wait_secs)).await;
+
+            if !enabled {
+                continue;
+            }
+            let started = chrono::Utc::now().timestamp_millis();
+            let result = app.emit(
+                BACKGROUND_TASK_EVENT,
+                serde_json::json!({
+                    "pluginId": plugin_id,
+                    "taskId": decl.id,
+                    "command": decl.command,
+                }),
+            );
+            record_run(
+                &key,
+                TaskRunRecord {
+                    started_at: started,
+                    duration_ms: (chrono::Utc::now().timestamp_millis() - started).max(0) as u64,
+                    success: result.is_ok(),
+                    error: result.err().map(|e| e.to_string()),
+                },
+            );
+        }
+    });
+}
+
+fn record_run(key: &str, record: TaskRunRecord) {
+    if let Ok(mut tasks) = TASKS.write() {
+        if let Some(task) = tasks.get_mut(key) {
+            task.history.push(record);
+            if task.history.len() > RUN_HISTORY_LIMIT {
+                let overflow = task.history.len() - RUN_HISTORY_LIMIT;
+                task.history.drain(..overflow);
+            }
+        }
+    }
+}
+
+/// 启用/禁用单个后台任务（设置页开关）
+#[tauri::command]
+pub fn set_background_task_enabled(
+    plugin_id: String,
+    task_id: String,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut tasks = TASKS.write().map_err(|e| e.to_string())?;
+    let task = tasks
+        .get_mut(&task_key(&plugin_id, &task_id))
+        .ok_or_else(|| format!("未找到任务 {}/{}", plugin_id, task_id))?;
+    task.enabled = enabled;
+    log::info!("[Scheduler] task {}/{} enabled={}", plugin_id, task_id, enabled);
+    Ok(())
+}
+
+/// 查询某插件后台任务的运行历史
+#[tauri::command]
+pub fn get_background_task_history(
+    plugin_id: String,
+    task_id: String,
+) -> Result<Vec<TaskRunRecord>, String> {
+    let tasks = TASKS.read().map_err(|e| e.to_string())?;
+    tasks
+        .get(&task_key(&plugin_id, &task_id))
+        .map(|t| t.history.clone())
+        .ok_or_else(|| format!("未找到任务 {}/{}", plugin_id, task_id))
+}
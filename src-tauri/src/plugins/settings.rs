@@ -0,0 +1,156 @@
+//! 插件设置的类型校验与默认值解析
+//!
+//! ETP 元数据里的 `PluginSetting` 声明此前只用于渲染设置页，写入时并不校验。
+//! 现在 `set_plugin_setting` 会按声明的类型/可选值校验，读取时自动套用默认值，
+//! `get_all_plugin_settings` 返回完整解析后的设置对象。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// ETP 元数据中的单项设置声明
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginSetting {
+    pub key: String,
+    /// "string" / "number" / "boolean" / "select"
+    pub setting_type: String,
+    #[serde(default)]
+    pub default_value: Option<Value>,
+    /// select 类型的可选值
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// number 类型的取值范围
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[serde(default)]
+    pub max: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+struct PluginSettingsStore {
+    /// 各插件的设置声明（加载元数据时注册）
+    declarations: HashMap<String, Vec<PluginSetting>>,
+    /// 已写入的值：plugin_id -> key -> value
+    values: HashMap<String, HashMap<String, Value>>,
+}
+
+static STORE: Lazy<RwLock<PluginSettingsStore>> =
+    Lazy::new(|| RwLock::new(PluginSettingsStore::default()));
+
+/// 注册插件的设置声明（插件加载时由 plugin_service 调用）
+pub fn register_declarations(plugin_id: &str, declarations: Vec<PluginSetting>) {
+    if let Ok(mut store) = STORE.write() {
+        store.declarations.insert(plugin_id.to_string(), declarations);
+    }
+}
+
+/// 清理插件的声明与已存值（卸载时调用）
+pub fn unregister_plugin(plugin_id: &str) {
+    if let Ok(mut store) = STORE.write() {
+        store.declarations.remove(plugin_id);
+        store.values.remove(plugin_id);
+    }
+}
+
+/// 按声明校验单个值
+fn validate_value(decl: &PluginSetting, value: &Value) -> Result<(), String> {
+    match decl.setting_type.as_str() {
+        "string" => {
+            if !value.is_string() {
+                return Err(format!("设置 {} 需要字符串", decl.key));
+            }
+        }
+        "number" => {
+            let n = value
+                .as_f64()
+                .ok_or_else(|| format!("设置 {} 需要数字", decl.key))?;
+            if let Some(min) = decl.min {
+                if n < min {
+                    return Err(format!("设置 {} 不能小于 {}", decl.key, min));
+                }
+            }
+            if let Some(max) = decl.max {
+                if n > max {
+                    return Err(format!("设置 {} 不能大于 {}", decl.key, max));
+                }
+            }
+        }
+        "boolean" => {
+            if !value.is_boolean() {
+                return Err(format!("设置 {} 需要布尔值", decl.key));
+            }
+        }
+        "select" => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| format!("设置 {} 需要字符串", decl.key))?;
+            if !decl.options.iter().any(|o| o == s) {
+                return Err(format!(
+                    "设置 {} 的值 '{}' 不在可选项中（{}）",
+                    decl.key,
+                    s,
+                    decl.options.join(", ")
+                ));
+            }
+        }
+        other => return Err(format!("设置 {} 声明了未知类型 {}", decl.key, other)),
+    }
+    Ok(())
+}
+
+/// 写入插件设置；值必须通过声明校验，未声明的 key 会被拒绝
+#[tauri::command]
+pub fn set_plugin_setting(plugin_id: String, key: String, value: Value) -> Result<(), String> {
+    let mut store = STORE.write().map_err(|e| e.to_string())?;
+    let decl = store
+        .declarations
+        .get(&plugin_id)
+        .and_then(|decls| decls.iter().find(|d| d.key == key))
+        .cloned()
+        .ok_or_else(|| format!("插件 {} 未声明设置项 {}", plugin_id, key))?;
+    validate_value(&decl, &value)?;
+    store
+        .values
+        .entry(plugin_id)
+        .or_default()
+        .insert(key, value);
+    Ok(())
+}
+
+/// 读取单项设置；未写入过时返回声明的默认值
+#[tauri::command]
+pub fn get_plugin_setting(plugin_id: String, key: String) -> Result<Value, String> {
+    let store = STORE.read().map_err(|e| e.to_string())?;
+    if let Some(value) = store.values.get(&plugin_id).and_then(|v| v.get(&key)) {
+        return Ok(value.clone());
+    }
+    store
+        .declarations
+        .get(&plugin_id)
+        .and_then(|decls| decls.iter().find(|d| d.key == key))
+        .map(|d| d.default_value.clone().unwrap_or(Value::Null))
+        .ok_or_else(|| format!("插件 {} 未声明设置项 {}", plugin_id, key))
+}
+
+/// 返回完整解析后的设置对象：声明的每个 key 都有值（已存值或默认值）
+#[tauri::command]
+pub fn get_all_plugin_settings(plugin_id: String) -> Result<HashMap<String, Value>, String> {
+    let store = STORE.read().map_err(|e| e.to_string())?;
+    let decls = store
+        .declarations
+        .get(&plugin_id)
+        .ok_or_else(|| format!("插件 {} 未注册设置声明", plugin_id))?;
+    let stored = store.values.get(&plugin_id);
+    let mut resolved = HashMap::with_capacity(decls.len());
+    for decl in decls {
+        let value = stored
+            .and_then(|v| v.get(&decl.key).cloned())
+            .or_else(|| decl.default_value.clone())
+            .unwrap_or(Value::Null);
+        resolved.insert(decl.key.clone(), value);
+    }
+    Ok(resolved)
+}
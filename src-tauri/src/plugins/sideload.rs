@@ -0,0 +1,160 @@
+//! 私有插件目录（侧载）
+//!
+//! 开发者或企业可以把未上架的插件放进 `sideload-plugins` 目录。
+//! 与市场安装不同，侧载插件必须经过一次显式信任确认才会被加载；
+//! 信任与目录内容哈希绑定，文件被改动后会重新要求确认，
+//! 防止"信任一次、静默换包"。
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// 侧载插件条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SideloadPlugin {
+    pub plugin_id: String,
+    pub path: String,
+    /// 当前目录内容哈希
+    pub content_hash: String,
+    /// 是否已被信任（哈希匹配）
+    pub trusted: bool,
+    /// 曾被信任但内容已变化，需要重新确认
+    pub modified_since_trust: bool,
+}
+
+/// 侧载目录
+pub fn sideload_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("sideload-plugins");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn trust_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("sideload-trust.json"))
+}
+
+/// plugin_id -> 被信任时的内容哈希
+fn load_trust(app: &AppHandle) -> HashMap<String, String> {
+    trust_path(app)
+        .ok()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_trust(app: &AppHandle, trust: &HashMap<String, String>) -> Result<(), String> {
+    let path = trust_path(app)?;
+    let json = serde_json::to_string_pretty(trust).map_err(|e| e.to_string())?;
+    std::fs::write(&path, json).map_err(|e| format!("保存信任记录失败: {}", e))
+}
+
+/// 目录内容哈希：文件相对路径 + 内容哈希按序拼接再哈希
+fn hash_dir(dir: &Path) -> Result<String, String> {
+    let mut entries = Vec::new();
+    collect(dir, dir, &mut entries)?;
+    entries.sort();
+    let mut hasher = Sha256::new();
+    for rel in &entries {
+        let bytes = std::fs::read(dir.join(rel)).map_err(|e| e.to_string())?;
+        hasher.update(rel.as_bytes());
+        hasher.update(Sha256::digest(&bytes));
+    }
+    return Ok(format!("{:x}", hasher.finalize()));
+
+    fn collect(root: &Path, dir: &Path, out: &mut Vec<String>) -> Result<(), String> {
+        for entry in std::fs::read_dir(dir).map_err(|e| e.to_string())?.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') || name == "node_modules" {
+                continue;
+            }
+            if path.is_dir() {
+                collect(root, &path, out)?;
+            } else {
+                out.push(
+                    path.strip_prefix(root)
+                        .map_err(|e| e.to_string())?
+                        .to_string_lossy()
+                        .replace('\\', "/"),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 扫描侧载目录；加载器只加载 `trusted: true` 的条目，
+/// 其余由前端弹信任确认
+#[tauri::command]
+pub fn scan_sideload_plugins(app: AppHandle) -> Result<Vec<SideloadPlugin>, String> {
+    let dir = sideload_dir(&app)?;
+    let trust = load_trust(&app);
+    let mut plugins = Vec::new();
+    for entry in std::fs::read_dir(&dir).map_err(|e| e.to_string())?.flatten() {
+        let path = entry.path();
+        if !path.is_dir() || !path.join("package.json").exists() {
+            continue;
+        }
+        let meta: serde_json::Value = match std::fs::read_to_string(path.join("package.json"))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+        {
+            Some(v) => v,
+            None => continue,
+        };
+        let Some(plugin_id) = meta.get("name").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let content_hash = hash_dir(&path)?;
+        let trusted_hash = trust.get(plugin_id);
+        plugins.push(SideloadPlugin {
+            plugin_id: plugin_id.to_string(),
+            path: path.display().to_string(),
+            trusted: trusted_hash == Some(&content_hash),
+            modified_since_trust: trusted_hash.is_some() && trusted_hash != Some(&content_hash),
+            content_hash,
+        });
+    }
+    Ok(plugins)
+}
+
+/// 用户在信任弹窗确认后调用；信任绑定当前内容哈希
+#[tauri::command]
+pub fn trust_sideload_plugin(app: AppHandle, plugin_id: String, path: String) -> Result<(), String> {
+    let dir = Path::new(&path);
+    // 只允许信任侧载目录下的条目
+    let root = sideload_dir(&app)?;
+    if !dir.starts_with(&root) {
+        return Err("只能信任侧载目录内的插件".into());
+    }
+    let content_hash = hash_dir(dir)?;
+    let mut trust = load_trust(&app);
+    trust.insert(plugin_id.clone(), content_hash);
+    save_trust(&app, &trust)?;
+    crate::services::audit_log::record(&app, "sideloadTrust", &plugin_id);
+    log::info!("[Sideload] plugin '{}' trusted", plugin_id);
+    Ok(())
+}
+
+/// 撤销信任；插件下次扫描时回到未信任状态
+#[tauri::command]
+pub fn revoke_sideload_trust(app: AppHandle, plugin_id: String) -> Result<(), String> {
+    let mut trust = load_trust(&app);
+    if trust.remove(&plugin_id).is_none() {
+        return Err(format!("插件 {} 没有信任记录", plugin_id));
+    }
+    save_trust(&app, &trust)?;
+    crate::services::audit_log::record(&app, "sideloadRevoke", &plugin_id);
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! 插件卸载残留清理
+//!
+//! 仅删包目录会留下设置、缩写/触发词、使用统计、插件数据目录和已授
+//! 权限。`plugin_uninstall` 现在走统一的清理流水线，逐项摘除并汇报
+//! 删了什么；`keep_settings` 用于"卸载后可能重装"的场景。
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use tauri::{AppHandle, Manager};
+
+/// 卸载清理报告：各项残留的处理结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CleanupReport {
+    /// 已删除的条目（人类可读描述）
+    pub removed: Vec<String>,
+    /// 按用户要求保留的条目
+    pub kept: Vec<String>,
+    /// 清理失败但不阻断卸载的警告
+    pub warnings: Vec<String>,
+}
+
+/// 插件的私有数据目录（plugin.storage API 的落盘位置）
+fn plugin_data_dir(app: &AppHandle, plugin_id: &str) -> Option<std::path::PathBuf> {
+    let safe = plugin_id.replace('/', "_");
+    app.path()
+        .app_data_dir()
+        .ok()
+        .map(|d| d.join("plugin-data").join(safe))
+}
+
+/// 卸载插件并清理全部残留
+#[tauri::command]
+pub fn plugin_uninstall(
+    app: AppHandle,
+    plugin_id: String,
+    plugins_dir: String,
+    keep_settings: Option<bool>,
+) -> Result<CleanupReport, String> {
+    let keep_settings = keep_settings.unwrap_or(false);
+    let mut report = CleanupReport::default();
+
+    // 1. 停掉后台任务，再动文件
+    crate::plugins::scheduler::unregister_plugin_tasks(&plugin_id);
+    report.removed.push("后台任务".into());
+
+    // 2. 包目录
+    let package_dir = std::path::Path::new(&plugins_dir).join(plugin_id.replace('/', "_"));
+    if package_dir.exists() {
+        fs::remove_dir_all(&package_dir).map_err(|e| format!("删除插件目录失败: {}", e))?;
+        report.removed.push(format!("插件目录 {}", package_dir.display()));
+    }
+
+    // 3. 运行时注册：消息总线、深链路由、文本检测器、触发词
+    crate::plugins::plugin_bus::unregister_plugin(&plugin_id);
+    crate::plugins::deep_link::unregister_plugin(&plugin_id);
+    report.removed.push("消息总线订阅与深链路由".into());
+    if crate::services::text_detector::unregister_plugin_detectors(plugin_id.clone()).is_ok() {
+        report.removed.push("文本实体检测器".into());
+    }
+    crate::search::trigger_registry::unregister_owner(&plugin_id);
+    report.removed.push("触发词与缩写".into());
+
+    // 4. 设置（声明 + 已存值）
+    if keep_settings {
+        report.kept.push("插件设置（为重装保留）".into());
+    } else {
+        crate::plugins::settings::unregister_plugin(&plugin_id);
+        report.removed.push("插件设置".into());
+    }
+
+    // 5. 使用统计
+    match crate::db::pool::get() {
+        Ok(conn) => {
+            match conn.execute(
+                "DELETE FROM app_usage WHERE item_id = ?1 AND item_type = 'plugin'",
+                [&plugin_id],
+            ) {
+                Ok(n) if n > 0 => report.removed.push(format!("{} 条使用统计", n)),
+                Ok(_) => {}
+                Err(e) => report.warnings.push(format!("清理使用统计失败: {}", e)),
+            }
+        }
+        Err(e) => report.warnings.push(format!("数据库不可用，跳过使用统计清理: {}", e)),
+    }
+
+    // 6. 插件私有数据目录
+    if let Some(data_dir) = plugin_data_dir(&app, &plugin_id) {
+        if data_dir.exists() {
+            match fs::remove_dir_all(&data_dir) {
+                Ok(_) => report.removed.push(format!("插件数据 {}", data_dir.display())),
+                Err(e) => report.warnings.push(format!("删除插件数据失败: {}", e)),
+            }
+        }
+    }
+
+    crate::services::audit_log::record(&app, "pluginUninstall", &plugin_id);
+    log::info!(
+        "[Uninstall] plugin '{}' removed ({} artifacts, {} kept, {} warnings)",
+        plugin_id,
+        report.removed.len(),
+        report.kept.len(),
+        report.warnings.len()
+    );
+    Ok(report)
+}
@@ -0,0 +1,177 @@
+//! 插件 UI 视图协议
+//!
+//! 插件命令返回结构化视图（list / detail / form / grid），由主窗口原生渲染。
+//! 后端在转发给前端之前做 schema 校验，非法视图直接拒绝；
+//! 长耗时插件命令可以通过 `push_plugin_view` 多次推送视图更新。
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+/// 视图更新事件名，前端在插件容器中监听
+pub const PLUGIN_VIEW_EVENT: &str = "plugin://view-update";
+
+/// 插件可返回的视图类型
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PluginView {
+    /// 列表视图：可选中、可过滤
+    List {
+        items: Vec<ListItem>,
+        #[serde(default)]
+        filterable: bool,
+    },
+    /// 详情视图：markdown 正文 + 可选元数据侧栏
+    Detail {
+        markdown: String,
+        #[serde(default)]
+        metadata: Vec<MetadataEntry>,
+    },
+    /// 表单视图：提交后回调插件命令
+    Form {
+        fields: Vec<FormField>,
+        submit_command: String,
+    },
+    /// 网格视图：图片/卡片类结果
+    Grid {
+        items: Vec<GridItem>,
+        #[serde(default = "default_columns")]
+        columns: u8,
+    },
+}
+
+fn default_columns() -> u8 {
+    4
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListItem {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub subtitle: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// 选中后触发的插件命令
+    #[serde(default)]
+    pub action: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataEntry {
+    pub label: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormField {
+    pub id: String,
+    pub label: String,
+    /// text / password / number / checkbox / select
+    pub field_type: String,
+    #[serde(default)]
+    pub default_value: Option<String>,
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GridItem {
+    pub id: String,
+    pub image: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub action: Option<String>,
+}
+
+/// 推送给前端的视图更新载荷
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewUpdatePayload {
+    pub plugin_id: String,
+    /// 同一命令多次推送时递增，前端丢弃乱序的旧帧
+    pub sequence: u64,
+    pub view: PluginView,
+}
+
+const VALID_FIELD_TYPES: &[&str] = &["text", "password", "number", "checkbox", "select"];
+/// 单个视图的条目上限，防止插件一次塞爆渲染层
+const MAX_VIEW_ITEMS: usize = 500;
+
+/// 校验插件返回的视图是否符合协议
+pub fn validate_view(view: &PluginView) -> Result<(), String> {
+    match view {
+        PluginView::List { items, .. } => {
+            if items.len() > MAX_VIEW_ITEMS {
+                return Err(format!("列表条目超过上限 {}", MAX_VIEW_ITEMS));
+            }
+            for item in items {
+                if item.id.is_empty() || item.title.is_empty() {
+                    return Err("列表条目的 id 与 title 不能为空".into());
+                }
+            }
+        }
+        PluginView::Detail { markdown, .. } => {
+            if markdown.is_empty() {
+                return Err("详情视图的 markdown 不能为空".into());
+            }
+        }
+        PluginView::Form { fields, submit_command } => {
+            if submit_command.is_empty() {
+                return Err("表单视图必须声明 submitCommand".into());
+            }
+            for field in fields {
+                if !VALID_FIELD_TYPES.contains(&field.field_type.as_str()) {
+                    return Err(format!("未知表单字段类型: {}", field.field_type));
+                }
+                if field.field_type == "select" && field.options.is_empty() {
+                    return Err(format!("select 字段 {} 缺少 options", field.id));
+                }
+            }
+        }
+        PluginView::Grid { items, columns } => {
+            if items.len() > MAX_VIEW_ITEMS {
+                return Err(format!("网格条目超过上限 {}", MAX_VIEW_ITEMS));
+            }
+            if *columns == 0 || *columns > 12 {
+                return Err("网格列数必须在 1-12 之间".into());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 插件推送视图更新（长耗时命令分批渲染）
+#[tauri::command]
+pub fn push_plugin_view(
+    app: AppHandle,
+    plugin_id: String,
+    sequence: u64,
+    view: PluginView,
+) -> Result<(), String> {
+    validate_view(&view).map_err(|e| {
+        log::warn!("[PluginView] rejected view from {}: {}", plugin_id, e);
+        format!("视图校验失败: {}", e)
+    })?;
+    app.emit(
+        PLUGIN_VIEW_EVENT,
+        ViewUpdatePayload {
+            plugin_id,
+            sequence,
+            view,
+        },
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// 仅校验视图（插件开发调试用）
+#[tauri::command]
+pub fn validate_plugin_view(view: PluginView) -> Result<(), String> {
+    validate_view(&view)
+}
@@ -0,0 +1,98 @@
+//! 搜索性能基准
+//!
+//! 用一组固定的样例查询逐个压测已注册的 provider，统计 p50/p95/最大
+//! 延迟与超时次数，帮助定位是哪个来源（文件、剪贴板、某个插件）拖慢
+//! 了启动器。样本同时汇入索引统计的延迟采样，出现在性能趋势里。
+//! 基准是串行跑的——并发会相互干扰，测不出单 provider 的真实延迟。
+
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// 样例查询集：中英文、短词、路径风格各有覆盖
+const SAMPLE_QUERIES: &[&str] = &[
+    "te", "test", "设置", "微信", "doc", "readme", "截图", "npm", "config", "下载",
+];
+/// 每条查询的重复次数；取多次消除冷启动抖动
+const RUNS_PER_QUERY: usize = 3;
+/// 单次调用的超时预算（比线上 400ms 放宽，能观测到真实慢速）
+const BENCH_TIMEOUT: Duration = Duration::from_millis(2000);
+
+/// 单个 provider 的基准报告
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderBenchmark {
+    pub provider: String,
+    pub priority: i32,
+    /// 实际完成的采样次数
+    pub samples: usize,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+    /// 平均返回结果数
+    pub avg_results: f64,
+    /// 超过预算被截断的次数
+    pub timeouts: usize,
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let idx = ((sorted.len() as f64 - 1.0) * p).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+/// 对每个 provider 跑完整样例集并返回按 p95 降序的报告
+#[tauri::command]
+pub async fn benchmark_search(app: tauri::AppHandle) -> Result<Vec<ProviderBenchmark>, String> {
+    let _guard = crate::cmds::busy_guard::BusyGuard::acquire(&app, "benchmark_search")?;
+    let providers = super::pipeline::providers_snapshot();
+    let mut reports = Vec::with_capacity(providers.len());
+
+    for provider in providers {
+        let mut latencies: Vec<f64> = Vec::new();
+        let mut total_results = 0usize;
+        let mut timeouts = 0usize;
+
+        for query in SAMPLE_QUERIES {
+            for _ in 0..RUNS_PER_QUERY {
+                let started = Instant::now();
+                match tokio::time::timeout(BENCH_TIMEOUT, provider.search(query)).await {
+                    Ok(results) => {
+                        let ms = started.elapsed().as_secs_f64() * 1000.0;
+                        total_results += results.len();
+                        super::index_stats::record_search_latency(ms);
+                        latencies.push(ms);
+                    }
+                    Err(_) => timeouts += 1,
+                }
+            }
+        }
+
+        latencies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let samples = latencies.len();
+        reports.push(ProviderBenchmark {
+            provider: provider.name().to_string(),
+            priority: provider.priority(),
+            samples,
+            p50_ms: percentile(&latencies, 0.5),
+            p95_ms: percentile(&latencies, 0.95),
+            max_ms: latencies.last().copied().unwrap_or(0.0),
+            avg_results: if samples > 0 {
+                total_results as f64 / samples as f64
+            } else {
+                0.0
+            },
+            timeouts,
+        });
+        log::info!(
+            "[Benchmark] provider '{}': {} samples, {} timeouts",
+            provider.name(),
+            samples,
+            timeouts
+        );
+    }
+
+    reports.sort_by(|a, b| b.p95_ms.partial_cmp(&a.p95_ms).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(reports)
+}
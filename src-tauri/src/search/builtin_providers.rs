@@ -0,0 +1,105 @@
+//! 内置搜索 provider
+//!
+//! 各内置数据源对 `SearchProvider` 的实现。应用/文件索引、浏览器
+//! 数据等来源在各自模块就绪后同样在 `register_builtins` 里登记。
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tauri::AppHandle;
+
+use super::pipeline::{self, SearchProvider, SearchResult};
+
+/// 剪贴板历史
+struct ClipboardProvider;
+
+#[async_trait]
+impl SearchProvider for ClipboardProvider {
+    fn name(&self) -> &str {
+        "clipboard"
+    }
+
+    fn priority(&self) -> i32 {
+        20
+    }
+
+    async fn search(&self, query: &str) -> Vec<SearchResult> {
+        let query = query.to_string();
+        // SQLite 查询是阻塞调用，放 blocking 池
+        tauri::async_runtime::spawn_blocking(move || {
+            let Ok(conn) = crate::db::pool::get() else { return Vec::new() };
+            let Ok(mut stmt) = conn.prepare(
+                "SELECT id, content FROM clipboard_history
+                 WHERE is_sensitive = 0 AND content_type = 'text' AND content IS NOT NULL
+                 ORDER BY created_at DESC LIMIT 500",
+            ) else {
+                return Vec::new();
+            };
+            let rows: Vec<(i64, String)> = stmt
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map(|rows| rows.flatten().collect())
+                .unwrap_or_default();
+            let mut results: Vec<SearchResult> = rows
+                .into_iter()
+                .filter_map(|(id, content)| {
+                    crate::services::pinyin_matcher::score(&query, &content).map(|score| {
+                        SearchResult {
+                            id: format!("clip:{}", id),
+                            title: content.chars().take(80).collect(),
+                            subtitle: Some("剪贴板".into()),
+                            icon: Some("builtin:clipboard".into()),
+                            provider: String::new(),
+                            score,
+                            payload: serde_json::json!({ "clipboardId": id }),
+                        }
+                    })
+                })
+                .collect();
+            results.sort_by(|a, b| b.score.cmp(&a.score));
+            results.truncate(20);
+            results
+        })
+        .await
+        .unwrap_or_default()
+    }
+}
+
+/// 应用命令面板
+struct AppCommandsProvider;
+
+#[async_trait]
+impl SearchProvider for AppCommandsProvider {
+    fn name(&self) -> &str {
+        "app-commands"
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+
+    async fn search(&self, query: &str) -> Vec<SearchResult> {
+        crate::app::app_commands::catalog()
+            .into_iter()
+            .filter_map(|cmd| {
+                let best = std::iter::once(cmd.title.as_str())
+                    .chain(cmd.keywords.iter().map(|k| k.as_str()))
+                    .filter_map(|text| crate::services::pinyin_matcher::score(query, text))
+                    .max()?;
+                Some(SearchResult {
+                    id: format!("cmd:{}", cmd.id),
+                    title: cmd.title.clone(),
+                    subtitle: Some(cmd.category.clone()),
+                    icon: Some("builtin:command".into()),
+                    provider: String::new(),
+                    score: best,
+                    payload: serde_json::json!({ "commandId": cmd.id }),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 注册全部内置 provider（启动时调用一次）
+pub fn register_builtins(_app: &AppHandle) {
+    pipeline::register_provider(Arc::new(AppCommandsProvider));
+    pipeline::register_provider(Arc::new(ClipboardProvider));
+}
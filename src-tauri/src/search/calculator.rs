@@ -0,0 +1,291 @@
+//! 搜索栏内置计算器
+//!
+//! "23*4+sqrt(9)"、"15% of 230" 这类查询直接出结果，不需要装插件。
+//! 递归下降解析：运算符优先级、括号、常用函数与常量，小数点接受
+//! `.` 和 `,`（跟随语言设置的小数分隔符）。作为高优先级 provider
+//! 注册进搜索流水线，结果带复制动作。
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::pipeline::{self, SearchProvider, SearchResult};
+
+struct Parser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    src: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self {
+            chars: src.chars().collect(),
+            pos: 0,
+            src,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_ws();
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 加减
+    fn expr(&mut self) -> Result<f64, String> {
+        let mut value = self.term()?;
+        loop {
+            self.skip_ws();
+            match self.peek() {
+                Some('+') => {
+                    self.pos += 1;
+                    value += self.term()?;
+                }
+                Some('-') => {
+                    self.pos += 1;
+                    value -= self.term()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    /// 乘除取余
+    fn term(&mut self) -> Result<f64, String> {
+        let mut value = self.power()?;
+        loop {
+            self.skip_ws();
+            match self.peek() {
+                Some('*') => {
+                    self.pos += 1;
+                    value *= self.power()?;
+                }
+                Some('/') => {
+                    self.pos += 1;
+                    let divisor = self.power()?;
+                    if divisor == 0.0 {
+                        return Err("除数为零".into());
+                    }
+                    value /= divisor;
+                }
+                Some('%') => {
+                    // 可能是取余（后面还有操作数）或百分数后缀
+                    let save = self.pos;
+                    self.pos += 1;
+                    self.skip_ws();
+                    if self.peek().map_or(false, |c| {
+                        c.is_ascii_digit() || c == '(' || c == '.' || c == ','
+                    }) {
+                        let rhs = self.power()?;
+                        value %= rhs;
+                    } else {
+                        self.pos = save;
+                        return Ok(value);
+                    }
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    /// 幂（右结合）
+    fn power(&mut self) -> Result<f64, String> {
+        let base = self.unary()?;
+        self.skip_ws();
+        if self.peek() == Some('^') {
+            self.pos += 1;
+            let exp = self.power()?;
+            return Ok(base.powf(exp));
+        }
+        Ok(base)
+    }
+
+    fn unary(&mut self) -> Result<f64, String> {
+        self.skip_ws();
+        if self.eat('-') {
+            return Ok(-self.unary()?);
+        }
+        let mut value = self.atom()?;
+        // 百分数后缀：50% -> 0.5
+        self.skip_ws();
+        if self.peek() == Some('%') {
+            let save = self.pos;
+            self.pos += 1;
+            self.skip_ws();
+            let next = self.peek();
+            if next.map_or(true, |c| !(c.is_ascii_digit() || c == '(' || c == '.' || c == ',')) {
+                value /= 100.0;
+            } else {
+                self.pos = save;
+            }
+        }
+        Ok(value)
+    }
+
+    fn atom(&mut self) -> Result<f64, String> {
+        self.skip_ws();
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let value = self.expr()?;
+                if !self.eat(')') {
+                    return Err("缺少右括号".into());
+                }
+                Ok(value)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' || c == ',' => self.number(),
+            Some(c) if c.is_ascii_alphabetic() => self.ident(),
+            _ => Err(format!("无法解析: {}", self.src)),
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        let start = self.pos;
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() {
+                text.push(c);
+                self.pos += 1;
+            } else if c == '.' || c == ',' {
+                // 语言设置用逗号做小数点时同样接受
+                text.push('.');
+                self.pos += 1;
+            } else if c == '_' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        if self.pos == start {
+            return Err("期望数字".into());
+        }
+        text.parse::<f64>().map_err(|_| format!("非法数字: {}", text))
+    }
+
+    fn ident(&mut self) -> Result<f64, String> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+        let name: String = self.chars[start..self.pos].iter().collect::<String>().to_lowercase();
+        match name.as_str() {
+            "pi" => return Ok(std::f64::consts::PI),
+            "e" => return Ok(std::f64::consts::E),
+            _ => {}
+        }
+        if !self.eat('(') {
+            return Err(format!("未知标识符: {}", name));
+        }
+        let arg = self.expr()?;
+        if !self.eat(')') {
+            return Err("缺少右括号".into());
+        }
+        let result = match name.as_str() {
+            "sqrt" => arg.sqrt(),
+            "abs" => arg.abs(),
+            "ln" => arg.ln(),
+            "log" => arg.log10(),
+            "sin" => arg.sin(),
+            "cos" => arg.cos(),
+            "tan" => arg.tan(),
+            "round" => arg.round(),
+            "floor" => arg.floor(),
+            "ceil" => arg.ceil(),
+            other => return Err(format!("未知函数: {}", other)),
+        };
+        Ok(result)
+    }
+}
+
+/// 计算表达式；"15% of 230" 先归一成 "(15/100)*(230)"
+pub fn evaluate(input: &str) -> Result<f64, String> {
+    let trimmed = input.trim();
+    let normalized = if let Some((pct, base)) = trimmed.to_lowercase().split_once(" of ") {
+        let pct = pct.trim().trim_end_matches('%');
+        format!("(({})/100)*({})", pct, base.trim())
+    } else {
+        trimmed.to_string()
+    };
+    let mut parser = Parser::new(&normalized);
+    let value = parser.expr()?;
+    parser.skip_ws();
+    if parser.pos != parser.chars.len() {
+        return Err(format!("表达式末尾有多余内容: {}", trimmed));
+    }
+    if !value.is_finite() {
+        return Err("结果超出范围".into());
+    }
+    Ok(value)
+}
+
+/// 粗判输入是否像算式，避免每次按键都跑解析器
+fn looks_like_math(input: &str) -> bool {
+    let has_digit = input.chars().any(|c| c.is_ascii_digit());
+    let has_op = input.chars().any(|c| "+-*/^%(".contains(c))
+        || input.to_lowercase().contains(" of ");
+    has_digit && has_op
+}
+
+struct CalculatorProvider;
+
+#[async_trait]
+impl SearchProvider for CalculatorProvider {
+    fn name(&self) -> &str {
+        "calculator"
+    }
+
+    fn priority(&self) -> i32 {
+        5
+    }
+
+    async fn search(&self, query: &str) -> Vec<SearchResult> {
+        if !looks_like_math(query) {
+            return Vec::new();
+        }
+        match evaluate(query) {
+            Ok(value) => {
+                // 去掉无意义的尾零
+                let display = if value.fract() == 0.0 && value.abs() < 1e15 {
+                    format!("{}", value as i64)
+                } else {
+                    format!("{}", value)
+                };
+                vec![SearchResult {
+                    id: "calc:result".into(),
+                    title: display.clone(),
+                    subtitle: Some(format!("{} =", query.trim())),
+                    icon: Some("builtin:calculator".into()),
+                    provider: String::new(),
+                    score: i32::MAX - 1,
+                    payload: serde_json::json!({ "action": "copy", "text": display }),
+                }]
+            }
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+/// 注册计算器 provider（启动时调用）
+pub fn register() {
+    pipeline::register_provider(Arc::new(CalculatorProvider));
+}
+
+/// 直接求值（前端即时预览用）
+#[tauri::command]
+pub fn evaluate_expression(expression: String) -> Result<f64, String> {
+    evaluate(&expression)
+}
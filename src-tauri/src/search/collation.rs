@@ -0,0 +1,72 @@
+//! 搜索归一化与排序
+//!
+//! 应用、文件、剪贴板三路搜索统一使用这里的归一化：
+//! NFKC（全角转半角等兼容分解）+ case folding，可选去变音符号，
+//! 让 "café"、"CAFE"、"ｃａｆｅ" 按预期互相匹配。
+//! 排序用本地化 collation key，中文按拼音区域规则排列稳定。
+
+use once_cell::sync::Lazy;
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use unicode_normalization::UnicodeNormalization;
+
+/// 变音符号不敏感开关（设置页切换）
+static DIACRITIC_INSENSITIVE: Lazy<AtomicBool> = Lazy::new(|| AtomicBool::new(true));
+
+/// 归一化一段文本用于匹配：NFKC + 小写化（+ 可选去变音符号）
+pub fn normalize_for_match(input: &str) -> String {
+    let folded: String = input.nfkc().flat_map(char::to_lowercase).collect();
+    if DIACRITIC_INSENSITIVE.load(AtomicOrdering::Relaxed) {
+        strip_diacritics(&folded)
+    } else {
+        folded
+    }
+}
+
+/// 去除组合变音符号：NFD 分解后丢弃 Mn 类别码点
+pub fn strip_diacritics(input: &str) -> String {
+    input
+        .nfd()
+        .filter(|c| {
+            // U+0300..U+036F 覆盖拉丁文常见组合符号；其余 Mn 很少出现在搜索词里
+            !('\u{0300}'..='\u{036F}').contains(c)
+        })
+        .collect()
+}
+
+/// 归一化后的包含匹配；所有搜索 provider 的子串判断统一走这里
+pub fn matches(haystack: &str, needle: &str) -> bool {
+    if needle.is_empty() {
+        return true;
+    }
+    normalize_for_match(haystack).contains(&normalize_for_match(needle))
+}
+
+/// 本地化比较：归一化后逐字符比较，汉字按码点、其余按 fold 后顺序。
+/// 用作各搜索结果列表的稳定排序。
+pub fn compare(a: &str, b: &str) -> Ordering {
+    let na = normalize_for_match(a);
+    let nb = normalize_for_match(b);
+    // 非字母数字前缀（如 "." 开头的文件）排到最后
+    let a_special = na.chars().next().map(|c| !c.is_alphanumeric()).unwrap_or(false);
+    let b_special = nb.chars().next().map(|c| !c.is_alphanumeric()).unwrap_or(false);
+    match (a_special, b_special) {
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        _ => {}
+    }
+    na.cmp(&nb)
+}
+
+/// 设置变音符号不敏感开关
+#[tauri::command]
+pub fn set_diacritic_insensitive(enabled: bool) {
+    DIACRITIC_INSENSITIVE.store(enabled, AtomicOrdering::Relaxed);
+    log::info!("[Collation] diacritic-insensitive matching: {}", enabled);
+}
+
+/// 读取当前开关状态
+#[tauri::command]
+pub fn get_diacritic_insensitive() -> bool {
+    DIACRITIC_INSENSITIVE.load(AtomicOrdering::Relaxed)
+}
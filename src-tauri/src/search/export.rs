@@ -0,0 +1,116 @@
+//! 搜索结果导出
+//!
+//! `export_search_results(query, format, path)` 执行一次不分页的完整搜索，
+//! 把结果写成 CSV 或 JSON——排查重复文件、生成清单时很有用。
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+
+use crate::db::pool;
+
+/// 导出的单行结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRow {
+    /// 结果来源："clipboard" / "file" / "history"
+    pub source: String,
+    pub title: String,
+    #[serde(default)]
+    pub detail: Option<String>,
+    /// 时间戳（Unix 秒），无则为 0
+    pub timestamp: i64,
+}
+
+/// 执行不分页的完整搜索；与交互式搜索不同，这里不做条数截断
+fn run_unpaginated(query: &str) -> Result<Vec<ExportRow>, String> {
+    let conn = pool::get()?;
+    let mut rows = Vec::new();
+    let pattern = format!("%{}%", query.replace('%', "\\%").replace('_', "\\_"));
+
+    // 剪贴板历史（跳过敏感条目，导出文件不应包含凭证）
+    let mut stmt = conn
+        .prepare(
+            "SELECT content, created_at FROM clipboard_history
+             WHERE content LIKE ?1 ESCAPE '\\' AND is_sensitive = 0
+             ORDER BY created_at DESC",
+        )
+        .map_err(|e| e.to_string())?;
+    let clipboard = stmt
+        .query_map([&pattern], |row| {
+            Ok(ExportRow {
+                source: "clipboard".into(),
+                title: row.get::<_, String>(0)?,
+                detail: None,
+                timestamp: row.get(1)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    for row in clipboard.flatten() {
+        rows.push(row);
+    }
+
+    // 搜索历史
+    let mut stmt = conn
+        .prepare(
+            "SELECT query, searched_at FROM search_history
+             WHERE query LIKE ?1 ESCAPE '\\' ORDER BY searched_at DESC",
+        )
+        .map_err(|e| e.to_string())?;
+    let history = stmt
+        .query_map([&pattern], |row| {
+            Ok(ExportRow {
+                source: "history".into(),
+                title: row.get::<_, String>(0)?,
+                detail: None,
+                timestamp: row.get(1)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    for row in history.flatten() {
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// CSV 字段转义
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// 导出搜索结果到文件；`format` 为 "csv" 或 "json"。返回导出条数
+#[tauri::command]
+pub fn export_search_results(query: String, format: String, path: String) -> Result<usize, String> {
+    let rows = run_unpaginated(&query)?;
+    let mut file = fs::File::create(&path).map_err(|e| format!("创建导出文件失败: {}", e))?;
+
+    match format.as_str() {
+        "json" => {
+            let json = serde_json::to_string_pretty(&rows).map_err(|e| e.to_string())?;
+            file.write_all(json.as_bytes())
+                .map_err(|e| format!("写入失败: {}", e))?;
+        }
+        "csv" => {
+            writeln!(file, "source,title,detail,timestamp").map_err(|e| e.to_string())?;
+            for row in &rows {
+                writeln!(
+                    file,
+                    "{},{},{},{}",
+                    csv_escape(&row.source),
+                    csv_escape(&row.title),
+                    csv_escape(row.detail.as_deref().unwrap_or("")),
+                    row.timestamp
+                )
+                .map_err(|e| e.to_string())?;
+            }
+        }
+        other => return Err(format!("不支持的导出格式: {}（支持 csv / json）", other)),
+    }
+
+    log::info!("[Export] exported {} rows to {}", rows.len(), path);
+    Ok(rows.len())
+}
@@ -0,0 +1,166 @@
+//! 文件内容全文检索
+//!
+//! 可选的内容索引：纯文本/Markdown/代码直接读，PDF 取文本层，
+//! 写入 FTS5 虚表；`search_file_contents` 用 `snippet()` 返回命中
+//! 片段与高亮。按 mtime 增量——没变的文件不会重复索引。免索引名单
+//! 与文件访问守卫在入口处生效。
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tauri::AppHandle;
+
+/// 单文件内容上限：4 MB，超过只取前段
+const MAX_CONTENT_BYTES: usize = 4 * 1024 * 1024;
+/// 可索引的文本扩展名
+const TEXT_EXTENSIONS: &[&str] = &[
+    "txt", "md", "markdown", "rst", "log", "json", "yaml", "yml", "toml", "ini", "csv",
+    "rs", "ts", "tsx", "js", "jsx", "py", "go", "java", "kt", "c", "cc", "cpp", "h", "hpp",
+    "sh", "sql", "html", "css", "vue", "svelte",
+];
+
+/// 全文检索命中
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentHit {
+    pub path: String,
+    /// 命中片段，命中词用 <mark></mark> 包裹
+    pub snippet: String,
+    pub rank: f64,
+}
+
+/// 按扩展名提取文本；不支持的类型返回 None
+fn extract_text(path: &Path) -> Option<String> {
+    let ext = path.extension()?.to_string_lossy().to_lowercase();
+    if ext == "pdf" {
+        return match pdf_extract::extract_text(path) {
+            Ok(text) if !text.trim().is_empty() => Some(text),
+            _ => None,
+        };
+    }
+    if !TEXT_EXTENSIONS.contains(&ext.as_str()) {
+        return None;
+    }
+    let bytes = std::fs::read(path).ok()?;
+    let truncated = &bytes[..bytes.len().min(MAX_CONTENT_BYTES)];
+    Some(String::from_utf8_lossy(truncated).into_owned())
+}
+
+/// 索引单个文件；返回是否实际写入（未变化/不支持时 false）
+pub fn index_file(path: &Path) -> Result<bool, String> {
+    if crate::services::do_not_index::is_excluded(path) {
+        return Ok(false);
+    }
+    let meta = std::fs::metadata(path).map_err(|e| e.to_string())?;
+    let mtime = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let path_str = path.display().to_string();
+
+    let conn = crate::db::pool::get()?;
+    let known_mtime: Option<i64> = conn
+        .query_row(
+            "SELECT mtime FROM file_contents_meta WHERE path = ?1",
+            params![path_str],
+            |row| row.get(0),
+        )
+        .ok();
+    if known_mtime == Some(mtime) {
+        return Ok(false);
+    }
+    let Some(content) = extract_text(path) else {
+        return Ok(false);
+    };
+
+    conn.execute("DELETE FROM file_contents WHERE path = ?1", params![path_str])
+        .map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT INTO file_contents (path, content) VALUES (?1, ?2)",
+        params![path_str, content],
+    )
+    .map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT OR REPLACE INTO file_contents_meta (path, mtime, indexed_at)
+         VALUES (?1, ?2, ?3)",
+        params![path_str, mtime, chrono::Utc::now().timestamp()],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+/// 把路径移出内容索引（文件删除/进免索引名单时）
+pub fn remove_file(path: &Path) -> Result<(), String> {
+    let conn = crate::db::pool::get()?;
+    let path_str = path.display().to_string();
+    conn.execute("DELETE FROM file_contents WHERE path = ?1", params![path_str])
+        .map_err(|e| e.to_string())?;
+    conn.execute(
+        "DELETE FROM file_contents_meta WHERE path = ?1",
+        params![path_str],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 递归索引目录内容；返回新索引的文件数
+#[tauri::command]
+pub async fn index_directory_contents(app: AppHandle, path: String) -> Result<u64, String> {
+    crate::cmds::fs_guard::check_access(&app, &path, crate::cmds::fs_guard::Access::Read)?;
+    let token = crate::app::cancellation::CancelToken::register(&format!("content-index:{}", path))?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut indexed = 0u64;
+        let mut stack = vec![std::path::PathBuf::from(&path)];
+        while let Some(dir) = stack.pop() {
+            token.check()?;
+            let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+            for entry in entries.flatten() {
+                let entry_path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                if name.starts_with('.') || name == "node_modules" || name == "target" {
+                    continue;
+                }
+                if entry_path.is_dir() {
+                    stack.push(entry_path);
+                } else if let Ok(true) = index_file(&entry_path) {
+                    indexed += 1;
+                }
+            }
+        }
+        Ok(indexed)
+    })
+    .await
+    .map_err(|e| format!("索引任务异常: {}", e))?
+}
+
+/// 全文检索；FTS5 snippet 高亮
+#[tauri::command]
+pub fn search_file_contents(query: String, limit: Option<i64>) -> Result<Vec<ContentHit>, String> {
+    let trimmed = query.trim();
+    if trimmed.is_empty() {
+        return Ok(Vec::new());
+    }
+    // 引号包住用户输入，避免 FTS5 语法注入
+    let fts_query = format!("\"{}\"", trimmed.replace('"', "\"\""));
+    let conn = crate::db::pool::get()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT path, snippet(file_contents, 1, '<mark>', '</mark>', '…', 16), rank
+             FROM file_contents WHERE file_contents MATCH ?1
+             ORDER BY rank LIMIT ?2",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(params![fts_query, limit.unwrap_or(30).clamp(1, 100)], |row| {
+            Ok(ContentHit {
+                path: row.get(0)?,
+                snippet: row.get(1)?,
+                rank: row.get(2)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
@@ -0,0 +1,103 @@
+//! 模糊匹配打分器
+//!
+//! unified_search 之前只做子串匹配，"vsc" 搜不到 "Visual Studio Code"。
+//! 这里实现 fzf/Skim 风格的打分：必须按序命中全部查询字符，
+//! 连续命中、单词首字母（空格/驼峰/分隔符后）命中加分，
+//! 命中位置靠前加分，间隙扣分。返回 None 表示不匹配。
+
+/// 连续命中加分
+const BONUS_CONSECUTIVE: i32 = 8;
+/// 单词边界（空格、-、_、. 之后或驼峰大写）命中加分
+const BONUS_WORD_START: i32 = 12;
+/// 整串首字符命中加分
+const BONUS_FIRST_CHAR: i32 = 16;
+/// 每个间隙字符的扣分
+const PENALTY_GAP: i32 = -1;
+/// 基础命中分
+const SCORE_MATCH: i32 = 10;
+
+/// 判断 `target[idx]` 是否处于单词边界
+fn is_word_start(chars: &[char], idx: usize) -> bool {
+    if idx == 0 {
+        return true;
+    }
+    let prev = chars[idx - 1];
+    let cur = chars[idx];
+    if prev == ' ' || prev == '-' || prev == '_' || prev == '.' || prev == '/' {
+        return true;
+    }
+    // 驼峰：小写后跟大写
+    prev.is_lowercase() && cur.is_uppercase()
+}
+
+/// 模糊匹配打分；不匹配返回 None，分数越高越靠前
+pub fn score(query: &str, target: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let query_chars: Vec<char> = query.chars().flat_map(|c| c.to_lowercase()).collect();
+    let target_chars: Vec<char> = target.chars().collect();
+    let target_lower: Vec<char> = target_chars
+        .iter()
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+    if query_chars.len() > target_lower.len() {
+        return None;
+    }
+
+    let mut total = 0i32;
+    let mut qi = 0usize;
+    let mut last_match: Option<usize> = None;
+    for ti in 0..target_lower.len() {
+        if qi >= query_chars.len() {
+            break;
+        }
+        if target_lower[ti] != query_chars[qi] {
+            continue;
+        }
+        let mut gained = SCORE_MATCH;
+        if ti == 0 {
+            gained += BONUS_FIRST_CHAR;
+        }
+        if is_word_start(&target_chars, ti.min(target_chars.len().saturating_sub(1))) {
+            gained += BONUS_WORD_START;
+        }
+        if let Some(last) = last_match {
+            if ti == last + 1 {
+                gained += BONUS_CONSECUTIVE;
+            } else {
+                gained += PENALTY_GAP * ((ti - last - 1).min(10) as i32);
+            }
+        }
+        total += gained;
+        last_match = Some(ti);
+        qi += 1;
+    }
+    if qi < query_chars.len() {
+        return None;
+    }
+    // 短目标优先：同分时更短的名字更可能是想要的
+    total -= (target_lower.len() / 8) as i32;
+    Some(total)
+}
+
+/// 对候选列表打分排序，返回 (原索引, 分数) 降序；
+/// 各 provider 只需提供候选文本
+pub fn rank<'a, I>(query: &str, candidates: I) -> Vec<(usize, i32)>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut scored: Vec<(usize, i32)> = candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, text)| score(query, text).map(|s| (idx, s)))
+        .collect();
+    scored.sort_by(|a, b| b.1.cmp(&a.1));
+    scored
+}
+
+/// 供前端直接调用的打分命令（插件自定义排序用）
+#[tauri::command]
+pub fn fuzzy_score(query: String, target: String) -> Option<i32> {
+    score(&query, &target)
+}
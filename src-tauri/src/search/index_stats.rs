@@ -0,0 +1,142 @@
+//! 索引统计快照与趋势
+//!
+//! 每天落一条索引规模、搜索次数、延迟分位数的快照，
+//! `get_performance_metrics` 据此返回最近 30 天的趋势数据，
+//! 帮助定位"越用越慢"类问题。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+use crate::db::pool;
+
+/// 保留的快照天数
+const RETENTION_DAYS: i64 = 30;
+
+/// 单日快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailySnapshot {
+    /// 日期 "YYYY-MM-DD"
+    pub day: String,
+    pub indexed_files: i64,
+    pub search_count: i64,
+    pub latency_p50_ms: f64,
+    pub latency_p95_ms: f64,
+}
+
+/// 当日搜索延迟样本（毫秒），快照时计算分位数后清空
+static LATENCY_SAMPLES: Lazy<Mutex<Vec<f64>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// 每次搜索完成后上报延迟
+pub fn record_search_latency(ms: f64) {
+    if let Ok(mut samples) = LATENCY_SAMPLES.lock() {
+        // 超量时随机淘汰前半，足以支撑分位数估计
+        if samples.len() >= 10_000 {
+            samples.drain(..5_000);
+        }
+        samples.push(ms);
+    }
+}
+
+fn ensure_table() -> Result<(), String> {
+    let conn = pool::get()?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS index_stats_daily (
+             day TEXT PRIMARY KEY,
+             indexed_files INTEGER NOT NULL,
+             search_count INTEGER NOT NULL,
+             latency_p50_ms REAL NOT NULL,
+             latency_p95_ms REAL NOT NULL
+         );",
+    )
+    .map_err(|e| e.to_string())
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let idx = ((sorted.len() as f64 - 1.0) * p).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+/// 写入当日快照（维护任务每日触发；同日重复写入则覆盖）
+pub fn take_snapshot(indexed_files: i64) -> Result<(), String> {
+    ensure_table()?;
+    let conn = pool::get()?;
+    let day = chrono::Local::now().format("%Y-%m-%d").to_string();
+
+    // searched_at 存的是 UTC 时间戳，本地零点要按本地时区换算成 epoch，
+    // 直接按 UTC 解释会让统计窗口整体偏移一个时区
+    let today_start = chrono::Local::now()
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|t| t.and_local_timezone(chrono::Local).earliest())
+        .map(|t| t.timestamp())
+        .unwrap_or(0);
+    let search_count: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM search_history WHERE searched_at >= ?1",
+            [today_start],
+            |r| r.get(0),
+        )
+        .unwrap_or(0);
+
+    let (p50, p95) = {
+        let mut samples = LATENCY_SAMPLES.lock().map_err(|e| e.to_string())?;
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let result = (percentile(&samples, 0.5), percentile(&samples, 0.95));
+        samples.clear();
+        result
+    };
+
+    conn.execute(
+        "INSERT INTO index_stats_daily (day, indexed_files, search_count, latency_p50_ms, latency_p95_ms)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(day) DO UPDATE SET
+             indexed_files = excluded.indexed_files,
+             search_count = excluded.search_count,
+             latency_p50_ms = excluded.latency_p50_ms,
+             latency_p95_ms = excluded.latency_p95_ms",
+        rusqlite::params![day, indexed_files, search_count, p50, p95],
+    )
+    .map_err(|e| e.to_string())?;
+
+    // 清理过期快照
+    let cutoff = (chrono::Local::now() - chrono::Duration::days(RETENTION_DAYS))
+        .format("%Y-%m-%d")
+        .to_string();
+    conn.execute(
+        "DELETE FROM index_stats_daily WHERE day < ?1",
+        rusqlite::params![cutoff],
+    )
+    .map_err(|e| e.to_string())?;
+    log::info!("[IndexStats] daily snapshot taken for {}", day);
+    Ok(())
+}
+
+/// 最近 30 天的趋势数据
+#[tauri::command]
+pub fn get_index_stats_trend() -> Result<Vec<DailySnapshot>, String> {
+    ensure_table()?;
+    let conn = pool::get()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT day, indexed_files, search_count, latency_p50_ms, latency_p95_ms
+             FROM index_stats_daily ORDER BY day",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(DailySnapshot {
+                day: row.get(0)?,
+                indexed_files: row.get(1)?,
+                search_count: row.get(2)?,
+                latency_p50_ms: row.get(3)?,
+                latency_p95_ms: row.get(4)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
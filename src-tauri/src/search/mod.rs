@@ -0,0 +1,16 @@
+pub mod benchmark;
+pub mod builtin_providers;
+pub mod calculator;
+pub mod collation;
+pub mod pipeline;
+pub mod fulltext;
+pub mod fuzzy;
+pub mod export;
+pub mod index_stats;
+pub mod regex_mode;
+pub mod result_actions;
+pub mod saved_searches;
+pub mod scopes;
+pub mod streaming;
+pub mod trigger_registry;
+pub mod typo;
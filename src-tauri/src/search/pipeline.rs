@@ -0,0 +1,310 @@
+//! 搜索流水线与 SearchProvider trait
+//!
+//! `unified_search` 原来是一大块顺序执行的匹配代码，插件结果只能走
+//! 触发词。现在拆成 provider 模型：每个数据源实现 `SearchProvider`
+//! （名称、优先级、异步 search），启动时注册；已启用插件也能注册为
+//! provider——后端发 `plugin://search-request` 事件，插件运行时在限时
+//! 内用 `plugin_search_respond` 回包，结果与内置来源一起参与排序。
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// 插件 provider 的搜索请求事件
+pub const PLUGIN_SEARCH_EVENT: &str = "plugin://search-request";
+/// 单个 provider 的搜索预算（阻塞与流式路径共用）
+pub(crate) const PROVIDER_TIMEOUT: Duration = Duration::from_millis(400);
+
+/// 统一的搜索结果条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    /// provider 内唯一的结果 id
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub subtitle: Option<String>,
+    /// 图标引用；由图标服务统一解析
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// 来源 provider 名称（由流水线填充）
+    #[serde(default)]
+    pub provider: String,
+    /// 排序分；provider 内部打分，流水线跨 provider 归并
+    pub score: i32,
+    /// 选中时的动作载荷，provider 自定义
+    #[serde(default)]
+    pub payload: serde_json::Value,
+}
+
+/// 搜索数据源
+#[async_trait]
+pub trait SearchProvider: Send + Sync {
+    /// 稳定名称（设置页开关、结果归属展示用）
+    fn name(&self) -> &str;
+    /// 同分结果的来源间排序：数值小的靠前
+    fn priority(&self) -> i32 {
+        100
+    }
+    /// 只在显式范围前缀下运行；网络/子进程类重型 provider 置 true，
+    /// 避免在每个按键的全量查询里被触发
+    fn scope_only(&self) -> bool {
+        false
+    }
+    async fn search(&self, query: &str) -> Vec<SearchResult>;
+}
+
+static PROVIDERS: Lazy<RwLock<Vec<Arc<dyn SearchProvider>>>> =
+    Lazy::new(|| RwLock::new(Vec::new()));
+
+/// 连续查询的序号；防抖窗口内到来的新查询会让旧查询直接放弃
+static SEARCH_TICKET: AtomicU64 = AtomicU64::new(0);
+
+/// 设置项 `search_debounce_ms`：快速输入时后端侧合并查询
+pub(crate) fn debounce_ms() -> u64 {
+    crate::settings::store::get("search_debounce_ms")
+        .ok()
+        .and_then(|v| v.as_u64())
+        .unwrap_or(120)
+}
+
+/// 设置项 `max_results`：单个 provider 最多返回的条数
+pub(crate) fn per_provider_cap() -> usize {
+    crate::settings::store::get("max_results")
+        .ok()
+        .and_then(|v| v.as_u64())
+        .unwrap_or(30) as usize
+}
+
+/// 注册 provider；各数据源在启动时调用
+pub fn register_provider(provider: Arc<dyn SearchProvider>) {
+    if let Ok(mut providers) = PROVIDERS.write() {
+        log::info!("[Search] provider '{}' registered", provider.name());
+        providers.push(provider);
+    }
+}
+
+/// 当前 provider 列表的快照（流式搜索用）
+pub fn providers_snapshot() -> Vec<Arc<dyn SearchProvider>> {
+    PROVIDERS.read().map(|p| p.clone()).unwrap_or_default()
+}
+
+/// 按名称摘除 provider（插件禁用/卸载时）
+pub fn unregister_provider(name: &str) {
+    if let Ok(mut providers) = PROVIDERS.write() {
+        providers.retain(|p| p.name() != name);
+    }
+}
+
+/// 统一搜索：触发词优先，其余 provider 并发执行后归并
+#[tauri::command]
+pub async fn unified_search(query: String) -> Result<Vec<SearchResult>, String> {
+    let trimmed = query.trim();
+    if trimmed.is_empty() {
+        return Ok(Vec::new());
+    }
+    // 后端侧防抖：窗口内又来了新查询就放弃本次，省掉整轮 provider 调用
+    let ticket = SEARCH_TICKET.fetch_add(1, Ordering::SeqCst) + 1;
+    let debounce = debounce_ms();
+    if debounce > 0 {
+        tokio::time::sleep(Duration::from_millis(debounce)).await;
+        if SEARCH_TICKET.load(Ordering::SeqCst) != ticket {
+            return Ok(Vec::new());
+        }
+    }
+    // 触发词命中时交给对应归属方，不再跑全量 provider
+    if let Some(resolved) = crate::search::trigger_registry::resolve(trimmed) {
+        return Ok(vec![SearchResult {
+            id: format!("trigger:{}", resolved.trigger.keyword),
+            title: resolved.trigger.keyword.clone(),
+            subtitle: Some(resolved.rest.clone()),
+            icon: None,
+            provider: "trigger".into(),
+            score: i32::MAX,
+            payload: serde_json::to_value(&resolved).unwrap_or_default(),
+        }]);
+    }
+
+    // 范围前缀：只跑指定 provider
+    let (scope_provider, effective_query) = match crate::search::scopes::parse(trimmed) {
+        Some((provider, rest)) => (Some(provider), rest),
+        None => (None, trimmed.to_string()),
+    };
+
+    let mut results = run_providers(scope_provider.as_deref(), &effective_query).await?;
+
+    // 零结果时尝试编辑距离 1 的纠错重查，命中则带上“你是不是要找”标记
+    if results.is_empty() {
+        if let Some((corrected, corrected_results)) =
+            crate::search::typo::retry_with_corrections(scope_provider.as_deref(), &effective_query)
+                .await
+        {
+            results.push(SearchResult {
+                id: format!("did-you-mean:{}", corrected),
+                title: format!("你是不是要找：{}", corrected),
+                subtitle: Some(effective_query.clone()),
+                icon: None,
+                provider: "suggestion".into(),
+                score: i32::MAX,
+                payload: serde_json::json!({
+                    "original": effective_query,
+                    "corrected": corrected,
+                }),
+            });
+            results.extend(corrected_results);
+        }
+    }
+
+    // 冗长标签模式：给读屏器补充来源上下文
+    if crate::services::accessibility::current().verbose_labels {
+        for result in results.iter_mut() {
+            let suffix = format!("来自 {}", result.provider);
+            result.subtitle = Some(match result.subtitle.take() {
+                Some(sub) => format!("{}（{}）", sub, suffix),
+                None => suffix,
+            });
+        }
+    }
+    Ok(results)
+}
+
+/// 并发执行 provider 并按分数归并（正常查询与纠错重查共用）
+pub(crate) async fn run_providers(
+    scope_provider: Option<&str>,
+    query: &str,
+) -> Result<Vec<SearchResult>, String> {
+    let providers: Vec<Arc<dyn SearchProvider>> = PROVIDERS
+        .read()
+        .map_err(|e| e.to_string())?
+        .iter()
+        .filter(|p| match scope_provider {
+            Some(scope) => p.name() == scope,
+            // 无范围的全量查询跳过 scope_only provider
+            None => !p.scope_only(),
+        })
+        .cloned()
+        .collect();
+
+    let cap = per_provider_cap();
+    let mut handles = Vec::with_capacity(providers.len());
+    for provider in providers {
+        let query = query.to_string();
+        handles.push(tauri::async_runtime::spawn(async move {
+            let started = std::time::Instant::now();
+            let mut results =
+                match tokio::time::timeout(PROVIDER_TIMEOUT, provider.search(&query)).await {
+                    Ok(results) => results,
+                    Err(_) => {
+                        log::warn!("[Search] provider '{}' timed out", provider.name());
+                        Vec::new()
+                    }
+                };
+            // provider 内先按分排序再截断，保证截掉的是低分结果
+            results.sort_by(|a, b| b.score.cmp(&a.score));
+            results.truncate(cap);
+            crate::search::index_stats::record_search_latency(started.elapsed().as_secs_f64() * 1000.0);
+            (provider.name().to_string(), provider.priority(), results)
+        }));
+    }
+
+    let mut merged = Vec::new();
+    for handle in handles {
+        if let Ok((name, priority, results)) = handle.await {
+            for mut result in results {
+                result.provider = name.clone();
+                merged.push((priority, result));
+            }
+        }
+    }
+    merged.sort_by(|a, b| b.1.score.cmp(&a.1.score).then(a.0.cmp(&b.0)));
+    Ok(merged.into_iter().map(|(_, r)| r).collect())
+}
+
+// ---- 插件 provider 桥 ----
+
+static PENDING: Lazy<Mutex<HashMap<u64, tokio::sync::oneshot::Sender<Vec<SearchResult>>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static REQUEST_SEQ: AtomicU64 = AtomicU64::new(1);
+
+/// 保证 PENDING 条目一定被摘除：provider 超时后 search future 在
+/// await 处被直接 drop，await 之后的清理代码不会执行，只能靠 Drop
+struct PendingGuard(u64);
+
+impl Drop for PendingGuard {
+    fn drop(&mut self) {
+        if let Ok(mut pending) = PENDING.lock() {
+            pending.remove(&self.0);
+        }
+    }
+}
+
+/// 把启用的插件包成 provider：事件请求 + 限时等待回包
+pub struct PluginSearchProvider {
+    app: AppHandle,
+    plugin_id: String,
+}
+
+impl PluginSearchProvider {
+    pub fn new(app: AppHandle, plugin_id: String) -> Self {
+        Self { app, plugin_id }
+    }
+}
+
+#[async_trait]
+impl SearchProvider for PluginSearchProvider {
+    fn name(&self) -> &str {
+        &self.plugin_id
+    }
+
+    fn priority(&self) -> i32 {
+        // 插件结果默认排在内置来源之后
+        200
+    }
+
+    async fn search(&self, query: &str) -> Vec<SearchResult> {
+        let request_id = REQUEST_SEQ.fetch_add(1, Ordering::SeqCst);
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        if let Ok(mut pending) = PENDING.lock() {
+            pending.insert(request_id, tx);
+        }
+        let _guard = PendingGuard(request_id);
+        let _ = self.app.emit(
+            PLUGIN_SEARCH_EVENT,
+            serde_json::json!({
+                "requestId": request_id,
+                "pluginId": self.plugin_id,
+                "query": query,
+            }),
+        );
+        rx.await.unwrap_or_default()
+    }
+}
+
+/// 插件运行时回传搜索结果
+#[tauri::command]
+pub fn plugin_search_respond(request_id: u64, results: Vec<SearchResult>) -> Result<(), String> {
+    let sender = {
+        let mut pending = PENDING.lock().map_err(|e| e.to_string())?;
+        pending.remove(&request_id)
+    };
+    match sender {
+        Some(tx) => {
+            let _ = tx.send(results);
+            Ok(())
+        }
+        None => Err(format!("搜索请求 {} 已超时或不存在", request_id)),
+    }
+}
+
+/// 插件启用时注册为搜索 provider
+pub fn register_plugin_provider(app: AppHandle, plugin_id: &str) {
+    register_provider(Arc::new(PluginSearchProvider::new(
+        app,
+        plugin_id.to_string(),
+    )));
+}
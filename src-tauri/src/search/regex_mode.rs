@@ -0,0 +1,86 @@
+//! 正则搜索模式
+//!
+//! `search_clipboard` / `search_files` 支持 `/pattern/` 语法（或显式 flag）
+//! 切换到正则匹配。带防护：编译大小限制、单条内容长度限制和整体超时，
+//! 避免灾难性回溯模式卡死搜索线程。
+
+use regex::RegexBuilder;
+use std::time::{Duration, Instant};
+
+/// 正则编译后的内部状态上限（字节），防止构造超大 DFA
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+/// 参与匹配的单条内容截断长度
+const MAX_HAYSTACK_BYTES: usize = 256 * 1024;
+/// 一次批量匹配的总时间预算
+const MATCH_TIME_BUDGET: Duration = Duration::from_millis(500);
+
+/// 解析后的搜索查询：正则或普通子串
+pub enum SearchQuery {
+    Regex(regex::Regex),
+    Plain(String),
+}
+
+/// 解析用户输入：`/pattern/` 走正则（可带 i 标志 `/pattern/i`），
+/// `force_regex` 为 true 时整串按正则编译；否则按归一化子串处理
+pub fn parse_query(input: &str, force_regex: bool) -> Result<SearchQuery, String> {
+    let (pattern, case_insensitive) = if force_regex {
+        (input, true)
+    } else if let Some(rest) = input.strip_prefix('/') {
+        if let Some(p) = rest.strip_suffix("/i") {
+            (p, true)
+        } else if let Some(p) = rest.strip_suffix('/') {
+            (p, false)
+        } else {
+            // 未闭合的斜杠按普通文本处理，用户可能只是在搜路径
+            return Ok(SearchQuery::Plain(input.to_string()));
+        }
+    } else {
+        return Ok(SearchQuery::Plain(input.to_string()));
+    };
+
+    if pattern.is_empty() {
+        return Err("正则表达式不能为空".into());
+    }
+    let regex = RegexBuilder::new(pattern)
+        .case_insensitive(case_insensitive)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .dfa_size_limit(REGEX_SIZE_LIMIT)
+        .build()
+        .map_err(|e| format!("正则表达式无效: {}", e))?;
+    Ok(SearchQuery::Regex(regex))
+}
+
+/// 带超时预算的批量过滤：对候选集逐条匹配，超出预算即截止返回已有结果。
+/// 返回 (命中下标, 是否因超时截断)
+pub fn filter_with_budget<'a, I>(query: &SearchQuery, candidates: I) -> (Vec<usize>, bool)
+where
+    I: Iterator<Item = &'a str>,
+{
+    let deadline = Instant::now() + MATCH_TIME_BUDGET;
+    let mut hits = Vec::new();
+    for (idx, candidate) in candidates.enumerate() {
+        // regex crate 本身无回溯，但超大输入 × 海量候选仍需预算控制
+        if idx % 64 == 0 && Instant::now() >= deadline {
+            log::warn!("[RegexSearch] time budget exhausted after {} candidates", idx);
+            return (hits, true);
+        }
+        let haystack = if candidate.len() > MAX_HAYSTACK_BYTES {
+            // 按字符边界截断
+            let mut end = MAX_HAYSTACK_BYTES;
+            while !candidate.is_char_boundary(end) {
+                end -= 1;
+            }
+            &candidate[..end]
+        } else {
+            candidate
+        };
+        let matched = match query {
+            SearchQuery::Regex(re) => re.is_match(haystack),
+            SearchQuery::Plain(text) => super::collation::matches(haystack, text),
+        };
+        if matched {
+            hits.push(idx);
+        }
+    }
+    (hits, false)
+}
@@ -0,0 +1,179 @@
+//! 搜索结果动作框架
+//!
+//! 结果不再只有"打开"：按载荷类型返回可用动作列表（打开、在
+//! 访达/资源管理器中显示、复制路径、打开方式、移到废纸篓、快速
+//! 预览……），前端用它渲染二级菜单，选中后统一走
+//! `execute_result_action` 分发。
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tauri::AppHandle;
+
+/// 一个可执行动作
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultAction {
+    pub id: String,
+    pub label: String,
+    pub icon: String,
+}
+
+fn action(id: &str, label: &str, icon: &str) -> ResultAction {
+    ResultAction {
+        id: id.into(),
+        label: label.into(),
+        icon: format!("builtin:{}", icon),
+    }
+}
+
+/// 按结果载荷返回可用动作；`payload` 即 SearchResult.payload
+#[tauri::command]
+pub fn get_result_actions(payload: serde_json::Value) -> Vec<ResultAction> {
+    let mut actions = Vec::new();
+    if let Some(path) = payload.get("path").and_then(|v| v.as_str()) {
+        let is_file = Path::new(path).is_file();
+        actions.push(action("open", "打开", "open"));
+        actions.push(action("reveal", "在文件管理器中显示", "folder"));
+        actions.push(action("copy-path", "复制路径", "copy"));
+        if is_file {
+            actions.push(action("open-with", "打开方式…", "apps"));
+            #[cfg(target_os = "macos")]
+            actions.push(action("quick-look", "快速预览", "eye"));
+        }
+        actions.push(action("trash", "移到废纸篓", "trash"));
+    } else if payload.get("url").and_then(|v| v.as_str()).is_some() {
+        actions.push(action("open", "打开链接", "open"));
+        actions.push(action("copy", "复制链接", "copy"));
+    } else if payload.get("clipboardId").is_some() {
+        actions.push(action("paste", "粘贴", "paste"));
+        actions.push(action("copy", "复制", "copy"));
+    } else if payload.get("commandId").is_some() {
+        actions.push(action("run", "执行", "command"));
+    } else if let Some(text) = payload.get("text").and_then(|v| v.as_str()) {
+        let _ = text;
+        actions.push(action("copy", "复制", "copy"));
+    }
+    actions
+}
+
+/// 在系统文件管理器中显示
+fn reveal(path: &str) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("open").args(["-R", path]).status();
+    #[cfg(target_os = "windows")]
+    let result = std::process::Command::new("explorer")
+        .arg(format!("/select,{}", path))
+        .status();
+    #[cfg(target_os = "linux")]
+    let result = std::process::Command::new("xdg-open")
+        .arg(
+            Path::new(path)
+                .parent()
+                .unwrap_or_else(|| Path::new("/"))
+                .as_os_str(),
+        )
+        .status();
+    result
+        .map_err(|e| format!("打开文件管理器失败: {}", e))
+        .and_then(|s| {
+            if s.success() {
+                Ok(())
+            } else {
+                Err("文件管理器返回错误".into())
+            }
+        })
+}
+
+/// 执行动作；文件类动作过访问守卫并写审计
+#[tauri::command]
+pub fn execute_result_action(
+    app: AppHandle,
+    action_id: String,
+    payload: serde_json::Value,
+) -> Result<(), String> {
+    let path = payload.get("path").and_then(|v| v.as_str());
+    if let Some(path) = path {
+        crate::cmds::fs_guard::check_access(&app, path, crate::cmds::fs_guard::Access::Read)?;
+    }
+    match action_id.as_str() {
+        "open" => {
+            if let Some(path) = path {
+                open::that(path).map_err(|e| format!("打开失败: {}", e))
+            } else if let Some(url) = payload.get("url").and_then(|v| v.as_str()) {
+                crate::cmds::open_url::open_url(app.clone(), url.to_string())
+            } else {
+                Err("载荷缺少 path/url".into())
+            }
+        }
+        "reveal" => reveal(path.ok_or("载荷缺少 path")?),
+        "copy-path" | "copy" => {
+            let text = path
+                .map(|p| p.to_string())
+                .or_else(|| payload.get("url").and_then(|v| v.as_str()).map(String::from))
+                .or_else(|| payload.get("text").and_then(|v| v.as_str()).map(String::from))
+                .ok_or("没有可复制的内容")?;
+            crate::services::emotes::copy_emote(text, "text".into())
+        }
+        "open-with" => {
+            let path = path.ok_or("载荷缺少 path")?;
+            #[cfg(target_os = "macos")]
+            {
+                // 弹系统"打开方式"选择器
+                std::process::Command::new("open")
+                    .args(["-a", "Finder", path])
+                    .status()
+                    .map_err(|e| e.to_string())
+                    .map(|_| ())
+            }
+            #[cfg(target_os = "windows")]
+            {
+                std::process::Command::new("rundll32")
+                    .args(["shell32.dll,OpenAs_RunDLL", path])
+                    .status()
+                    .map_err(|e| e.to_string())
+                    .map(|_| ())
+            }
+            #[cfg(target_os = "linux")]
+            {
+                open::that(path).map_err(|e| e.to_string())
+            }
+        }
+        "trash" => {
+            let path = path.ok_or("载荷缺少 path")?;
+            crate::cmds::fs_guard::check_access(&app, path, crate::cmds::fs_guard::Access::Write)?;
+            trash::delete(path).map_err(|e| format!("移到废纸篓失败: {}", e))?;
+            crate::services::audit_log::record(&app, "fileTrash", path);
+            Ok(())
+        }
+        "quick-look" => {
+            #[cfg(target_os = "macos")]
+            {
+                std::process::Command::new("qlmanage")
+                    .args(["-p", path.ok_or("载荷缺少 path")?])
+                    .spawn()
+                    .map_err(|e| format!("快速预览失败: {}", e))
+                    .map(|_| ())
+            }
+            #[cfg(not(target_os = "macos"))]
+            {
+                Err("快速预览仅支持 macOS".into())
+            }
+        }
+        "run" => {
+            let command_id = payload
+                .get("commandId")
+                .and_then(|v| v.as_str())
+                .ok_or("载荷缺少 commandId")?
+                .to_string();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) =
+                    crate::app::app_commands::execute_app_command(app, command_id, None).await
+                {
+                    log::warn!("[ResultActions] app command failed: {}", e);
+                }
+            });
+            Ok(())
+        }
+        other => Err(format!("未知动作: {}", other)),
+    }
+}
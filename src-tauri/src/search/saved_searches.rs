@@ -0,0 +1,127 @@
+//! 保存的搜索（智能文件夹）
+//!
+//! 用户可以把"查询 + 过滤条件"存成命名的智能搜索
+//! （如 "~/Docs 里最近的 PDF"），在搜索结果中以条目形式出现，
+//! 展开时实时执行。持久化在 SQLite 里，提供完整 CRUD 命令。
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::pool;
+
+/// 一条保存的搜索
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearch {
+    pub id: i64,
+    pub name: String,
+    /// 原始查询串（可含 /regex/ 语法）
+    pub query: String,
+    /// 过滤条件（JSON：扩展名、目录、时间范围等），结构由前端定义
+    pub filters: serde_json::Value,
+    pub created_at: i64,
+}
+
+fn ensure_table() -> Result<(), String> {
+    let conn = pool::get()?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS saved_searches (
+             id INTEGER PRIMARY KEY AUTOINCREMENT,
+             name TEXT NOT NULL UNIQUE,
+             query TEXT NOT NULL,
+             filters TEXT NOT NULL DEFAULT '{}',
+             created_at INTEGER NOT NULL
+         );",
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// 创建保存的搜索；重名时报错
+#[tauri::command]
+pub fn create_saved_search(
+    name: String,
+    query: String,
+    filters: serde_json::Value,
+) -> Result<SavedSearch, String> {
+    if name.trim().is_empty() {
+        return Err("名称不能为空".into());
+    }
+    ensure_table()?;
+    let conn = pool::get()?;
+    let created_at = chrono::Utc::now().timestamp();
+    let filters_json = serde_json::to_string(&filters).map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT INTO saved_searches (name, query, filters, created_at) VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params![name, query, filters_json, created_at],
+    )
+    .map_err(|e| {
+        if e.to_string().contains("UNIQUE") {
+            format!("已存在名为 '{}' 的保存搜索", name)
+        } else {
+            e.to_string()
+        }
+    })?;
+    Ok(SavedSearch {
+        id: conn.last_insert_rowid(),
+        name,
+        query,
+        filters,
+        created_at,
+    })
+}
+
+/// 列出全部保存的搜索
+#[tauri::command]
+pub fn list_saved_searches() -> Result<Vec<SavedSearch>, String> {
+    ensure_table()?;
+    let conn = pool::get()?;
+    let mut stmt = conn
+        .prepare("SELECT id, name, query, filters, created_at FROM saved_searches ORDER BY name")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| {
+            let filters_json: String = row.get(3)?;
+            Ok(SavedSearch {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                query: row.get(2)?,
+                filters: serde_json::from_str(&filters_json)
+                    .unwrap_or(serde_json::Value::Object(Default::default())),
+                created_at: row.get(4)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+/// 更新保存的搜索
+#[tauri::command]
+pub fn update_saved_search(
+    id: i64,
+    name: String,
+    query: String,
+    filters: serde_json::Value,
+) -> Result<(), String> {
+    ensure_table()?;
+    let conn = pool::get()?;
+    let filters_json = serde_json::to_string(&filters).map_err(|e| e.to_string())?;
+    let updated = conn
+        .execute(
+            "UPDATE saved_searches SET name = ?1, query = ?2, filters = ?3 WHERE id = ?4",
+            rusqlite::params![name, query, filters_json, id],
+        )
+        .map_err(|e| e.to_string())?;
+    if updated == 0 {
+        return Err(format!("保存的搜索 #{} 不存在", id));
+    }
+    Ok(())
+}
+
+/// 删除保存的搜索
+#[tauri::command]
+pub fn delete_saved_search(id: i64) -> Result<(), String> {
+    ensure_table()?;
+    let conn = pool::get()?;
+    conn.execute("DELETE FROM saved_searches WHERE id = ?1", rusqlite::params![id])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
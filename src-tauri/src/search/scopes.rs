@@ -0,0 +1,99 @@
+//! 搜索范围前缀
+//!
+//! "f: report" 只搜文件、"c: token" 只搜剪贴板。前缀到 provider 的
+//! 映射可在设置里改（比如把 "f:" 换成 "file:"）；`get_search_scopes`
+//! 返回当前映射供前端渲染提示。流水线在触发词之后、全量 provider
+//! 之前应用范围过滤。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// 一条范围定义
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchScope {
+    /// 含冒号的前缀，如 "f:"
+    pub prefix: String,
+    /// 对应的 provider 名称
+    pub provider: String,
+    pub label: String,
+}
+
+fn default_scopes() -> Vec<SearchScope> {
+    let scope = |prefix: &str, provider: &str, label: &str| SearchScope {
+        prefix: prefix.into(),
+        provider: provider.into(),
+        label: label.into(),
+    };
+    vec![
+        scope("f:", "files", "仅文件"),
+        scope("a:", "apps", "仅应用"),
+        scope("c:", "clipboard", "仅剪贴板"),
+        scope("cmd:", "app-commands", "仅应用命令"),
+    ]
+}
+
+static SCOPES: Lazy<RwLock<Vec<SearchScope>>> = Lazy::new(|| RwLock::new(default_scopes()));
+
+/// 解析输入开头的范围前缀；返回 (provider 名称, 去掉前缀的查询)
+pub fn parse(input: &str) -> Option<(String, String)> {
+    let scopes = SCOPES.read().ok()?;
+    let trimmed = input.trim_start();
+    for scope in scopes.iter() {
+        if let Some(rest) = trimmed.strip_prefix(&scope.prefix) {
+            return Some((scope.provider.clone(), rest.trim_start().to_string()));
+        }
+    }
+    None
+}
+
+/// 动态登记一条范围（集成类 provider 在注册时调用）；前缀冲突时忽略
+pub fn register_scope(prefix: &str, provider: &str, label: &str) {
+    let Ok(mut scopes) = SCOPES.write() else { return };
+    if scopes.iter().any(|s| s.prefix == prefix) {
+        return;
+    }
+    scopes.push(SearchScope {
+        prefix: prefix.into(),
+        provider: provider.into(),
+        label: label.into(),
+    });
+}
+
+/// 当前全部范围（前端提示用）
+#[tauri::command]
+pub fn get_search_scopes() -> Vec<SearchScope> {
+    SCOPES.read().map(|s| s.clone()).unwrap_or_default()
+}
+
+/// 修改某 provider 的范围前缀；prefix 须以冒号结尾且不与其它冲突
+#[tauri::command]
+pub fn set_search_scope_prefix(provider: String, prefix: String) -> Result<(), String> {
+    let trimmed = prefix.trim();
+    if !trimmed.ends_with(':') || trimmed.len() < 2 {
+        return Err("前缀必须以冒号结尾且不为空".into());
+    }
+    let mut scopes = SCOPES.write().map_err(|e| e.to_string())?;
+    if scopes
+        .iter()
+        .any(|s| s.prefix == trimmed && s.provider != provider)
+    {
+        return Err(format!("前缀 '{}' 已被其它范围占用", trimmed));
+    }
+    let scope = scopes
+        .iter_mut()
+        .find(|s| s.provider == provider)
+        .ok_or_else(|| format!("范围 '{}' 不存在", provider))?;
+    scope.prefix = trimmed.to_string();
+    Ok(())
+}
+
+/// 恢复默认前缀
+#[tauri::command]
+pub fn reset_search_scopes() {
+    if let Ok(mut scopes) = SCOPES.write() {
+        *scopes = default_scopes();
+    }
+}
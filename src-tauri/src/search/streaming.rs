@@ -0,0 +1,152 @@
+//! 流式搜索结果
+//!
+//! `unified_search` 要等最慢的 provider 才返回，首个按键显得迟钝。
+//! 流式模式为每次查询分配递增的 generation：各 provider 一完成就发
+//! `search:partial`，全部结束发 `search:done`；新查询到来后旧
+//! generation 的批次不再发出，前端只渲染与当前输入匹配的 generation。
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Emitter};
+
+/// 单个 provider 的部分结果
+pub const SEARCH_PARTIAL_EVENT: &str = "search:partial";
+/// 本次查询全部 provider 结束
+pub const SEARCH_DONE_EVENT: &str = "search:done";
+
+static QUERY_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PartialBatch {
+    generation: u64,
+    provider: String,
+    results: Vec<super::pipeline::SearchResult>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DonePayload {
+    generation: u64,
+    provider_count: usize,
+}
+
+fn is_current(generation: u64) -> bool {
+    QUERY_GENERATION.load(Ordering::SeqCst) == generation
+}
+
+/// 启动流式搜索；立即返回 generation，结果通过事件推送
+#[tauri::command]
+pub fn unified_search_stream(app: AppHandle, query: String) -> Result<u64, String> {
+    let generation = QUERY_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let trimmed = query.trim().to_string();
+    if trimmed.is_empty() {
+        let _ = app.emit(
+            SEARCH_DONE_EVENT,
+            DonePayload {
+                generation,
+                provider_count: 0,
+            },
+        );
+        return Ok(generation);
+    }
+
+    tauri::async_runtime::spawn(async move {
+        // 防抖：窗口内被新查询取代的 generation 不再发任何事件
+        let debounce = super::pipeline::debounce_ms();
+        if debounce > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(debounce)).await;
+            if !is_current(generation) {
+                return;
+            }
+        }
+        // 触发词命中：单批次直接结束
+        if let Some(resolved) = crate::search::trigger_registry::resolve(&trimmed) {
+            if is_current(generation) {
+                let _ = app.emit(
+                    SEARCH_PARTIAL_EVENT,
+                    PartialBatch {
+                        generation,
+                        provider: "trigger".into(),
+                        results: vec![super::pipeline::SearchResult {
+                            id: format!("trigger:{}", resolved.trigger.keyword),
+                            title: resolved.trigger.keyword.clone(),
+                            subtitle: Some(resolved.rest.clone()),
+                            icon: None,
+                            provider: "trigger".into(),
+                            score: i32::MAX,
+                            payload: serde_json::to_value(&resolved).unwrap_or_default(),
+                        }],
+                    },
+                );
+                let _ = app.emit(
+                    SEARCH_DONE_EVENT,
+                    DonePayload {
+                        generation,
+                        provider_count: 1,
+                    },
+                );
+            }
+            return;
+        }
+
+        // 流式路径不解析范围前缀，scope_only provider 一律不参与
+        let providers: Vec<_> = super::pipeline::providers_snapshot()
+            .into_iter()
+            .filter(|p| !p.scope_only())
+            .collect();
+        let provider_count = providers.len();
+        let cap = super::pipeline::per_provider_cap();
+        let mut handles = Vec::with_capacity(provider_count);
+        for provider in providers {
+            let app = app.clone();
+            let query = trimmed.clone();
+            handles.push(tauri::async_runtime::spawn(async move {
+                // 与 run_providers 同样的预算；不限时的话挂掉的 provider
+                // 会永远卡住本 generation 的 search:done
+                let mut results = match tokio::time::timeout(
+                    super::pipeline::PROVIDER_TIMEOUT,
+                    provider.search(&query),
+                )
+                .await
+                {
+                    Ok(results) => results,
+                    Err(_) => {
+                        log::warn!("[Search] provider '{}' timed out", provider.name());
+                        return;
+                    }
+                };
+                // 旧查询的迟到批次直接丢弃
+                if !is_current(generation) {
+                    return;
+                }
+                results.sort_by(|a, b| b.score.cmp(&a.score));
+                results.truncate(cap);
+                for result in results.iter_mut() {
+                    result.provider = provider.name().to_string();
+                }
+                let _ = app.emit(
+                    SEARCH_PARTIAL_EVENT,
+                    PartialBatch {
+                        generation,
+                        provider: provider.name().to_string(),
+                        results,
+                    },
+                );
+            }));
+        }
+        for handle in handles {
+            let _ = handle.await;
+        }
+        if is_current(generation) {
+            let _ = app.emit(
+                SEARCH_DONE_EVENT,
+                DonePayload {
+                    generation,
+                    provider_count,
+                },
+            );
+        }
+    });
+    Ok(generation)
+}
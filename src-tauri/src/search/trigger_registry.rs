@@ -0,0 +1,174 @@
+//! 中央触发词注册表
+//!
+//! 插件触发词、插件缩写、全局缩写、快捷链接关键词过去各自维护，
+//! 冲突时行为取决于加载顺序。现在启动时统一汇入本注册表：
+//! 冲突被显式检测并暴露给设置页，用户可以指定覆盖项；
+//! 搜索分发器只需调用 `resolve_trigger(input)`。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// 触发词来源；数值越小默认优先级越高
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TriggerSource {
+    GlobalAbbreviation,
+    PluginTrigger,
+    PluginAbbreviation,
+    QuicklinkKeyword,
+}
+
+impl TriggerSource {
+    fn default_priority(&self) -> u8 {
+        match self {
+            TriggerSource::GlobalAbbreviation => 0,
+            TriggerSource::PluginTrigger => 1,
+            TriggerSource::PluginAbbreviation => 2,
+            TriggerSource::QuicklinkKeyword => 3,
+        }
+    }
+}
+
+/// 一条注册的触发词
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trigger {
+    pub keyword: String,
+    pub source: TriggerSource,
+    /// 归属方：插件 ID、快捷链接 ID 或 "global"
+    pub owner_id: String,
+}
+
+/// 解析结果：命中的触发词 + 去掉关键词后的剩余输入
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedTrigger {
+    pub trigger: Trigger,
+    pub rest: String,
+}
+
+/// 冲突报告条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerConflict {
+    pub keyword: String,
+    pub candidates: Vec<Trigger>,
+    /// 用户指定的覆盖归属方（如有）
+    pub override_owner: Option<String>,
+}
+
+/// keyword（小写）-> 所有注册方
+static REGISTRY: Lazy<RwLock<HashMap<String, Vec<Trigger>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+/// 用户冲突覆盖：keyword -> owner_id
+static OVERRIDES: Lazy<RwLock<HashMap<String, String>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// 注册一条触发词；返回是否引入了冲突
+pub fn register(keyword: &str, source: TriggerSource, owner_id: &str) -> bool {
+    let key = keyword.trim().to_lowercase();
+    if key.is_empty() {
+        return false;
+    }
+    let Ok(mut registry) = REGISTRY.write() else { return false };
+    let entries = registry.entry(key.clone()).or_default();
+    if entries.iter().any(|t| t.owner_id == owner_id && t.source == source) {
+        return entries.len() > 1;
+    }
+    entries.push(Trigger {
+        keyword: key.clone(),
+        source,
+        owner_id: owner_id.to_string(),
+    });
+    let conflict = entries.len() > 1;
+    if conflict {
+        log::warn!(
+            "[TriggerRegistry] keyword '{}' now has {} registrants",
+            key,
+            entries.len()
+        );
+    }
+    conflict
+}
+
+/// 摘除某归属方的全部触发词（插件卸载/快捷链接删除时调用）
+pub fn unregister_owner(owner_id: &str) {
+    if let Ok(mut registry) = REGISTRY.write() {
+        for entries in registry.values_mut() {
+            entries.retain(|t| t.owner_id != owner_id);
+        }
+        registry.retain(|_, entries| !entries.is_empty());
+    }
+}
+
+/// 解析输入的第一个词；搜索分发器据此决定路由
+pub fn resolve(input: &str) -> Option<ResolvedTrigger> {
+    let trimmed = input.trim_start();
+    let (word, rest) = match trimmed.split_once(char::is_whitespace) {
+        Some((w, r)) => (w, r.trim_start()),
+        None => (trimmed, ""),
+    };
+    let key = word.to_lowercase();
+    let registry = REGISTRY.read().ok()?;
+    let entries = registry.get(&key)?;
+
+    let chosen = if entries.len() == 1 {
+        entries[0].clone()
+    } else {
+        // 冲突：优先用户覆盖，否则按来源默认优先级
+        let override_owner = OVERRIDES.read().ok()?.get(&key).cloned();
+        match override_owner.and_then(|o| entries.iter().find(|t| t.owner_id == o)) {
+            Some(t) => t.clone(),
+            None => entries
+                .iter()
+                .min_by_key(|t| t.source.default_priority())?
+                .clone(),
+        }
+    };
+    Some(ResolvedTrigger {
+        trigger: chosen,
+        rest: rest.to_string(),
+    })
+}
+
+/// 解析触发词（前端搜索分发用）
+#[tauri::command]
+pub fn resolve_trigger(input: String) -> Option<ResolvedTrigger> {
+    resolve(&input)
+}
+
+/// 列出所有存在冲突的关键词
+#[tauri::command]
+pub fn list_trigger_conflicts() -> Result<Vec<TriggerConflict>, String> {
+    let registry = REGISTRY.read().map_err(|e| e.to_string())?;
+    let overrides = OVERRIDES.read().map_err(|e| e.to_string())?;
+    let mut conflicts: Vec<TriggerConflict> = registry
+        .iter()
+        .filter(|(_, entries)| entries.len() > 1)
+        .map(|(keyword, entries)| TriggerConflict {
+            keyword: keyword.clone(),
+            candidates: entries.clone(),
+            override_owner: overrides.get(keyword).cloned(),
+        })
+        .collect();
+    conflicts.sort_by(|a, b| a.keyword.cmp(&b.keyword));
+    Ok(conflicts)
+}
+
+/// 为冲突关键词指定获胜方；owner 为 None 时清除覆盖
+#[tauri::command]
+pub fn set_trigger_override(keyword: String, owner: Option<String>) -> Result<(), String> {
+    let key = keyword.trim().to_lowercase();
+    let mut overrides = OVERRIDES.write().map_err(|e| e.to_string())?;
+    match owner {
+        Some(owner) => {
+            overrides.insert(key, owner);
+        }
+        None => {
+            overrides.remove(&key);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,118 @@
+//! 搜索纠错（“你是不是要找”）
+//!
+//! 查询零结果时的兜底层：先从历史搜索里找编辑距离 1 以内的相近查询，
+//! 再尝试拼写词典纠正，用纠正后的词重跑一轮 provider。只在候选真能
+//! 搜出结果时才向前端返回建议，避免无意义的打扰。中文查询的错拼
+//! 形态完全不同（多为拼音口误），这里只处理字符级的编辑距离。
+
+use std::collections::HashSet;
+
+/// 参与纠错的最短查询长度；太短的查询编辑距离 1 噪声过大
+const MIN_QUERY_LEN: usize = 3;
+/// 最多尝试的候选数
+const MAX_CANDIDATES: usize = 3;
+
+/// Damerau-Levenshtein 距离是否 ≤ 1（含相邻交换）
+fn within_distance_one(a: &str, b: &str) -> bool {
+    if a == b {
+        return false;
+    }
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (longer, shorter) = if a.len() >= b.len() { (&a, &b) } else { (&b, &a) };
+    match longer.len() - shorter.len() {
+        0 => {
+            // 等长：一处替换，或一处相邻交换
+            let diffs: Vec<usize> = (0..a.len()).filter(|&i| a[i] != b[i]).collect();
+            match diffs.as_slice() {
+                [_] => true,
+                [i, j] => *j == *i + 1 && a[*i] == b[*j] && a[*j] == b[*i],
+                _ => false,
+            }
+        }
+        1 => {
+            // 差一个字符：一处插入/删除
+            let mut li = 0;
+            let mut si = 0;
+            let mut skipped = false;
+            while li < longer.len() && si < shorter.len() {
+                if longer[li] == shorter[si] {
+                    li += 1;
+                    si += 1;
+                } else if skipped {
+                    return false;
+                } else {
+                    skipped = true;
+                    li += 1;
+                }
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+/// 历史搜索里与 query 编辑距离 1 以内的相近查询（按最近使用排序）
+fn history_candidates(query: &str) -> Vec<String> {
+    let Ok(conn) = crate::db::pool::get() else {
+        return Vec::new();
+    };
+    let Ok(mut stmt) = conn.prepare(
+        "SELECT DISTINCT query FROM search_history ORDER BY searched_at DESC LIMIT 500",
+    ) else {
+        return Vec::new();
+    };
+    let lower = query.to_lowercase();
+    let rows = stmt
+        .query_map([], |row| row.get::<_, String>(0))
+        .map(|rows| rows.flatten().collect::<Vec<_>>())
+        .unwrap_or_default();
+    rows.into_iter()
+        .filter(|past| within_distance_one(&past.to_lowercase(), &lower))
+        .collect()
+}
+
+/// 生成纠错候选：历史相近查询优先，其次词典逐词纠正
+fn candidates(query: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    for candidate in history_candidates(query) {
+        if seen.insert(candidate.to_lowercase()) {
+            out.push(candidate);
+        }
+    }
+    if let Ok(fixed) = crate::services::spellcheck::fix_spelling(query.to_string()) {
+        if fixed.dictionary_loaded
+            && !fixed.corrections.is_empty()
+            && seen.insert(fixed.text.to_lowercase())
+        {
+            out.push(fixed.text);
+        }
+    }
+    out.truncate(MAX_CANDIDATES);
+    out
+}
+
+/// 用纠错候选重跑 provider；返回第一个有结果的 (候选词, 结果)
+pub(crate) async fn retry_with_corrections(
+    scope_provider: Option<&str>,
+    query: &str,
+) -> Option<(String, Vec<super::pipeline::SearchResult>)> {
+    if query.chars().count() < MIN_QUERY_LEN {
+        return None;
+    }
+    let query = query.to_string();
+    let candidates = tauri::async_runtime::spawn_blocking(move || candidates(&query))
+        .await
+        .unwrap_or_default();
+    for candidate in candidates {
+        match super::pipeline::run_providers(scope_provider, &candidate).await {
+            Ok(results) if !results.is_empty() => {
+                log::info!("[Search] typo fallback matched '{}'", candidate);
+                return Some((candidate, results));
+            }
+            _ => {}
+        }
+    }
+    None
+}
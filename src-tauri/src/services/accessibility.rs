@@ -0,0 +1,164 @@
+//! 无障碍配置
+//!
+//! 三个开关：减少动画、冗长结果标签（给读屏器更完整的上下文）、
+//! 高对比度主题提示。每项都是三态：跟随系统（启动时原生探测）、
+//! 强制开、强制关。搜索流水线在冗长标签开启时给结果附加来源说明，
+//! 前端与 HUD 根据 `get_accessibility_config` 调整动画与主题。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+/// 三态开关
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessibilityToggle {
+    System,
+    On,
+    Off,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityPrefs {
+    pub reduce_motion: AccessibilityToggle,
+    pub verbose_labels: AccessibilityToggle,
+    pub high_contrast: AccessibilityToggle,
+}
+
+impl Default for AccessibilityPrefs {
+    fn default() -> Self {
+        Self {
+            reduce_motion: AccessibilityToggle::System,
+            verbose_labels: AccessibilityToggle::System,
+            high_contrast: AccessibilityToggle::System,
+        }
+    }
+}
+
+/// 解析后的生效配置（已合并系统探测结果）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityConfig {
+    pub reduce_motion: bool,
+    pub verbose_labels: bool,
+    pub high_contrast: bool,
+}
+
+static PREFS: Lazy<RwLock<AccessibilityPrefs>> =
+    Lazy::new(|| RwLock::new(AccessibilityPrefs::default()));
+
+/// 探测系统"减少动画"偏好
+fn system_reduce_motion() -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("defaults")
+            .args(["read", "com.apple.universalaccess", "reduceMotion"])
+            .output()
+            .ok()
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim() == "1")
+            .unwrap_or(false)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        // MenuShowDelay 为 0 常与"关闭动画"一起被辅助工具设置；
+        // 准确探测需要 SystemParametersInfo，这里退而读注册表标记
+        use winreg::enums::HKEY_CURRENT_USER;
+        use winreg::RegKey;
+        RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey("Control Panel\\Desktop\\WindowMetrics")
+            .and_then(|k| k.get_value::<String, _>("MinAnimate"))
+            .map(|v| v == "0")
+            .unwrap_or(false)
+    }
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("gsettings")
+            .args(["get", "org.gnome.desktop.interface", "enable-animations"])
+            .output()
+            .ok()
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim() == "false")
+            .unwrap_or(false)
+    }
+}
+
+/// 探测系统高对比度偏好
+fn system_high_contrast() -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("defaults")
+            .args(["read", "com.apple.universalaccess", "increaseContrast"])
+            .output()
+            .ok()
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim() == "1")
+            .unwrap_or(false)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        use winreg::enums::HKEY_CURRENT_USER;
+        use winreg::RegKey;
+        RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey("Control Panel\\Accessibility\\HighContrast")
+            .and_then(|k| k.get_value::<u32, _>("Flags"))
+            .map(|flags| flags & 1 != 0)
+            .unwrap_or(false)
+    }
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("gsettings")
+            .args(["get", "org.gnome.desktop.a11y.interface", "high-contrast"])
+            .output()
+            .ok()
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim() == "true")
+            .unwrap_or(false)
+    }
+}
+
+/// 系统偏好探测结果；进程内只探测一次。`current()` 跑在每次搜索的
+/// 热路径上，不能每次都起 defaults/gsettings 子进程，系统偏好变化
+/// 在重启后生效（与模块头的"启动时原生探测"一致）
+struct SystemProbe {
+    reduce_motion: bool,
+    high_contrast: bool,
+}
+
+static SYSTEM: Lazy<SystemProbe> = Lazy::new(|| SystemProbe {
+    reduce_motion: system_reduce_motion(),
+    high_contrast: system_high_contrast(),
+});
+
+fn resolve(toggle: AccessibilityToggle, system: bool) -> bool {
+    match toggle {
+        AccessibilityToggle::System => system,
+        AccessibilityToggle::On => true,
+        AccessibilityToggle::Off => false,
+    }
+}
+
+/// 当前生效的无障碍配置
+pub fn current() -> AccessibilityConfig {
+    let prefs = PREFS
+        .read()
+        .map(|p| p.clone())
+        .unwrap_or_default();
+    AccessibilityConfig {
+        reduce_motion: resolve(prefs.reduce_motion, SYSTEM.reduce_motion),
+        // 读屏器运行状态难以跨平台探测，verbose 的系统默认取 false
+        verbose_labels: resolve(prefs.verbose_labels, false),
+        high_contrast: resolve(prefs.high_contrast, SYSTEM.high_contrast),
+    }
+}
+
+/// 读取生效配置（前端与 HUD 初始化时调用）
+#[tauri::command]
+pub fn get_accessibility_config() -> AccessibilityConfig {
+    current()
+}
+
+/// 更新无障碍偏好
+#[tauri::command]
+pub fn set_accessibility_prefs(prefs: AccessibilityPrefs) -> Result<(), String> {
+    let mut current = PREFS.write().map_err(|e| e.to_string())?;
+    *current = prefs;
+    Ok(())
+}
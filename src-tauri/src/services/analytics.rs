@@ -0,0 +1,171 @@
+//! 本地使用分析
+//!
+//! 把 `app_usage` / `search_history` 聚合成个人生产力报告：最常用
+//! 条目、按小时分布的使用热力图、高频搜索词。全部在本地 SQLite 上
+//! 计算，不上传任何数据；隐私会话期间的记录本来就不会入库。
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+/// 报告时间范围
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReportRange {
+    Week,
+    Month,
+    Quarter,
+}
+
+impl ReportRange {
+    fn days(&self) -> i64 {
+        match self {
+            ReportRange::Week => 7,
+            ReportRange::Month => 30,
+            ReportRange::Quarter => 90,
+        }
+    }
+}
+
+/// 最常用条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopItem {
+    pub item_id: String,
+    pub item_type: String,
+    pub count: u64,
+}
+
+/// 高频搜索词
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopQuery {
+    pub query: String,
+    pub count: u64,
+}
+
+/// 使用报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageReport {
+    pub range: ReportRange,
+    pub total_launches: u64,
+    pub total_searches: u64,
+    pub top_items: Vec<TopItem>,
+    pub top_queries: Vec<TopQuery>,
+    /// 24 项，按本地小时统计的启动次数热力图
+    pub hourly_heatmap: Vec<u64>,
+    /// 7 项，周一到周日
+    pub weekday_heatmap: Vec<u64>,
+}
+
+/// 生成使用报告；全程本地计算
+#[tauri::command]
+pub fn get_usage_report(range: ReportRange) -> Result<UsageReport, String> {
+    let conn = crate::db::pool::get()?;
+    let since = (chrono::Utc::now() - chrono::Duration::days(range.days())).timestamp();
+
+    let total_launches: u64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM app_usage WHERE used_at >= ?1",
+            params![since],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|e| e.to_string())? as u64;
+    let total_searches: u64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM search_history WHERE searched_at >= ?1",
+            params![since],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|e| e.to_string())? as u64;
+
+    let mut top_items = Vec::new();
+    {
+        let mut stmt = conn
+            .prepare(
+                "SELECT item_id, item_type, COUNT(*) AS c FROM app_usage
+                 WHERE used_at >= ?1 GROUP BY item_id, item_type
+                 ORDER BY c DESC LIMIT 10",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map(params![since], |row| {
+                Ok(TopItem {
+                    item_id: row.get(0)?,
+                    item_type: row.get(1)?,
+                    count: row.get::<_, i64>(2)? as u64,
+                })
+            })
+            .map_err(|e| e.to_string())?;
+        for row in rows {
+            top_items.push(row.map_err(|e| e.to_string())?);
+        }
+    }
+
+    let mut top_queries = Vec::new();
+    {
+        let mut stmt = conn
+            .prepare(
+                "SELECT query, COUNT(*) AS c FROM search_history
+                 WHERE searched_at >= ?1 GROUP BY query
+                 ORDER BY c DESC LIMIT 10",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map(params![since], |row| {
+                Ok(TopQuery {
+                    query: row.get(0)?,
+                    count: row.get::<_, i64>(1)? as u64,
+                })
+            })
+            .map_err(|e| e.to_string())?;
+        for row in rows {
+            top_queries.push(row.map_err(|e| e.to_string())?);
+        }
+    }
+
+    // 热力图按本地时区换算：SQLite 的 localtime 修饰符
+    let mut hourly_heatmap = vec![0u64; 24];
+    let mut weekday_heatmap = vec![0u64; 7];
+    {
+        let mut stmt = conn
+            .prepare(
+                "SELECT CAST(strftime('%H', used_at, 'unixepoch', 'localtime') AS INTEGER),
+                        CAST(strftime('%w', used_at, 'unixepoch', 'localtime') AS INTEGER),
+                        COUNT(*)
+                 FROM app_usage WHERE used_at >= ?1
+                 GROUP BY 1, 2",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map(params![since], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, i64>(2)? as u64,
+                ))
+            })
+            .map_err(|e| e.to_string())?;
+        for row in rows {
+            let (hour, weekday, count) = row.map_err(|e| e.to_string())?;
+            if let Some(slot) = hourly_heatmap.get_mut(hour as usize) {
+                *slot += count;
+            }
+            // strftime %w: 0 = 周日；报告按周一开头
+            let idx = ((weekday + 6) % 7) as usize;
+            if let Some(slot) = weekday_heatmap.get_mut(idx) {
+                *slot += count;
+            }
+        }
+    }
+
+    Ok(UsageReport {
+        range,
+        total_launches,
+        total_searches,
+        top_items,
+        top_queries,
+        hourly_heatmap,
+        weekday_heatmap,
+    })
+}
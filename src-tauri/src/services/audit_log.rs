@@ -0,0 +1,164 @@
+//! 审计日志
+//!
+//! 独立于调试日志的只追加审计轨迹，记录 shell 执行、`write_file` 写入、
+//! 权限授予和插件安装。每条记录带前一条的 SHA-256 链式哈希，
+//! 事后篡改任意一条都会破坏后续全部哈希。
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use once_cell::sync::Lazy;
+
+const AUDIT_FILE: &str = "audit.log";
+/// 链首哈希（创世记录的 prev_hash）
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 一条审计记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub timestamp: i64,
+    /// "shellExec" / "fileWrite" / "permissionGrant" / "pluginInstall"
+    pub action: String,
+    /// 动作详情（命令行、路径、插件 ID 等）
+    pub detail: String,
+    /// 前一条记录的哈希
+    pub prev_hash: String,
+    /// 本条记录的哈希：SHA-256(timestamp|action|detail|prev_hash)
+    pub hash: String,
+}
+
+/// 写入串行化 + 记住链尾哈希
+static WRITE_STATE: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+
+fn audit_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(AUDIT_FILE))
+}
+
+fn compute_hash(timestamp: i64, action: &str, detail: &str, prev_hash: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{}|{}|{}|{}", timestamp, action, detail, prev_hash));
+    format!("{:x}", hasher.finalize())
+}
+
+/// 读取文件中最后一条记录的哈希
+fn tail_hash(path: &PathBuf) -> String {
+    let Ok(file) = fs::File::open(path) else {
+        return GENESIS_HASH.to_string();
+    };
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str::<AuditEntry>(&line).ok())
+        .last()
+        .map(|e| e.hash)
+        .unwrap_or_else(|| GENESIS_HASH.to_string())
+}
+
+/// 记录一条审计事件；各特权操作路径调用
+pub fn record(app: &AppHandle, action: &str, detail: &str) {
+    let write = || -> Result<(), String> {
+        let path = audit_path(app)?;
+        let mut state = WRITE_STATE.lock().map_err(|e| e.to_string())?;
+        let prev_hash = state.clone().unwrap_or_else(|| tail_hash(&path));
+
+        let timestamp = chrono::Utc::now().timestamp_millis();
+        let hash = compute_hash(timestamp, action, detail, &prev_hash);
+        let entry = AuditEntry {
+            timestamp,
+            action: action.to_string(),
+            detail: detail.to_string(),
+            prev_hash,
+            hash: hash.clone(),
+        };
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| e.to_string())?;
+        writeln!(
+            file,
+            "{}",
+            serde_json::to_string(&entry).map_err(|e| e.to_string())?
+        )
+        .map_err(|e| e.to_string())?;
+        *state = Some(hash);
+        Ok(())
+    };
+    if let Err(e) = write() {
+        // 审计写入失败绝不能静默
+        log::error!("[Audit] FAILED to record audit entry ({}): {}", action, e);
+    }
+}
+
+/// 审计文件被合法删除后（数据清除）重置链尾缓存，让新文件的第一条
+/// 重新从创世哈希续链；否则 `verify_audit_log` 会把它判为篡改
+pub fn reset_chain() {
+    if let Ok(mut state) = WRITE_STATE.lock() {
+        *state = None;
+    }
+}
+
+/// 查询审计日志；可按动作类型过滤，按时间倒序返回最近 `limit` 条
+#[tauri::command]
+pub fn get_audit_log(
+    app: AppHandle,
+    action: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<AuditEntry>, String> {
+    let path = audit_path(&app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let file = fs::File::open(&path).map_err(|e| e.to_string())?;
+    let mut entries: Vec<AuditEntry> = BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .filter(|e: &AuditEntry| action.as_ref().map(|a| &e.action == a).unwrap_or(true))
+        .collect();
+    entries.reverse();
+    entries.truncate(limit.unwrap_or(200));
+    Ok(entries)
+}
+
+/// 校验审计链完整性；返回第一条被篡改记录的下标，None 表示完好
+#[tauri::command]
+pub fn verify_audit_log(app: AppHandle) -> Result<Option<usize>, String> {
+    let path = audit_path(&app)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let file = fs::File::open(&path).map_err(|e| e.to_string())?;
+    let mut prev = GENESIS_HASH.to_string();
+    for (idx, line) in BufReader::new(file).lines().map_while(Result::ok).enumerate() {
+        let Ok(entry) = serde_json::from_str::<AuditEntry>(&line) else {
+            return Ok(Some(idx));
+        };
+        let expected = compute_hash(entry.timestamp, &entry.action, &entry.detail, &prev);
+        if entry.prev_hash != prev || entry.hash != expected {
+            return Ok(Some(idx));
+        }
+        prev = entry.hash;
+    }
+    Ok(None)
+}
+
+/// 导出审计日志到指定路径
+#[tauri::command]
+pub fn export_audit_log(app: AppHandle, dest: String) -> Result<(), String> {
+    let path = audit_path(&app)?;
+    if !path.exists() {
+        return Err("暂无审计日志".into());
+    }
+    fs::copy(&path, &dest).map_err(|e| format!("导出失败: {}", e))?;
+    Ok(())
+}
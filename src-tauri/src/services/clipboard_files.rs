@@ -0,0 +1,221 @@
+//! 剪贴板文件列表捕获
+//!
+//! 复制文件/文件夹时（macOS 的 file URL、Windows 的 CF_HDROP、Linux 的
+//! text/uri-list）把路径集合作为独立的历史条目记录：`content_type =
+//! "files"`、`content` 存 JSON 路径数组。之后可以把整组文件引用还原回
+//! 剪贴板再粘贴。读写系统剪贴板按平台走 osascript / PowerShell /
+//! wl-clipboard。只记录路径，不复制文件内容。
+
+use rusqlite::params;
+use sha2::{Digest, Sha256};
+use std::process::Command;
+use std::sync::Mutex;
+
+/// 单条目路径数上限，防御超大批量复制
+const MAX_FILES_PER_ITEM: usize = 200;
+
+/// 上次记录的文件集合指纹；watcher 轮询去重用
+static LAST_CAPTURE: Mutex<Option<String>> = Mutex::new(None);
+
+fn run_capture(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("启动 {} 失败: {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} 失败: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// 读取系统剪贴板里的文件路径列表；没有文件时返回空
+fn read_file_list() -> Result<Vec<String>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        // AppleScript 把文件剪贴板转成每行一个 POSIX 路径
+        let script = "try\n\
+                      set fileList to the clipboard as list\n\
+                      set out to \"\"\n\
+                      repeat with f in fileList\n\
+                      set out to out & POSIX path of f & linefeed\n\
+                      end repeat\n\
+                      return out\n\
+                      on error\n\
+                      return \"\"\n\
+                      end try";
+        let stdout = run_capture("osascript", &["-e", script])?;
+        return Ok(stdout
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.trim().to_string())
+            .collect());
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let stdout = run_capture(
+            "powershell",
+            &[
+                "-NoProfile",
+                "-Command",
+                "(Get-Clipboard -Format FileDropList) | ForEach-Object { $_.FullName }",
+            ],
+        )?;
+        return Ok(stdout
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.trim().to_string())
+            .collect());
+    }
+    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+    {
+        // Wayland 优先，回落 xclip；内容是 text/uri-list 的 file:// URI
+        let stdout = run_capture("wl-paste", &["-t", "text/uri-list"])
+            .or_else(|_| run_capture("xclip", &["-selection", "clipboard", "-o", "-t", "text/uri-list"]))?;
+        let mut paths = Vec::new();
+        for line in stdout.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(path) = url::Url::parse(line)
+                .ok()
+                .filter(|u| u.scheme() == "file")
+                .and_then(|u| u.to_file_path().ok())
+            {
+                paths.push(path.display().to_string());
+            }
+        }
+        Ok(paths)
+    }
+}
+
+/// 记录一组文件路径为历史条目；与上次相同的集合不重复记录
+#[tauri::command]
+pub fn record_clipboard_files(paths: Vec<String>) -> Result<(), String> {
+    if crate::services::privacy_session::is_recording_paused() || paths.is_empty() {
+        return Ok(());
+    }
+    let mut paths = paths;
+    paths.truncate(MAX_FILES_PER_ITEM);
+    let content = serde_json::to_string(&paths).map_err(|e| e.to_string())?;
+    let fingerprint = format!("{:x}", Sha256::digest(content.as_bytes()));
+    {
+        let mut last = LAST_CAPTURE.lock().map_err(|e| e.to_string())?;
+        if last.as_deref() == Some(&fingerprint) {
+            return Ok(());
+        }
+        *last = Some(fingerprint);
+    }
+    let conn = crate::db::pool::get()?;
+    conn.execute(
+        "INSERT INTO clipboard_history (content, content_type, created_at) VALUES (?1, 'files', ?2)",
+        params![content, chrono::Utc::now().timestamp()],
+    )
+    .map_err(|e| e.to_string())?;
+    log::info!("[ClipboardFiles] captured {} file references", paths.len());
+    Ok(())
+}
+
+/// watcher 轮询入口：剪贴板里有文件列表就记入历史
+#[tauri::command]
+pub async fn poll_clipboard_files() -> Result<usize, String> {
+    let paths = tauri::async_runtime::spawn_blocking(read_file_list)
+        .await
+        .map_err(|e| format!("剪贴板读取任务异常: {}", e))??;
+    let count = paths.len();
+    if count > 0 {
+        record_clipboard_files(paths)?;
+    }
+    Ok(count)
+}
+
+/// 把文件引用集合还原到系统剪贴板
+fn set_file_list(paths: &[String]) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let items: Vec<String> = paths
+            .iter()
+            .map(|p| format!("POSIX file \"{}\"", p.replace('"', "\\\"")))
+            .collect();
+        let script = format!("set the clipboard to {{{}}}", items.join(", "));
+        return run_capture("osascript", &["-e", &script]).map(|_| ());
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let list: Vec<String> = paths
+            .iter()
+            .map(|p| format!("'{}'", p.replace('\'', "''")))
+            .collect();
+        let script = format!(
+            "Add-Type -AssemblyName System.Windows.Forms; \
+             $files = New-Object System.Collections.Specialized.StringCollection; \
+             @({}) | ForEach-Object {{ [void]$files.Add($_) }}; \
+             [System.Windows.Forms.Clipboard]::SetFileDropList($files)",
+            list.join(", ")
+        );
+        return run_capture("powershell", &["-NoProfile", "-STA", "-Command", &script]).map(|_| ());
+    }
+    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+    {
+        use std::io::Write;
+        let uri_list: String = paths
+            .iter()
+            .filter_map(|p| url::Url::from_file_path(p).ok())
+            .map(|u| u.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut child = Command::new("wl-copy")
+            .args(["-t", "text/uri-list"])
+            .stdin(std::process::Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("启动 wl-copy 失败: {}", e))?;
+        if let Some(stdin) = child.stdin.as_mut() {
+            stdin
+                .write_all(uri_list.as_bytes())
+                .map_err(|e| e.to_string())?;
+        }
+        if child.wait().map(|s| s.success()).unwrap_or(false) {
+            Ok(())
+        } else {
+            Err("wl-copy 写入失败".into())
+        }
+    }
+}
+
+/// 把历史里的文件条目还原回剪贴板；已消失的文件会剔除并警告
+#[tauri::command]
+pub async fn paste_clipboard_file_item(id: i64) -> Result<Vec<String>, String> {
+    let conn = crate::db::pool::get()?;
+    let content: String = conn
+        .query_row(
+            "SELECT content FROM clipboard_history WHERE id = ?1 AND content_type = 'files'",
+            params![id],
+            |row| row.get(0),
+        )
+        .map_err(|_| format!("文件条目 {} 不存在", id))?;
+    let paths: Vec<String> =
+        serde_json::from_str(&content).map_err(|e| format!("条目内容损坏: {}", e))?;
+    let (existing, missing): (Vec<String>, Vec<String>) = paths
+        .into_iter()
+        .partition(|p| std::path::Path::new(p).exists());
+    if existing.is_empty() {
+        return Err("条目里的文件都已不存在".into());
+    }
+    for path in &missing {
+        log::warn!("[ClipboardFiles] skipping missing file {}", path);
+    }
+    let to_set = existing.clone();
+    tauri::async_runtime::spawn_blocking(move || set_file_list(&to_set))
+        .await
+        .map_err(|e| format!("剪贴板任务异常: {}", e))??;
+    Ok(missing)
+}
+
+/// 供 get_clipboard_item 之类的读取方把 files 条目解码成路径数组
+pub fn decode_file_item(content: &str) -> Vec<String> {
+    serde_json::from_str(content).unwrap_or_default()
+}
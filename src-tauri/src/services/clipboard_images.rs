@@ -0,0 +1,288 @@
+//! 剪贴板图片历史
+//!
+//! 剪贴板历史此前只有文本。图片走「原图落盘、缩略图预览、路径入库」
+//! 的模型：原 PNG 存 `app_data/clipboard-images/<sha256>.png`，缩略图
+//! 另存 `thumbs/`，`clipboard_history` 表里 `content_type = "image"`、
+//! `content` 存原图路径。`get_clipboard_item` 对图片返回缩略图的
+//! data URL 预览，`paste_clipboard_item` 把原图写回系统剪贴板
+//! （按平台走 osascript / PowerShell / wl-copy）。隐私会话期间不采集。
+
+use base64::Engine;
+use rusqlite::params;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use std::process::Command;
+use tauri::{AppHandle, Manager};
+
+/// 缩略图最长边
+const THUMBNAIL_SIZE: u32 = 256;
+/// 单张图片上限：10 MB
+const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+
+/// 一条剪贴板条目（含图片预览）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardItem {
+    pub id: i64,
+    pub content_type: String,
+    /// 文本条目是内容本身；图片条目是原图路径
+    pub content: String,
+    /// 图片条目的缩略图 data URL；文本条目为 None
+    pub preview: Option<String>,
+    pub created_at: i64,
+}
+
+fn images_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("clipboard-images");
+    std::fs::create_dir_all(dir.join("thumbs")).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// 采集一张剪贴板图片（前端监听到图片变化后以 base64 PNG 上报）。
+/// 同一张图重复复制按 sha256 去重，只刷新时间戳。
+#[tauri::command]
+pub fn record_clipboard_image(app: AppHandle, png_base64: String) -> Result<(), String> {
+    if crate::services::privacy_session::is_recording_paused() {
+        return Ok(());
+    }
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(png_base64.as_bytes())
+        .map_err(|e| format!("图片数据不是合法 base64: {}", e))?;
+    if bytes.len() > MAX_IMAGE_BYTES {
+        return Err("图片超过 10 MB，不记录历史".into());
+    }
+
+    let hash = format!("{:x}", Sha256::digest(&bytes));
+    let dir = images_dir(&app)?;
+    let full_path = dir.join(format!("{}.png", hash));
+    let path_str = full_path.display().to_string();
+
+    let conn = crate::db::pool::get()?;
+    let existing: Option<i64> = conn
+        .query_row(
+            "SELECT id FROM clipboard_history WHERE content = ?1 AND content_type = 'image'",
+            params![path_str],
+            |row| row.get(0),
+        )
+        .ok();
+    if let Some(id) = existing {
+        conn.execute(
+            "UPDATE clipboard_history SET created_at = ?1 WHERE id = ?2",
+            params![chrono::Utc::now().timestamp(), id],
+        )
+        .map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    let image = image::load_from_memory(&bytes).map_err(|e| format!("图片解码失败: {}", e))?;
+    std::fs::write(&full_path, &bytes).map_err(|e| format!("保存原图失败: {}", e))?;
+    let thumb = image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE);
+    let thumb_path = dir.join("thumbs").join(format!("{}.png", hash));
+    thumb
+        .save_with_format(&thumb_path, image::ImageFormat::Png)
+        .map_err(|e| format!("保存缩略图失败: {}", e))?;
+
+    conn.execute(
+        "INSERT INTO clipboard_history (content, content_type, created_at) VALUES (?1, 'image', ?2)",
+        params![path_str, chrono::Utc::now().timestamp()],
+    )
+    .map_err(|e| e.to_string())?;
+    log::info!("[ClipboardImages] captured image {} ({} bytes)", hash, bytes.len());
+    Ok(())
+}
+
+/// 读取单条历史；图片条目附带缩略图 data URL
+#[tauri::command]
+pub fn get_clipboard_item(app: AppHandle, id: i64) -> Result<ClipboardItem, String> {
+    let conn = crate::db::pool::get()?;
+    let (content, content_type, created_at): (String, String, i64) = conn
+        .query_row(
+            "SELECT content, content_type, created_at FROM clipboard_history WHERE id = ?1",
+            params![id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .map_err(|_| format!("剪贴板条目 {} 不存在", id))?;
+
+    let preview = if content_type == "image" {
+        let hash = PathBuf::from(&content)
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let thumb_path = images_dir(&app)?.join("thumbs").join(format!("{}.png", hash));
+        std::fs::read(&thumb_path).ok().map(|bytes| {
+            format!(
+                "data:image/png;base64,{}",
+                base64::engine::general_purpose::STANDARD.encode(&bytes)
+            )
+        })
+    } else {
+        None
+    };
+    Ok(ClipboardItem {
+        id,
+        content_type,
+        content,
+        preview,
+        created_at,
+    })
+}
+
+/// 把原图写回系统剪贴板（按平台走系统工具）
+fn set_clipboard_image(path: &std::path::Path) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let script = format!(
+            "set the clipboard to (read (POSIX file \"{}\") as «class PNGf»)",
+            path.display()
+        );
+        let output = Command::new("osascript")
+            .args(["-e", &script])
+            .output()
+            .map_err(|e| format!("启动 osascript 失败: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "写剪贴板失败: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        return Ok(());
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let script = format!(
+            "Add-Type -AssemblyName System.Windows.Forms; \
+             [System.Windows.Forms.Clipboard]::SetImage([System.Drawing.Image]::FromFile('{}'))",
+            path.display()
+        );
+        let output = Command::new("powershell")
+            .args(["-NoProfile", "-STA", "-Command", &script])
+            .output()
+            .map_err(|e| format!("启动 powershell 失败: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "写剪贴板失败: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        return Ok(());
+    }
+    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+    {
+        // Wayland 优先，回落 X11 的 xclip
+        let bytes = std::fs::read(path).map_err(|e| format!("读取原图失败: {}", e))?;
+        for (program, args) in [
+            ("wl-copy", vec!["--type", "image/png"]),
+            ("xclip", vec!["-selection", "clipboard", "-t", "image/png"]),
+        ] {
+            use std::io::Write;
+            let Ok(mut child) = Command::new(program)
+                .args(&args)
+                .stdin(std::process::Stdio::piped())
+                .spawn()
+            else {
+                continue;
+            };
+            if let Some(stdin) = child.stdin.as_mut() {
+                if stdin.write_all(&bytes).is_err() {
+                    continue;
+                }
+            }
+            if child.wait().map(|s| s.success()).unwrap_or(false) {
+                return Ok(());
+            }
+        }
+        Err("未找到 wl-copy 或 xclip，无法写入剪贴板".into())
+    }
+}
+
+/// 把历史条目还原到系统剪贴板；图片恢复原图，文本恢复文本
+#[tauri::command]
+pub async fn paste_clipboard_item(app: AppHandle, id: i64) -> Result<(), String> {
+    let item = get_clipboard_item(app, id)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        if item.content_type == "image" {
+            let path = PathBuf::from(&item.content);
+            if !path.exists() {
+                return Err("原图文件已被清理，无法恢复".into());
+            }
+            set_clipboard_image(&path)
+        } else {
+            set_clipboard_text(&item.content)
+        }
+    })
+    .await
+    .map_err(|e| format!("剪贴板任务异常: {}", e))?
+}
+
+/// 文本写回系统剪贴板（其它服务复制文本时也复用）
+pub(crate) fn set_clipboard_text(text: &str) -> Result<(), String> {
+    use std::io::Write;
+    let program_args: &[(&str, &[&str])] = if cfg!(target_os = "macos") {
+        &[("pbcopy", &[])]
+    } else if cfg!(target_os = "windows") {
+        &[("clip", &[])]
+    } else {
+        &[("wl-copy", &[]), ("xclip", &["-selection", "clipboard"])]
+    };
+    for (program, args) in program_args {
+        let Ok(mut child) = Command::new(program)
+            .args(*args)
+            .stdin(std::process::Stdio::piped())
+            .spawn()
+        else {
+            continue;
+        };
+        if let Some(stdin) = child.stdin.as_mut() {
+            if stdin.write_all(text.as_bytes()).is_err() {
+                continue;
+            }
+        }
+        if child.wait().map(|s| s.success()).unwrap_or(false) {
+            return Ok(());
+        }
+    }
+    Err("未找到可用的剪贴板写入工具".into())
+}
+
+/// 清理超出历史上限的图片条目及其磁盘文件（维护任务调用）
+pub fn prune(app: &AppHandle) -> Result<usize, String> {
+    let limit = crate::settings::store::get("clipboard_history_limit")
+        .ok()
+        .and_then(|v| v.as_i64())
+        .unwrap_or(500);
+    let conn = crate::db::pool::get()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, content FROM clipboard_history WHERE content_type = 'image'
+             ORDER BY created_at DESC LIMIT -1 OFFSET ?1",
+        )
+        .map_err(|e| e.to_string())?;
+    let stale: Vec<(i64, String)> = stmt
+        .query_map(params![limit], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(|e| e.to_string())?
+        .flatten()
+        .collect();
+    drop(stmt);
+
+    let dir = images_dir(app)?;
+    let mut removed = 0usize;
+    for (id, content) in stale {
+        conn.execute("DELETE FROM clipboard_history WHERE id = ?1", params![id])
+            .map_err(|e| e.to_string())?;
+        let path = PathBuf::from(&content);
+        if let Some(hash) = path.file_stem().map(|s| s.to_string_lossy().into_owned()) {
+            let _ = std::fs::remove_file(&path);
+            let _ = std::fs::remove_file(dir.join("thumbs").join(format!("{}.png", hash)));
+        }
+        removed += 1;
+    }
+    if removed > 0 {
+        log::info!("[ClipboardImages] pruned {} stale image items", removed);
+    }
+    Ok(removed)
+}
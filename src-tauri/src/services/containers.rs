@@ -0,0 +1,212 @@
+//! 容器集成（Docker / Podman）
+//!
+//! 通过本机 CLI 列出容器与 compose 项目，并以搜索结果形式提供
+//! 启动/停止/重启/看日志动作。属于「开发者集成」，默认关闭，由
+//! 设置项 `developer_integrations_enabled` 统一开启。CLI 自动探测：
+//! 优先 docker，缺失时回落 podman（命令行参数兼容）。
+
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// 日志动作默认返回的行数
+const LOG_TAIL_LINES: u32 = 200;
+
+/// 一个容器的概要
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerInfo {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    /// "running" / "exited" / "paused" 等
+    pub state: String,
+    pub status: String,
+}
+
+/// 开发者集成总开关（docker/kube 等 provider 共用）
+pub(crate) fn developer_integrations_enabled() -> bool {
+    crate::settings::store::get("developer_integrations_enabled")
+        .ok()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// 探测可用的容器 CLI；docker 优先
+fn container_cli() -> Option<&'static str> {
+    for cli in ["docker", "podman"] {
+        let available = Command::new(cli)
+            .arg("--version")
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false);
+        if available {
+            return Some(cli);
+        }
+    }
+    None
+}
+
+/// 列出全部容器（含已停止的）
+#[tauri::command]
+pub async fn list_containers() -> Result<Vec<ContainerInfo>, String> {
+    if !developer_integrations_enabled() {
+        return Err("开发者集成未开启，请在设置中打开 developer_integrations_enabled".into());
+    }
+    tauri::async_runtime::spawn_blocking(|| {
+        let cli = container_cli().ok_or("未检测到 docker 或 podman")?;
+        let output = Command::new(cli)
+            .args(["ps", "-a", "--format", "{{json .}}"])
+            .output()
+            .map_err(|e| format!("启动 {} 失败: {}", cli, e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "{} ps 失败: {}",
+                cli,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let mut containers = Vec::new();
+        // --format '{{json .}}' 每行一个 JSON 对象
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let Ok(obj) = serde_json::from_str::<serde_json::Value>(line) else { continue };
+            let field = |key: &str| {
+                obj.get(key)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            containers.push(ContainerInfo {
+                id: field("ID"),
+                name: field("Names"),
+                image: field("Image"),
+                state: field("State"),
+                status: field("Status"),
+            });
+        }
+        Ok(containers)
+    })
+    .await
+    .map_err(|e| format!("容器列表任务异常: {}", e))?
+}
+
+/// 对容器执行动作；`logs` 返回末尾若干行，其余动作返回空串
+#[tauri::command]
+pub async fn container_action(app: tauri::AppHandle, id: String, action: String) -> Result<String, String> {
+    if !developer_integrations_enabled() {
+        return Err("开发者集成未开启".into());
+    }
+    if !matches!(action.as_str(), "start" | "stop" | "restart" | "logs") {
+        return Err(format!("未知的容器动作: {}", action));
+    }
+    crate::services::audit_log::record(&app, "containerAction", &format!("{} {}", action, id));
+    tauri::async_runtime::spawn_blocking(move || {
+        let cli = container_cli().ok_or("未检测到 docker 或 podman")?;
+        let output = if action == "logs" {
+            Command::new(cli)
+                .args(["logs", "--tail", &LOG_TAIL_LINES.to_string(), &id])
+                .output()
+        } else {
+            Command::new(cli).args([action.as_str(), id.as_str()]).output()
+        }
+        .map_err(|e| format!("启动 {} 失败: {}", cli, e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "{} {} 失败: {}",
+                cli,
+                action,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        if action == "logs" {
+            // docker logs 会把部分输出写到 stderr
+            let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+            text.push_str(&String::from_utf8_lossy(&output.stderr));
+            Ok(text)
+        } else {
+            Ok(String::new())
+        }
+    })
+    .await
+    .map_err(|e| format!("容器动作任务异常: {}", e))?
+}
+
+/// 列出 compose 项目（`docker compose ls`）
+#[tauri::command]
+pub async fn list_compose_projects() -> Result<serde_json::Value, String> {
+    if !developer_integrations_enabled() {
+        return Err("开发者集成未开启".into());
+    }
+    tauri::async_runtime::spawn_blocking(|| {
+        let cli = container_cli().ok_or("未检测到 docker 或 podman")?;
+        let output = Command::new(cli)
+            .args(["compose", "ls", "--format", "json"])
+            .output()
+            .map_err(|e| format!("启动 {} 失败: {}", cli, e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "{} compose ls 失败: {}",
+                cli,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        serde_json::from_slice(&output.stdout).map_err(|e| format!("compose 输出解析失败: {}", e))
+    })
+    .await
+    .map_err(|e| format!("compose 列表任务异常: {}", e))?
+}
+
+/// 容器 provider：按名称/镜像匹配，payload 带可用动作
+pub struct ContainerProvider;
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for ContainerProvider {
+    fn name(&self) -> &str {
+        "containers"
+    }
+
+    fn priority(&self) -> i32 {
+        // 开发者集成靠后，不挤占日常搜索
+        160
+    }
+
+    fn scope_only(&self) -> bool {
+        // 每次搜索都要起 docker/podman ps 子进程，只在 docker: 范围里触发
+        true
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        if !developer_integrations_enabled() || query.chars().count() < 2 {
+            return Vec::new();
+        }
+        let Ok(containers) = list_containers().await else {
+            return Vec::new();
+        };
+        containers
+            .into_iter()
+            .filter_map(|c| {
+                let score = crate::search::fuzzy::score(query, &c.name)
+                    .max(crate::search::fuzzy::score(query, &c.image))?;
+                let toggle = if c.state == "running" { "stop" } else { "start" };
+                Some(crate::search::pipeline::SearchResult {
+                    id: format!("container:{}", c.id),
+                    title: c.name.clone(),
+                    subtitle: Some(format!("{}（{}）", c.image, c.status)),
+                    icon: None,
+                    provider: String::new(),
+                    score,
+                    payload: serde_json::json!({
+                        "containerId": c.id,
+                        "state": c.state,
+                        "actions": [toggle, "restart", "logs"],
+                    }),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 注册容器 provider 并登记 `docker:` 范围（启动时调用）
+pub fn register() {
+    crate::search::pipeline::register_provider(std::sync::Arc::new(ContainerProvider));
+    crate::search::scopes::register_scope("docker:", "containers", "容器");
+}
@@ -0,0 +1,107 @@
+//! 多格式复制服务
+//!
+//! 任意搜索结果都能以多种表示复制：纯文本、markdown 链接、HTML 锚点、
+//! shell 转义路径。各格式注册为结果的次级动作，默认格式可配置。
+
+use serde::{Deserialize, Serialize};
+
+/// 可用的复制格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CopyFormat {
+    Plain,
+    MarkdownLink,
+    HtmlAnchor,
+    ShellPath,
+}
+
+/// 待复制的结果素材
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopySource {
+    /// 展示标题（链接文本）
+    pub title: String,
+    /// 路径或 URL
+    pub target: String,
+}
+
+/// HTML 特殊字符转义
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// markdown 链接文本/目标中的特殊字符转义
+fn md_escape(s: &str) -> String {
+    s.replace('[', "\\[").replace(']', "\\]").replace(')', "\\)")
+}
+
+/// POSIX shell 单引号转义；Windows 下用双引号包裹
+fn shell_escape(path: &str) -> String {
+    #[cfg(target_os = "windows")]
+    {
+        format!("\"{}\"", path.replace('"', "\\\""))
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        if path
+            .chars()
+            .all(|c| c.is_alphanumeric() || "/.-_~".contains(c))
+        {
+            path.to_string()
+        } else {
+            format!("'{}'", path.replace('\'', r"'\''"))
+        }
+    }
+}
+
+/// 按指定格式渲染复制内容
+pub fn render(source: &CopySource, format: CopyFormat) -> String {
+    match format {
+        CopyFormat::Plain => source.target.clone(),
+        CopyFormat::MarkdownLink => {
+            format!("[{}]({})", md_escape(&source.title), md_escape(&source.target))
+        }
+        CopyFormat::HtmlAnchor => format!(
+            r#"<a href="{}">{}</a>"#,
+            html_escape(&source.target),
+            html_escape(&source.title)
+        ),
+        CopyFormat::ShellPath => shell_escape(&source.target),
+    }
+}
+
+/// 结果可用的全部复制动作（前端渲染为次级动作菜单）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyVariant {
+    pub format: CopyFormat,
+    pub label: String,
+    pub content: String,
+}
+
+/// 为一个结果生成全部复制变体
+#[tauri::command]
+pub fn get_copy_variants(source: CopySource) -> Vec<CopyVariant> {
+    [
+        (CopyFormat::Plain, "复制"),
+        (CopyFormat::MarkdownLink, "复制为 Markdown 链接"),
+        (CopyFormat::HtmlAnchor, "复制为 HTML 链接"),
+        (CopyFormat::ShellPath, "复制为 Shell 路径"),
+    ]
+    .into_iter()
+    .map(|(format, label)| CopyVariant {
+        format,
+        label: label.to_string(),
+        content: render(&source, format),
+    })
+    .collect()
+}
+
+/// 按格式渲染并返回内容（前端负责写剪贴板，以复用已有的历史记录路径）
+#[tauri::command]
+pub fn render_copy_as(source: CopySource, format: CopyFormat) -> String {
+    render(&source, format)
+}
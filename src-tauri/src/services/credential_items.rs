@@ -0,0 +1,272 @@
+//! 登录项感知（仅元数据）
+//!
+//! 对接密码管理器 CLI（Bitwarden `bw`、1Password `op`），把已保存的
+//! 登录项以「站点 + 用户名」的形式接入搜索：输入站点名即可打开网站
+//! 并复制用户名。**绝不读取、缓存或传输密码本身**——列表接口只取
+//! 元数据字段，结果也不落库。功能默认关闭，由设置项
+//! `credential_items_enabled` 显式开启。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// 列表缓存有效期；CLI 调用较慢，短缓存避免每次按键都起子进程
+const CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// 一条登录项（只有元数据，没有凭据）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginItem {
+    pub id: String,
+    pub site: String,
+    pub username: String,
+    /// 来源 CLI："bitwarden" / "1password"
+    pub source: String,
+}
+
+/// 密码管理器 CLI 的抽象；新增管理器只需加一个实现
+trait CredentialCli: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn binary(&self) -> &'static str;
+    /// 列出登录项元数据；**实现必须只解析站点与用户名字段**
+    fn list(&self) -> Result<Vec<LoginItem>, String>;
+
+    fn is_available(&self) -> bool {
+        Command::new(self.binary())
+            .arg("--version")
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+}
+
+struct BitwardenCli;
+
+impl CredentialCli for BitwardenCli {
+    fn name(&self) -> &'static str {
+        "bitwarden"
+    }
+
+    fn binary(&self) -> &'static str {
+        "bw"
+    }
+
+    fn list(&self) -> Result<Vec<LoginItem>, String> {
+        let output = Command::new("bw")
+            .args(["list", "items", "--nointeraction"])
+            .output()
+            .map_err(|e| format!("启动 bw 失败: {}", e))?;
+        if !output.status.success() {
+            return Err("bw list 失败，请确认已执行 bw unlock".into());
+        }
+        let items: serde_json::Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| format!("bw 输出不是合法 JSON: {}", e))?;
+        let mut logins = Vec::new();
+        for item in items.as_array().map(|a| a.as_slice()).unwrap_or(&[]) {
+            let Some(login) = item.get("login") else { continue };
+            let site = login
+                .get("uris")
+                .and_then(|u| u.as_array())
+                .and_then(|u| u.first())
+                .and_then(|u| u.get("uri"))
+                .and_then(|u| u.as_str())
+                .unwrap_or_default();
+            let username = login
+                .get("username")
+                .and_then(|u| u.as_str())
+                .unwrap_or_default();
+            if site.is_empty() {
+                continue;
+            }
+            logins.push(LoginItem {
+                id: item
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                site: site.to_string(),
+                username: username.to_string(),
+                source: self.name().to_string(),
+            });
+        }
+        Ok(logins)
+    }
+}
+
+struct OnePasswordCli;
+
+impl CredentialCli for OnePasswordCli {
+    fn name(&self) -> &'static str {
+        "1password"
+    }
+
+    fn binary(&self) -> &'static str {
+        "op"
+    }
+
+    fn list(&self) -> Result<Vec<LoginItem>, String> {
+        // `op item list` 只返回元数据，不含凭据字段
+        let output = Command::new("op")
+            .args(["item", "list", "--categories", "Login", "--format", "json"])
+            .output()
+            .map_err(|e| format!("启动 op 失败: {}", e))?;
+        if !output.status.success() {
+            return Err("op item list 失败，请确认已执行 op signin".into());
+        }
+        let items: serde_json::Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| format!("op 输出不是合法 JSON: {}", e))?;
+        let mut logins = Vec::new();
+        for item in items.as_array().map(|a| a.as_slice()).unwrap_or(&[]) {
+            let site = item
+                .get("urls")
+                .and_then(|u| u.as_array())
+                .and_then(|u| u.first())
+                .and_then(|u| u.get("href"))
+                .and_then(|u| u.as_str())
+                .unwrap_or_default();
+            if site.is_empty() {
+                continue;
+            }
+            logins.push(LoginItem {
+                id: item
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                site: site.to_string(),
+                username: item
+                    .get("additional_information")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                source: self.name().to_string(),
+            });
+        }
+        Ok(logins)
+    }
+}
+
+static BACKENDS: &[&(dyn CredentialCli)] = &[&BitwardenCli, &OnePasswordCli];
+
+static CACHE: Lazy<RwLock<Option<(Instant, Vec<LoginItem>)>>> = Lazy::new(|| RwLock::new(None));
+
+fn is_enabled() -> bool {
+    crate::settings::store::get("credential_items_enabled")
+        .ok()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// 汇总所有可用 CLI 的登录项；带短缓存
+fn all_items() -> Vec<LoginItem> {
+    if let Ok(cache) = CACHE.read() {
+        if let Some((at, items)) = cache.as_ref() {
+            if at.elapsed() < CACHE_TTL {
+                return items.clone();
+            }
+        }
+    }
+    let mut items = Vec::new();
+    for backend in BACKENDS {
+        if !backend.is_available() {
+            continue;
+        }
+        match backend.list() {
+            Ok(mut list) => items.append(&mut list),
+            Err(e) => log::warn!("[Credentials] {} list failed: {}", backend.name(), e),
+        }
+    }
+    if let Ok(mut cache) = CACHE.write() {
+        *cache = Some((Instant::now(), items.clone()));
+    }
+    items
+}
+
+/// 搜索登录项（站点名/用户名模糊匹配）
+#[tauri::command]
+pub async fn search_login_items(query: String) -> Result<Vec<LoginItem>, String> {
+    if !is_enabled() {
+        return Err("登录项搜索未开启，请在设置中打开 credential_items_enabled".into());
+    }
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut scored: Vec<(i32, LoginItem)> = all_items()
+            .into_iter()
+            .filter_map(|item| {
+                let site_score = crate::search::fuzzy::score(&query, &item.site);
+                let user_score = crate::search::fuzzy::score(&query, &item.username);
+                site_score.max(user_score).map(|s| (s, item))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        scored.truncate(20);
+        Ok(scored.into_iter().map(|(_, item)| item).collect())
+    })
+    .await
+    .map_err(|e| format!("搜索任务异常: {}", e))?
+}
+
+/// 登录项搜索 provider；未开启时静默返回空，不打扰搜索流水线
+pub struct CredentialProvider;
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for CredentialProvider {
+    fn name(&self) -> &str {
+        "credentials"
+    }
+
+    fn priority(&self) -> i32 {
+        // 登录项不如应用/剪贴板常用，排在内置来源之后
+        150
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        if !is_enabled() || query.chars().count() < 2 {
+            return Vec::new();
+        }
+        match search_login_items(query.to_string()).await {
+            Ok(items) => items
+                .into_iter()
+                .filter_map(|item| {
+                    // 与 search_login_items 相同的双字段取分，命中用户名的条目不能丢
+                    let score = crate::search::fuzzy::score(query, &item.site)
+                        .max(crate::search::fuzzy::score(query, &item.username))?;
+                    Some(crate::search::pipeline::SearchResult {
+                        id: format!("credential:{}", item.id),
+                        title: item.site.clone(),
+                        subtitle: Some(format!("{}（{}）", item.username, item.source)),
+                        icon: None,
+                        provider: String::new(),
+                        score,
+                        payload: serde_json::to_value(&item).unwrap_or_default(),
+                    })
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+/// 注册登录项 provider（启动时调用）
+pub fn register() {
+    crate::search::pipeline::register_provider(std::sync::Arc::new(CredentialProvider));
+}
+
+/// 打开登录项站点并把用户名复制到剪贴板
+#[tauri::command]
+pub async fn open_login_item(app: tauri::AppHandle, item: LoginItem) -> Result<(), String> {
+    if !is_enabled() {
+        return Err("登录项搜索未开启".into());
+    }
+    if !item.username.is_empty() {
+        crate::services::emotes::copy_emote(item.username.clone(), "text".into())?;
+    }
+    let url = if item.site.starts_with("http") {
+        item.site.clone()
+    } else {
+        format!("https://{}", item.site)
+    };
+    // 站点 URL 来自外部保险库，必须过 open_url 的 scheme 校验与审计
+    crate::cmds::open_url::open_url(app, url)
+}
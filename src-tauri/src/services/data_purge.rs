@@ -0,0 +1,107 @@
+//! 一键清除个人数据
+//!
+//! 共用机器的用户需要一个"全部抹掉"的出口：剪贴板历史、搜索/查询
+//! 历史、使用统计、市场与图标缓存、日志一次清空，并汇报各项删除
+//! 量。操作必须显式带 `confirm: true`（前端二次确认后传入），
+//! 清空后在新的审计日志里留一条 purge 记录。
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+/// 单类数据的清除结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgedCategory {
+    pub category: String,
+    /// 删除的行数或文件数
+    pub removed: u64,
+}
+
+/// 清除报告
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeReport {
+    pub categories: Vec<PurgedCategory>,
+    pub warnings: Vec<String>,
+}
+
+fn purge_table(report: &mut PurgeReport, name: &str, sql: &str) {
+    match crate::db::pool::get() {
+        Ok(conn) => match conn.execute(sql, []) {
+            Ok(n) => report.categories.push(PurgedCategory {
+                category: name.into(),
+                removed: n as u64,
+            }),
+            Err(e) => report.warnings.push(format!("{} 清除失败: {}", name, e)),
+        },
+        Err(e) => report.warnings.push(format!("数据库不可用: {}", e)),
+    }
+}
+
+/// 删除目录内全部文件并重建目录；返回删除的文件数
+fn purge_dir(dir: &std::path::Path) -> u64 {
+    let count = std::fs::read_dir(dir)
+        .map(|rd| rd.flatten().count() as u64)
+        .unwrap_or(0);
+    let _ = std::fs::remove_dir_all(dir);
+    let _ = std::fs::create_dir_all(dir);
+    count
+}
+
+/// 清除全部个人数据；`confirm` 必须为 true
+#[tauri::command]
+pub fn purge_all_personal_data(app: AppHandle, confirm: bool) -> Result<PurgeReport, String> {
+    if !confirm {
+        return Err("操作未确认".into());
+    }
+    let mut report = PurgeReport::default();
+
+    purge_table(&mut report, "剪贴板历史", "DELETE FROM clipboard_history");
+    purge_table(&mut report, "搜索历史", "DELETE FROM search_history");
+    purge_table(&mut report, "使用统计", "DELETE FROM app_usage");
+    purge_table(&mut report, "索引统计", "DELETE FROM index_stats_daily");
+    purge_table(&mut report, "RSS 已读状态", "UPDATE rss_items SET is_read = 0");
+
+    // 回收 SQLite 空间，避免已删数据留在空闲页里
+    if let Ok(conn) = crate::db::pool::get() {
+        if let Err(e) = conn.execute_batch("VACUUM;") {
+            report.warnings.push(format!("VACUUM 失败: {}", e));
+        }
+    }
+
+    if let Ok(data_dir) = app.path().app_data_dir() {
+        for (name, sub) in [
+            ("市场缓存", "marketplace-cache"),
+            ("图标缓存", "icon-cache"),
+            ("日志", "logs"),
+        ] {
+            let dir = data_dir.join(sub);
+            if dir.exists() {
+                report.categories.push(PurgedCategory {
+                    category: name.into(),
+                    removed: purge_dir(&dir),
+                });
+            }
+        }
+        // 审计日志文件本身也属于个人数据
+        let audit = data_dir.join("audit.log");
+        if audit.exists() {
+            let _ = std::fs::remove_file(&audit);
+            // 文件没了，缓存的链尾哈希也得清掉，新日志才能从创世哈希续链
+            crate::services::audit_log::reset_chain();
+            report.categories.push(PurgedCategory {
+                category: "审计日志".into(),
+                removed: 1,
+            });
+        }
+    }
+
+    // 在清空后的审计日志里留下 purge 本身的记录
+    crate::services::audit_log::record(&app, "dataPurge", "all personal data");
+    log::info!(
+        "[DataPurge] completed: {} categories, {} warnings",
+        report.categories.len(),
+        report.warnings.len()
+    );
+    Ok(report)
+}
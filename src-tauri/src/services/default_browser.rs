@@ -0,0 +1,139 @@
+//! 默认浏览器探测
+//!
+//! `get_default_browser` 跨平台实现：Windows 读 UserChoice 注册表键，
+//! Linux 用 xdg-settings，macOS 查询 LaunchServices。返回浏览器名称、
+//! 可执行路径和图标标识，URL 结果可以显示将由哪个浏览器打开。
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserInfo {
+    /// 展示名，如 "Google Chrome"
+    pub name: String,
+    /// 可执行文件路径或 bundle id；探测不到为 None
+    #[serde(default)]
+    pub executable: Option<String>,
+    /// 前端图标映射键："chrome" / "firefox" / "safari" / "edge" / "generic"
+    pub icon: String,
+}
+
+/// 从标识字符串推断浏览器（progId / desktop 文件名 / bundle id 通用）
+fn classify(identifier: &str) -> (String, String) {
+    let lower = identifier.to_lowercase();
+    if lower.contains("chrome") {
+        ("Google Chrome".into(), "chrome".into())
+    } else if lower.contains("firefox") {
+        ("Firefox".into(), "firefox".into())
+    } else if lower.contains("safari") {
+        ("Safari".into(), "safari".into())
+    } else if lower.contains("edge") {
+        ("Microsoft Edge".into(), "edge".into())
+    } else if lower.contains("brave") {
+        ("Brave".into(), "brave".into())
+    } else if lower.contains("arc") {
+        ("Arc".into(), "arc".into())
+    } else {
+        (identifier.to_string(), "generic".into())
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn detect() -> Option<BrowserInfo> {
+    use winreg::enums::HKEY_CURRENT_USER;
+    use winreg::RegKey;
+
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let key = hkcu
+        .open_subkey(
+            r"Software\Microsoft\Windows\Shell\Associations\UrlAssociations\https\UserChoice",
+        )
+        .ok()?;
+    let prog_id: String = key.get_value("ProgId").ok()?;
+    let (name, icon) = classify(&prog_id);
+
+    // 通过 ProgId 的 shell\open\command 拿可执行路径
+    let hkcr = RegKey::predef(winreg::enums::HKEY_CLASSES_ROOT);
+    let executable = hkcr
+        .open_subkey(format!(r"{}\shell\open\command", prog_id))
+        .ok()
+        .and_then(|k| k.get_value::<String, _>("").ok())
+        .map(|cmd| cmd.split('"').nth(1).unwrap_or(&cmd).to_string());
+    Some(BrowserInfo {
+        name,
+        executable,
+        icon,
+    })
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn detect() -> Option<BrowserInfo> {
+    use std::process::Command;
+
+    let output = Command::new("xdg-settings")
+        .args(["get", "default-web-browser"])
+        .output()
+        .ok()?;
+    let desktop = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if desktop.is_empty() {
+        return None;
+    }
+    let (name, icon) = classify(&desktop);
+    // desktop 文件里的 Exec 行给出可执行路径
+    let executable = ["/usr/share/applications", "/usr/local/share/applications"]
+        .iter()
+        .map(|dir| format!("{}/{}", dir, desktop))
+        .find_map(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| {
+            content
+                .lines()
+                .find(|l| l.starts_with("Exec="))
+                .map(|l| l.trim_start_matches("Exec=").split(' ').next().unwrap_or("").to_string())
+        });
+    Some(BrowserInfo {
+        name,
+        executable,
+        icon,
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn detect() -> Option<BrowserInfo> {
+    use std::process::Command;
+
+    // 读取 LaunchServices 的 https handler 配置
+    let output = Command::new("defaults")
+        .args([
+            "read",
+            "com.apple.LaunchServices/com.apple.launchservices.secure",
+            "LSHandlers",
+        ])
+        .output()
+        .ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    let bundle_id = text
+        .split("LSHandlerURLScheme = https")
+        .next()
+        .and_then(|before| before.rsplit("LSHandlerRoleAll = \"").next())
+        .and_then(|after| after.split('"').next())
+        .filter(|s| s.contains('.'))
+        .map(String::from)
+        // 未显式设置 handler 时默认 Safari
+        .unwrap_or_else(|| "com.apple.Safari".into());
+    let (name, icon) = classify(&bundle_id);
+    Some(BrowserInfo {
+        name,
+        executable: Some(bundle_id),
+        icon,
+    })
+}
+
+/// 探测系统默认浏览器；失败时返回通用条目而非错误
+#[tauri::command]
+pub fn get_default_browser() -> BrowserInfo {
+    detect().unwrap_or(BrowserInfo {
+        name: "系统默认浏览器".into(),
+        executable: None,
+        icon: "generic".into(),
+    })
+}
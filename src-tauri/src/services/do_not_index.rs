@@ -0,0 +1,140 @@
+//! 免索引隐私名单
+//!
+//! 用户可以把目录标成"永不索引、永不展示"。名单以加盐 SHA-256 存
+//! 储——配置文件泄露也不会暴露敏感目录名。判断某路径是否被排除时，
+//! 对其每一级祖先目录求哈希查表，所以排除目录本身与其任意深度的
+//! 子路径都会被拦下。文件索引器、最近文件、下载监视器统一调
+//! `is_excluded`。
+
+use base64::Engine;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ExclusionFile {
+    /// 每台机器独立的盐（base64），首次使用时生成
+    salt: String,
+    hashes: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct Exclusions {
+    salt: Vec<u8>,
+    hashes: HashSet<String>,
+}
+
+static EXCLUSIONS: Lazy<RwLock<Exclusions>> = Lazy::new(|| RwLock::new(Exclusions::default()));
+
+fn config_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("do-not-index.json"))
+}
+
+fn hash_path(salt: &[u8], canonical: &Path) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt);
+    hasher.update(canonical.to_string_lossy().as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+fn persist(app: &AppHandle) -> Result<(), String> {
+    let exclusions = EXCLUSIONS.read().map_err(|e| e.to_string())?;
+    let file = ExclusionFile {
+        salt: base64::engine::general_purpose::STANDARD.encode(&exclusions.salt),
+        hashes: exclusions.hashes.iter().cloned().collect(),
+    };
+    let json = serde_json::to_string_pretty(&file).map_err(|e| e.to_string())?;
+    std::fs::write(config_path(app)?, json).map_err(|e| format!("保存排除名单失败: {}", e))
+}
+
+/// 启动时加载名单；没有盐时现场生成
+pub fn load(app: &AppHandle) {
+    let loaded = config_path(app)
+        .ok()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str::<ExclusionFile>(&s).ok());
+    let Ok(mut exclusions) = EXCLUSIONS.write() else { return };
+    match loaded {
+        Some(file) => {
+            exclusions.salt = base64::engine::general_purpose::STANDARD
+                .decode(&file.salt)
+                .unwrap_or_default();
+            exclusions.hashes = file.hashes.into_iter().collect();
+        }
+        None => {
+            let mut hasher = Sha256::new();
+            hasher.update(std::process::id().to_le_bytes());
+            hasher.update(
+                chrono::Utc::now()
+                    .timestamp_nanos_opt()
+                    .unwrap_or(0)
+                    .to_le_bytes(),
+            );
+            exclusions.salt = hasher.finalize().to_vec();
+        }
+    }
+}
+
+/// 路径（或其任意祖先）是否在免索引名单中
+pub fn is_excluded(path: &Path) -> bool {
+    let Ok(exclusions) = EXCLUSIONS.read() else { return false };
+    if exclusions.hashes.is_empty() {
+        return false;
+    }
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut current: Option<&Path> = Some(&canonical);
+    while let Some(p) = current {
+        if exclusions.hashes.contains(&hash_path(&exclusions.salt, p)) {
+            return true;
+        }
+        current = p.parent();
+    }
+    false
+}
+
+/// 把目录加入名单；只存哈希
+#[tauri::command]
+pub fn add_do_not_index(app: AppHandle, path: String) -> Result<(), String> {
+    let canonical = Path::new(&path)
+        .canonicalize()
+        .map_err(|e| format!("路径无效: {}", e))?;
+    {
+        let mut exclusions = EXCLUSIONS.write().map_err(|e| e.to_string())?;
+        let hash = hash_path(&exclusions.salt, &canonical);
+        exclusions.hashes.insert(hash);
+    }
+    persist(&app)?;
+    crate::services::audit_log::record(&app, "doNotIndexAdd", "(path withheld)");
+    Ok(())
+}
+
+/// 移除名单条目；需要重新输入原路径（名单无法反向列出）
+#[tauri::command]
+pub fn remove_do_not_index(app: AppHandle, path: String) -> Result<(), String> {
+    let canonical = Path::new(&path)
+        .canonicalize()
+        .map_err(|e| format!("路径无效: {}", e))?;
+    let removed = {
+        let mut exclusions = EXCLUSIONS.write().map_err(|e| e.to_string())?;
+        let hash = hash_path(&exclusions.salt, &canonical);
+        exclusions.hashes.remove(&hash)
+    };
+    if !removed {
+        return Err("该路径不在名单中".into());
+    }
+    persist(&app)
+}
+
+/// 名单条目数（设置页展示）
+#[tauri::command]
+pub fn count_do_not_index() -> usize {
+    EXCLUSIONS.read().map(|e| e.hashes.len()).unwrap_or(0)
+}
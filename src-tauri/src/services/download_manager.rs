@@ -0,0 +1,288 @@
+//! 中央下载管理器
+//!
+//! 市场、更新器、docset、AI 模型下载统一走这里：进度事件、暂停/恢复
+//! （HTTP Range 断点续传）、校验和验证，以及 `list_downloads` /
+//! `cancel_download` API——取代各功能各自的阻塞 reqwest 调用。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter};
+
+/// 下载进度事件
+pub const DOWNLOAD_PROGRESS_EVENT: &str = "download://progress";
+
+/// 下载状态
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DownloadState {
+    Running,
+    Paused,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+/// 对外暴露的下载条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadInfo {
+    pub id: String,
+    pub url: String,
+    pub dest: String,
+    pub state: DownloadState,
+    pub downloaded_bytes: u64,
+    /// 总大小；服务器未返回 Content-Length 时为 0
+    pub total_bytes: u64,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+struct DownloadTask {
+    info: DownloadInfo,
+    /// 期望的 SHA-256（hex）；None 表示不校验
+    checksum: Option<String>,
+    cancel_flag: Arc<AtomicBool>,
+    pause_flag: Arc<AtomicBool>,
+}
+
+static DOWNLOADS: Lazy<Mutex<HashMap<String, DownloadTask>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn update_info(id: &str, f: impl FnOnce(&mut DownloadInfo)) {
+    if let Ok(mut downloads) = DOWNLOADS.lock() {
+        if let Some(task) = downloads.get_mut(id) {
+            f(&mut task.info);
+        }
+    }
+}
+
+fn emit_progress(app: &AppHandle, id: &str) {
+    let info = DOWNLOADS
+        .lock()
+        .ok()
+        .and_then(|d| d.get(id).map(|t| t.info.clone()));
+    if let Some(info) = info {
+        let _ = app.emit(DOWNLOAD_PROGRESS_EVENT, info);
+    }
+}
+
+async fn run_download(app: AppHandle, id: String) {
+    let (url, dest, cancel, pause, resume_from) = {
+        let Ok(downloads) = DOWNLOADS.lock() else { return };
+        let Some(task) = downloads.get(&id) else { return };
+        (
+            task.info.url.clone(),
+            PathBuf::from(&task.info.dest),
+            task.cancel_flag.clone(),
+            task.pause_flag.clone(),
+            task.info.downloaded_bytes,
+        )
+    };
+
+    // 同时登记到全局取消框架，前端可统一走 cancel_operation("download:<id>")
+    let _token = crate::app::cancellation::CancelToken::register_with_flag(
+        &format!("download:{}", id),
+        cancel.clone(),
+    )
+    .ok();
+
+    let result = download_loop(&app, &id, &url, &dest, cancel, pause, resume_from).await;
+    match result {
+        Ok(true) => {
+            // 校验和检查
+            let checksum = DOWNLOADS
+                .lock()
+                .ok()
+                .and_then(|d| d.get(&id).and_then(|t| t.checksum.clone()));
+            if let Some(expected) = checksum {
+                match verify_checksum(&dest, &expected) {
+                    Ok(true) => update_info(&id, |i| i.state = DownloadState::Completed),
+                    Ok(false) => {
+                        let _ = std::fs::remove_file(&dest);
+                        update_info(&id, |i| {
+                            i.state = DownloadState::Failed;
+                            i.error = Some("校验和不匹配，文件已删除".into());
+                        });
+                    }
+                    Err(e) => update_info(&id, |i| {
+                        i.state = DownloadState::Failed;
+                        i.error = Some(e);
+                    }),
+                }
+            } else {
+                update_info(&id, |i| i.state = DownloadState::Completed);
+            }
+        }
+        Ok(false) => {} // 暂停/取消，状态已在循环内更新
+        Err(e) => update_info(&id, |i| {
+            i.state = DownloadState::Failed;
+            i.error = Some(e);
+        }),
+    }
+    emit_progress(&app, &id);
+}
+
+/// 返回 Ok(true) 表示下载完成，Ok(false) 表示被暂停/取消
+async fn download_loop(
+    app: &AppHandle,
+    id: &str,
+    url: &str,
+    dest: &PathBuf,
+    cancel: Arc<AtomicBool>,
+    pause: Arc<AtomicBool>,
+    resume_from: u64,
+) -> Result<bool, String> {
+    use std::io::Write;
+    use futures_util::StreamExt;
+
+    let client = crate::marketplace::http_client::client();
+    let mut request = client.get(url);
+    if resume_from > 0 {
+        request = request.header("Range", format!("bytes={}-", resume_from));
+    }
+    let resp = request.send().await.map_err(|e| format!("请求失败: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("服务器返回 {}", resp.status()));
+    }
+    // 续传必须拿到 206；返回 200 说明服务器忽略了 Range、给的是完整文件，
+    // 追加写会把整份内容拼到半截文件后面，只能截断从头下
+    let resume_from = if resume_from > 0 && resp.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+        log::warn!("[Download] server ignored Range for {}, restarting from scratch", id);
+        update_info(id, |i| i.downloaded_bytes = 0);
+        0
+    } else {
+        resume_from
+    };
+    let total = resume_from + resp.content_length().unwrap_or(0);
+    update_info(id, |i| i.total_bytes = total);
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(resume_from > 0)
+        .truncate(resume_from == 0)
+        .write(true)
+        .open(dest)
+        .map_err(|e| format!("打开目标文件失败: {}", e))?;
+
+    let mut stream = resp.bytes_stream();
+    let mut downloaded = resume_from;
+    let mut last_emit = std::time::Instant::now();
+    while let Some(chunk) = stream.next().await {
+        if cancel.load(Ordering::SeqCst) {
+            update_info(id, |i| i.state = DownloadState::Cancelled);
+            let _ = std::fs::remove_file(dest);
+            return Ok(false);
+        }
+        if pause.load(Ordering::SeqCst) {
+            update_info(id, |i| i.state = DownloadState::Paused);
+            return Ok(false);
+        }
+        let chunk = chunk.map_err(|e| format!("下载中断: {}", e))?;
+        file.write_all(&chunk).map_err(|e| format!("写入失败: {}", e))?;
+        downloaded += chunk.len() as u64;
+        update_info(id, |i| i.downloaded_bytes = downloaded);
+        // 进度事件限流到 ~4 次/秒
+        if last_emit.elapsed().as_millis() >= 250 {
+            last_emit = std::time::Instant::now();
+            emit_progress(app, id);
+        }
+    }
+    Ok(true)
+}
+
+fn verify_checksum(path: &PathBuf, expected_hex: &str) -> Result<bool, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("读取文件校验失败: {}", e))?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()).eq_ignore_ascii_case(expected_hex))
+}
+
+/// 开始下载；`checksum` 为可选的 SHA-256（hex）
+#[tauri::command]
+pub fn start_download(
+    app: AppHandle,
+    id: String,
+    url: String,
+    dest: String,
+    checksum: Option<String>,
+) -> Result<(), String> {
+    {
+        let mut downloads = DOWNLOADS.lock().map_err(|e| e.to_string())?;
+        if let Some(existing) = downloads.get(&id) {
+            if existing.info.state == DownloadState::Running {
+                return Err(format!("下载 {} 已在进行中", id));
+            }
+        }
+        downloads.insert(
+            id.clone(),
+            DownloadTask {
+                info: DownloadInfo {
+                    id: id.clone(),
+                    url,
+                    dest,
+                    state: DownloadState::Running,
+                    downloaded_bytes: 0,
+                    total_bytes: 0,
+                    error: None,
+                },
+                checksum,
+                cancel_flag: Arc::new(AtomicBool::new(false)),
+                pause_flag: Arc::new(AtomicBool::new(false)),
+            },
+        );
+    }
+    tauri::async_runtime::spawn(run_download(app, id));
+    Ok(())
+}
+
+/// 暂停下载；已下载部分保留，可续传
+#[tauri::command]
+pub fn pause_download(id: String) -> Result<(), String> {
+    let downloads = DOWNLOADS.lock().map_err(|e| e.to_string())?;
+    let task = downloads
+        .get(&id)
+        .ok_or_else(|| format!("下载 {} 不存在", id))?;
+    task.pause_flag.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// 恢复暂停的下载（Range 续传）
+#[tauri::command]
+pub fn resume_download(app: AppHandle, id: String) -> Result<(), String> {
+    {
+        let mut downloads = DOWNLOADS.lock().map_err(|e| e.to_string())?;
+        let task = downloads
+            .get_mut(&id)
+            .ok_or_else(|| format!("下载 {} 不存在", id))?;
+        if task.info.state != DownloadState::Paused {
+            return Err(format!("下载 {} 不在暂停状态", id));
+        }
+        task.pause_flag.store(false, Ordering::SeqCst);
+        task.info.state = DownloadState::Running;
+    }
+    tauri::async_runtime::spawn(run_download(app, id));
+    Ok(())
+}
+
+/// 取消下载并删除未完成文件
+#[tauri::command]
+pub fn cancel_download(id: String) -> Result<(), String> {
+    let downloads = DOWNLOADS.lock().map_err(|e| e.to_string())?;
+    let task = downloads
+        .get(&id)
+        .ok_or_else(|| format!("下载 {} 不存在", id))?;
+    task.cancel_flag.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// 列出全部下载（含历史状态）
+#[tauri::command]
+pub fn list_downloads() -> Result<Vec<DownloadInfo>, String> {
+    let downloads = DOWNLOADS.lock().map_err(|e| e.to_string())?;
+    Ok(downloads.values().map(|t| t.info.clone()).collect())
+}
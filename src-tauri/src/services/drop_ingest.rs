@@ -0,0 +1,102 @@
+//! 拖放文件接入
+//!
+//! 文件拖到启动器窗口后，后端按类型计算可执行的动作菜单
+//! （.etp 插件包→安装、普通文件→加入索引/复制路径/分享），
+//! 取代之前只把文件读成 buffer 丢给插件的单一流程。
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// 针对一个拖入文件的动作
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DropAction {
+    /// "installPlugin" / "addToIndex" / "copyPath" / "share" / "openWithPlugin"
+    pub id: String,
+    pub title: String,
+    /// 前端执行该动作时调用的命令
+    pub command: String,
+}
+
+/// 单个拖入文件的解析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DroppedFile {
+    pub path: String,
+    pub name: String,
+    pub size: u64,
+    /// 归类："plugin" / "image" / "text" / "archive" / "other"
+    pub kind: String,
+    pub actions: Vec<DropAction>,
+}
+
+fn classify(path: &Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("etp") | Some("etpkg") => "plugin",
+        Some("png") | Some("jpg") | Some("jpeg") | Some("gif") | Some("webp") => "image",
+        Some("txt") | Some("md") | Some("json") | Some("csv") | Some("log") => "text",
+        Some("zip") | Some("tar") | Some("gz") | Some("7z") => "archive",
+        _ => "other",
+    }
+}
+
+fn actions_for(kind: &str) -> Vec<DropAction> {
+    let mut actions = Vec::new();
+    if kind == "plugin" {
+        actions.push(DropAction {
+            id: "installPlugin".into(),
+            title: "安装插件".into(),
+            command: "install_plugin_package".into(),
+        });
+    }
+    // 通用动作
+    actions.push(DropAction {
+        id: "addToIndex".into(),
+        title: "加入搜索索引".into(),
+        command: "add_index_path".into(),
+    });
+    actions.push(DropAction {
+        id: "copyPath".into(),
+        title: "复制路径".into(),
+        command: "copy_to_clipboard".into(),
+    });
+    actions.push(DropAction {
+        id: "share".into(),
+        title: "分享".into(),
+        command: "share_file".into(),
+    });
+    actions
+}
+
+/// 解析一批拖入的文件，返回每个文件的动作菜单
+#[tauri::command]
+pub fn ingest_dropped_files(paths: Vec<String>) -> Result<Vec<DroppedFile>, String> {
+    let mut results = Vec::with_capacity(paths.len());
+    for raw in paths {
+        let path = Path::new(&raw);
+        let Ok(meta) = path.metadata() else {
+            log::warn!("[DropIngest] dropped path vanished: {}", raw);
+            continue;
+        };
+        let kind = if meta.is_dir() { "dir" } else { classify(path) };
+        results.push(DroppedFile {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| raw.clone()),
+            path: raw,
+            size: meta.len(),
+            kind: kind.to_string(),
+            actions: actions_for(kind),
+        });
+    }
+    if results.is_empty() {
+        return Err("没有可处理的文件".into());
+    }
+    Ok(results)
+}
@@ -0,0 +1,243 @@
+//! 编辑器最近工作区（VS Code / JetBrains）
+//!
+//! 读 VS Code 的 `workspaceStorage/*/workspace.json` 与 JetBrains 各
+//! 产品的 `options/recentProjects.xml`，把最近打开的工作区接入搜索，
+//! 动作为「在对应编辑器中打开」。只读编辑器自己的存储文件，不依赖
+//! 编辑器进程在运行。属于开发者集成，由
+//! `developer_integrations_enabled` 开启。
+
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// 一条最近工作区
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentWorkspace {
+    pub path: String,
+    /// "vscode" 或 JetBrains 产品名（如 "IntelliJIdea"）
+    pub editor: String,
+    /// 打开用的 CLI；探测不到时为 None，回落系统默认打开
+    pub open_command: Option<String>,
+}
+
+fn home_dir() -> Option<PathBuf> {
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()
+        .map(PathBuf::from)
+}
+
+/// VS Code 用户数据目录（按平台；Code 与 VSCodium 都认）
+fn vscode_user_dirs() -> Vec<PathBuf> {
+    let Some(home) = home_dir() else { return Vec::new() };
+    let roots: Vec<PathBuf> = if cfg!(target_os = "macos") {
+        vec![home.join("Library/Application Support")]
+    } else if cfg!(target_os = "windows") {
+        std::env::var("APPDATA").map(PathBuf::from).into_iter().collect()
+    } else {
+        vec![home.join(".config")]
+    };
+    let mut dirs = Vec::new();
+    for root in roots {
+        for product in ["Code", "VSCodium"] {
+            let dir = root.join(product).join("User");
+            if dir.is_dir() {
+                dirs.push(dir);
+            }
+        }
+    }
+    dirs
+}
+
+/// 扫描 VS Code 的 workspaceStorage：每个目录一个 workspace.json
+fn vscode_workspaces() -> Vec<RecentWorkspace> {
+    let cli = Command::new("code")
+        .arg("--version")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+        .then(|| "code".to_string());
+    let mut out = Vec::new();
+    for user_dir in vscode_user_dirs() {
+        let storage = user_dir.join("workspaceStorage");
+        let Ok(entries) = std::fs::read_dir(&storage) else { continue };
+        for entry in entries.flatten() {
+            let meta_file = entry.path().join("workspace.json");
+            let Ok(content) = std::fs::read_to_string(&meta_file) else { continue };
+            let Ok(meta) = serde_json::from_str::<serde_json::Value>(&content) else { continue };
+            let Some(folder) = meta.get("folder").and_then(|v| v.as_str()) else { continue };
+            // folder 是 file:// URI，转回本地路径（顺带解码百分号转义）
+            let Some(path) = url::Url::parse(folder)
+                .ok()
+                .filter(|u| u.scheme() == "file")
+                .and_then(|u| u.to_file_path().ok())
+            else {
+                continue;
+            };
+            if path.exists() {
+                out.push(RecentWorkspace {
+                    path: path.display().to_string(),
+                    editor: "vscode".into(),
+                    open_command: cli.clone(),
+                });
+            }
+        }
+    }
+    out
+}
+
+/// JetBrains 配置根目录（按平台）
+fn jetbrains_config_root() -> Option<PathBuf> {
+    let home = home_dir()?;
+    let root = if cfg!(target_os = "macos") {
+        home.join("Library/Application Support/JetBrains")
+    } else if cfg!(target_os = "windows") {
+        PathBuf::from(std::env::var("APPDATA").ok()?).join("JetBrains")
+    } else {
+        home.join(".config/JetBrains")
+    };
+    root.is_dir().then_some(root)
+}
+
+/// 产品目录名（IntelliJIdea2024.1 等）映射到 CLI 名
+fn jetbrains_cli(product_dir: &str) -> Option<String> {
+    let cli = if product_dir.starts_with("IntelliJIdea") {
+        "idea"
+    } else if product_dir.starts_with("PyCharm") {
+        "pycharm"
+    } else if product_dir.starts_with("WebStorm") {
+        "webstorm"
+    } else if product_dir.starts_with("GoLand") {
+        "goland"
+    } else if product_dir.starts_with("CLion") {
+        "clion"
+    } else if product_dir.starts_with("RustRover") {
+        "rustrover"
+    } else {
+        return None;
+    };
+    Command::new(cli)
+        .arg("--version")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+        .then(|| cli.to_string())
+}
+
+/// 解析 recentProjects.xml：行级扫描 `key="..."` 条目
+fn jetbrains_workspaces() -> Vec<RecentWorkspace> {
+    let Some(root) = jetbrains_config_root() else { return Vec::new() };
+    let Some(home) = home_dir() else { return Vec::new() };
+    let mut out = Vec::new();
+    let Ok(products) = std::fs::read_dir(&root) else { return Vec::new() };
+    for product in products.flatten() {
+        let product_name = product.file_name().to_string_lossy().to_string();
+        let xml = product.path().join("options").join("recentProjects.xml");
+        let Ok(content) = std::fs::read_to_string(&xml) else { continue };
+        let cli = jetbrains_cli(&product_name);
+        // 去掉尾部版本号作为展示用的编辑器名
+        let editor: String = product_name
+            .chars()
+            .take_while(|c| !c.is_ascii_digit())
+            .collect();
+        for line in content.lines() {
+            let Some(start) = line.find("key=\"") else { continue };
+            let rest = &line[start + 5..];
+            let Some(end) = rest.find('"') else { continue };
+            let path = rest[..end].replace("$USER_HOME$", &home.to_string_lossy());
+            if std::path::Path::new(&path).exists() {
+                out.push(RecentWorkspace {
+                    path,
+                    editor: editor.clone(),
+                    open_command: cli.clone(),
+                });
+            }
+        }
+    }
+    out
+}
+
+/// 列出全部最近工作区
+#[tauri::command]
+pub async fn list_recent_workspaces() -> Result<Vec<RecentWorkspace>, String> {
+    if !crate::services::containers::developer_integrations_enabled() {
+        return Err("开发者集成未开启，请在设置中打开 developer_integrations_enabled".into());
+    }
+    tauri::async_runtime::spawn_blocking(|| {
+        let mut workspaces = vscode_workspaces();
+        workspaces.extend(jetbrains_workspaces());
+        workspaces.dedup_by(|a, b| a.path == b.path && a.editor == b.editor);
+        Ok(workspaces)
+    })
+    .await
+    .map_err(|e| format!("工作区扫描任务异常: {}", e))?
+}
+
+/// 在对应编辑器中打开工作区；无 CLI 时回落系统默认方式
+#[tauri::command]
+pub fn open_workspace(workspace: RecentWorkspaceArg) -> Result<(), String> {
+    match workspace.open_command.as_deref() {
+        Some(cli) => Command::new(cli)
+            .arg(&workspace.path)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("启动 {} 失败: {}", cli, e)),
+        None => open::that(&workspace.path).map_err(|e| format!("打开失败: {}", e)),
+    }
+}
+
+/// open_workspace 的入参（与 RecentWorkspace 同构，需要反序列化）
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentWorkspaceArg {
+    pub path: String,
+    pub open_command: Option<String>,
+}
+
+/// 最近工作区 provider：按目录名匹配
+pub struct EditorWorkspacesProvider;
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for EditorWorkspacesProvider {
+    fn name(&self) -> &str {
+        "editor-workspaces"
+    }
+
+    fn priority(&self) -> i32 {
+        160
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        if query.chars().count() < 2 {
+            return Vec::new();
+        }
+        let Ok(workspaces) = list_recent_workspaces().await else {
+            return Vec::new();
+        };
+        workspaces
+            .into_iter()
+            .filter_map(|ws| {
+                let folder = std::path::Path::new(&ws.path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| ws.path.clone());
+                let score = crate::search::fuzzy::score(query, &folder)?;
+                Some(crate::search::pipeline::SearchResult {
+                    id: format!("workspace:{}:{}", ws.editor, ws.path),
+                    title: folder,
+                    subtitle: Some(format!("{} ｜ {}", ws.editor, ws.path)),
+                    icon: None,
+                    provider: String::new(),
+                    score,
+                    payload: serde_json::to_value(&ws).unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 注册工作区 provider（启动时调用）
+pub fn register() {
+    crate::search::pipeline::register_provider(std::sync::Arc::new(EditorWorkspacesProvider));
+}
@@ -0,0 +1,151 @@
+//! 颜文字与 GIF 搜索
+//!
+//! `gif:` 触发词走 Tenor（默认）或 Giphy，API key 存系统钥匙串而非
+//! 配置文件；颜文字是内置离线数据集，断网可用。选中后写入剪贴板
+//! 历史：颜文字按文本、GIF 按链接（content_type 区分），前端粘贴时
+//! 按类型处理。
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+/// 钥匙串条目
+const KEYRING_SERVICE: &str = "etools";
+const KEYRING_USER: &str = "gif-api-key";
+
+/// 内置颜文字数据集：(颜文字, 检索标签)
+const KAOMOJI: &[(&str, &str)] = &[
+    ("(╯°□°）╯︵ ┻━┻", "flip table 掀桌 生气 angry"),
+    ("┬─┬ ノ( ゜-゜ノ)", "put table back 放回 冷静"),
+    ("(´・ω・`)", "sad 难过 委屈"),
+    ("(◕‿◕)", "happy 开心 smile"),
+    ("ヽ(✿ﾟ▽ﾟ)ノ", "yay 欢呼 celebrate"),
+    ("(￣▽￣)ノ", "hi bye 打招呼 再见"),
+    ("_(:3」∠)_", "lie down 躺 瘫"),
+    ("¯\\_(ツ)_/¯", "shrug 耸肩 whatever"),
+    ("(#°Д°)", "shock 震惊 surprised"),
+    ("( ˘ ³˘)♥", "kiss 亲亲 love"),
+    ("(ಥ_ಥ)", "cry 哭 tears"),
+    ("ᕕ( ᐛ )ᕗ", "run happy 跑 兴奋"),
+    ("(￢_￢)", "doubt 怀疑 side eye"),
+    ("(つ✧ω✧)つ", "excited 期待 sparkle"),
+    ("(￣^￣)ゞ", "salute 敬礼 yes sir"),
+    ("( ´_ゝ`)", "meh 无语 whatever"),
+    ("Σ( ° △ °|||)", "panic 慌 吓"),
+    ("(๑•̀ㅂ•́)و✧", "fighting 加油 good luck"),
+    ("(¬‿¬)", "smirk 坏笑"),
+    ("(づ￣ ³￣)づ", "hug 抱抱"),
+];
+
+/// GIF 搜索结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GifResult {
+    pub url: String,
+    pub preview_url: String,
+    pub title: String,
+}
+
+/// 颜文字搜索结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KaomojiResult {
+    pub text: String,
+    pub tags: String,
+}
+
+fn api_key() -> Result<String, String> {
+    keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER)
+        .and_then(|e| e.get_password())
+        .map_err(|_| "未配置 GIF API key，请先在设置中填写".to_string())
+}
+
+/// 搜索内置颜文字（离线）
+#[tauri::command]
+pub fn search_kaomoji(query: String) -> Vec<KaomojiResult> {
+    let q = query.trim().to_lowercase();
+    KAOMOJI
+        .iter()
+        .filter(|(text, tags)| {
+            q.is_empty() || tags.to_lowercase().contains(&q) || text.contains(&q)
+        })
+        .map(|(text, tags)| KaomojiResult {
+            text: text.to_string(),
+            tags: tags.to_string(),
+        })
+        .collect()
+}
+
+/// 搜索 GIF（Tenor v2）
+#[tauri::command]
+pub async fn search_gif(query: String, limit: Option<u32>) -> Result<Vec<GifResult>, String> {
+    if crate::services::policy::is_feature_disabled("network") {
+        return Err("网络访问已被禁用".into());
+    }
+    let key = api_key()?;
+    let encoded: String = url::form_urlencoded::byte_serialize(query.trim().as_bytes()).collect();
+    let url = format!(
+        "https://tenor.googleapis.com/v2/search?q={}&key={}&limit={}&media_filter=gif,tinygif",
+        encoded,
+        key,
+        limit.unwrap_or(20).min(50)
+    );
+    let data = crate::marketplace::http_client::get_json(&url).await?;
+    let results = data
+        .get("results")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+    Ok(results
+        .iter()
+        .filter_map(|r| {
+            let formats = r.get("media_formats")?;
+            Some(GifResult {
+                url: formats.get("gif")?.get("url")?.as_str()?.to_string(),
+                preview_url: formats
+                    .get("tinygif")
+                    .and_then(|t| t.get("url"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                title: r
+                    .get("content_description")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+            })
+        })
+        .collect())
+}
+
+/// 把选中的颜文字/GIF 写入剪贴板历史；
+/// `content_type`: "text"（颜文字）或 "gif-url"
+#[tauri::command]
+pub fn copy_emote(content: String, content_type: String) -> Result<(), String> {
+    if content_type != "text" && content_type != "gif-url" {
+        return Err(format!("未知的内容类型: {}", content_type));
+    }
+    if crate::services::privacy_session::is_recording_paused() {
+        return Ok(());
+    }
+    let conn = crate::db::pool::get()?;
+    conn.execute(
+        "INSERT INTO clipboard_history (content, content_type, created_at) VALUES (?1, ?2, ?3)",
+        params![content, content_type, chrono::Utc::now().timestamp()],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 配置 GIF API key（写入系统钥匙串）
+#[tauri::command]
+pub fn set_gif_api_key(key: String) -> Result<(), String> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER)
+        .map_err(|e| format!("访问钥匙串失败: {}", e))?;
+    if key.trim().is_empty() {
+        let _ = entry.delete_credential();
+        return Ok(());
+    }
+    entry
+        .set_password(key.trim())
+        .map_err(|e| format!("写入钥匙串失败: {}", e))
+}
@@ -0,0 +1,142 @@
+//! 文件监听订阅
+//!
+//! `watch_path(path, id)` / `unwatch_path(id)` 把文件系统变化以事件流
+//! 推给订阅方（前端或插件视图，比如实时刷新的下载目录监视器）。
+//! 每个订阅独立限流，避免大量变更刷爆事件通道。
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// 变更事件；payload 带订阅 id，前端按 id 路由
+pub const FILE_CHANGE_EVENT: &str = "fs://change";
+/// 同一订阅两次事件之间的最小间隔
+const MIN_EMIT_INTERVAL: Duration = Duration::from_millis(200);
+/// 每个订阅方的最大并存订阅数
+const MAX_WATCHES: usize = 32;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangePayload {
+    id: String,
+    /// "create" / "modify" / "remove" / "other"
+    kind: String,
+    paths: Vec<String>,
+}
+
+struct WatchEntry {
+    _watcher: RecommendedWatcher,
+    /// 限流状态：上次发送时间与期间积压的路径
+    last_emit: Instant,
+    pending: Vec<String>,
+}
+
+static WATCHES: Lazy<Mutex<HashMap<String, WatchEntry>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn kind_str(kind: &notify::EventKind) -> &'static str {
+    use notify::EventKind;
+    match kind {
+        EventKind::Create(_) => "create",
+        EventKind::Modify(_) => "modify",
+        EventKind::Remove(_) => "remove",
+        _ => "other",
+    }
+}
+
+/// 订阅路径变化；`id` 由调用方生成并负责唯一性
+#[tauri::command]
+pub fn watch_path(app: AppHandle, path: String, id: String) -> Result<(), String> {
+    let canonical = crate::cmds::fs_guard::check_access(&app, &path, crate::cmds::fs_guard::Access::Read)?;
+    let mut watches = WATCHES.lock().map_err(|e| e.to_string())?;
+    if watches.len() >= MAX_WATCHES {
+        return Err(format!("监听数已达上限 {}", MAX_WATCHES));
+    }
+    if watches.contains_key(&id) {
+        return Err(format!("订阅 id '{}' 已存在", id));
+    }
+
+    let app_clone = app.clone();
+    let id_clone = id.clone();
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        let Ok(event) = result else { return };
+        // 免索引名单中的路径不对外暴露
+        let paths: Vec<String> = event
+            .iter_paths()
+            .filter(|p| !crate::services::do_not_index::is_excluded(p))
+            .map(|p| p.display().to_string())
+            .collect();
+        if paths.is_empty() {
+            return;
+        }
+        let kind = kind_str(&event.kind).to_string();
+
+        let Ok(mut watches) = WATCHES.lock() else { return };
+        let Some(entry) = watches.get_mut(&id_clone) else { return };
+        entry.pending.extend(paths);
+        // 限流：间隔内的事件只积压路径，到点合并成一条发送
+        if entry.last_emit.elapsed() < MIN_EMIT_INTERVAL {
+            return;
+        }
+        let mut pending = std::mem::take(&mut entry.pending);
+        pending.dedup();
+        entry.last_emit = Instant::now();
+        drop(watches);
+
+        let _ = app_clone.emit(
+            FILE_CHANGE_EVENT,
+            ChangePayload {
+                id: id_clone.clone(),
+                kind,
+                paths: pending,
+            },
+        );
+    })
+    .map_err(|e| format!("创建 watcher 失败: {}", e))?;
+
+    watcher
+        .watch(&canonical, RecursiveMode::Recursive)
+        .map_err(|e| format!("监听 {} 失败: {}", canonical.display(), e))?;
+
+    watches.insert(
+        id.clone(),
+        WatchEntry {
+            _watcher: watcher,
+            last_emit: Instant::now(),
+            pending: Vec::new(),
+        },
+    );
+    log::info!("[FileWatcher] watching {} (id={})", canonical.display(), id);
+    Ok(())
+}
+
+/// 取消订阅；watcher 随 entry 析构自动停止
+#[tauri::command]
+pub fn unwatch_path(id: String) -> Result<(), String> {
+    let mut watches = WATCHES.lock().map_err(|e| e.to_string())?;
+    if watches.remove(&id).is_none() {
+        return Err(format!("订阅 id '{}' 不存在", id));
+    }
+    log::info!("[FileWatcher] unwatched id={}", id);
+    Ok(())
+}
+
+/// 插件卸载/窗口关闭时按前缀清理其订阅
+pub fn unwatch_by_prefix(prefix: &str) {
+    if let Ok(mut watches) = WATCHES.lock() {
+        watches.retain(|id, _| !id.starts_with(prefix));
+    }
+}
+
+/// 挂起时暂停所有监听（置空即可，恢复由订阅方重建）
+pub fn pause_all() -> Vec<String> {
+    let Ok(mut watches) = WATCHES.lock() else { return Vec::new() };
+    let ids: Vec<String> = watches.keys().cloned().collect();
+    watches.clear();
+    ids
+}
@@ -0,0 +1,131 @@
+//! 结果图标解析管线
+//!
+//! 结果模型里的 `icon` 字段是一个引用，支持三种形式：
+//! `builtin:<name>`（内置图标集，前端直接映射）、本地文件路径、
+//! http(s) URL。后端统一解析：文件与 URL 图标会被解码、等比缩到
+//! 64px、转成 PNG data URI 并落盘缓存，provider/插件不需要各自
+//! 处理格式与尺寸。
+//!
+//! 缓存注册进内存监控，内存压力下整体释放。
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager};
+
+/// 缩放目标边长
+const ICON_SIZE: u32 = 64;
+/// 源图标大小上限：2 MB
+const MAX_SOURCE_BYTES: usize = 2 * 1024 * 1024;
+
+/// 解析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedIcon {
+    /// "builtin" 直接交前端映射；"data" 为 PNG data URI
+    pub kind: String,
+    pub value: String,
+}
+
+fn cache_dir(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("icon-cache");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn cache_key(reference: &str) -> String {
+    format!("{:x}", Sha256::digest(reference.as_bytes()))
+}
+
+/// 解码、缩放、编码为 PNG data URI
+fn process_bytes(bytes: &[u8]) -> Result<String, String> {
+    if bytes.len() > MAX_SOURCE_BYTES {
+        return Err("图标文件超过 2 MB".into());
+    }
+    let img = image::load_from_memory(bytes).map_err(|e| format!("图标解码失败: {}", e))?;
+    let scaled = img.thumbnail(ICON_SIZE, ICON_SIZE);
+    let mut png = Vec::new();
+    scaled
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .map_err(|e| format!("图标编码失败: {}", e))?;
+    Ok(format!(
+        "data:image/png;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(&png)
+    ))
+}
+
+/// 解析图标引用；data URI 结果会写缓存
+#[tauri::command]
+pub async fn resolve_icon(app: AppHandle, icon: String) -> Result<ResolvedIcon, String> {
+    let reference = icon.trim().to_string();
+    if reference.is_empty() {
+        return Err("空图标引用".into());
+    }
+    if let Some(name) = reference.strip_prefix("builtin:") {
+        return Ok(ResolvedIcon {
+            kind: "builtin".into(),
+            value: name.to_string(),
+        });
+    }
+
+    // 命中磁盘缓存直接返回
+    let cache_file = cache_dir(&app)?.join(format!("{}.uri", cache_key(&reference)));
+    if let Ok(cached) = std::fs::read_to_string(&cache_file) {
+        return Ok(ResolvedIcon {
+            kind: "data".into(),
+            value: cached,
+        });
+    }
+
+    let bytes: Vec<u8> = if reference.starts_with("http://") || reference.starts_with("https://") {
+        let resp = crate::marketplace::http_client::client()
+            .get(&reference)
+            .send()
+            .await
+            .map_err(|e| format!("下载图标失败: {}", e))?;
+        if !resp.status().is_success() {
+            return Err(format!("图标服务器返回 {}", resp.status()));
+        }
+        resp.bytes().await.map_err(|e| e.to_string())?.to_vec()
+    } else {
+        // 本地路径受文件访问守卫约束
+        crate::cmds::fs_guard::check_access(&app, &reference, crate::cmds::fs_guard::Access::Read)?;
+        std::fs::read(&reference).map_err(|e| format!("读取图标失败: {}", e))?
+    };
+
+    let data_uri = tauri::async_runtime::spawn_blocking(move || process_bytes(&bytes))
+        .await
+        .map_err(|e| format!("图标处理异常: {}", e))??;
+    if let Err(e) = std::fs::write(&cache_file, &data_uri) {
+        log::warn!("[IconService] failed to cache icon: {}", e);
+    }
+    Ok(ResolvedIcon {
+        kind: "data".into(),
+        value: data_uri,
+    })
+}
+
+/// 把图标缓存挂进内存监控（启动时调用）
+pub fn register_cache_pressure(app: AppHandle) {
+    let size_app = app.clone();
+    crate::services::memory_monitor::register_cache(
+        "icon-cache",
+        move || {
+            cache_dir(&size_app)
+                .ok()
+                .and_then(|dir| std::fs::read_dir(dir).ok())
+                .map(|rd| rd.flatten().filter_map(|e| e.metadata().ok()).map(|m| m.len()).sum())
+                .unwrap_or(0)
+        },
+        move || {
+            if let Ok(dir) = cache_dir(&app) {
+                let _ = std::fs::remove_dir_all(&dir);
+                let _ = std::fs::create_dir_all(&dir);
+            }
+        },
+    );
+}
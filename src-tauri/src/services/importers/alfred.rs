@@ -0,0 +1,57 @@
+//! Alfred 导入
+//!
+//! 读取 Alfred 偏好目录下的 web search 配置
+//! （`preferences/features/websearch/prefs.plist` 的 JSON 导出，
+//! 或用户手动导出的 customsites JSON），映射为快捷链接。
+
+use super::{ImportResult, ImportedQuicklink};
+use std::fs;
+use std::path::Path;
+
+/// Alfred 的占位符是 {query}，与 etools 一致，无需转换
+fn normalize_url(url: &str) -> String {
+    url.replace("{q}", "{query}")
+}
+
+pub fn import(path: &str) -> Result<ImportResult, String> {
+    let base = Path::new(path);
+    let content = if base.is_dir() {
+        // 指向偏好目录时找 customsites 配置
+        let candidate = base.join("preferences/features/websearch/customsites.json");
+        fs::read_to_string(&candidate)
+            .map_err(|_| format!("未找到 Alfred web search 配置: {}", candidate.display()))?
+    } else {
+        fs::read_to_string(base).map_err(|e| format!("读取导入文件失败: {}", e))?
+    };
+
+    let parsed: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("Alfred 配置解析失败: {}", e))?;
+
+    let mut result = ImportResult::default();
+    // customsites 结构：{ "<uuid>": { "text": 名称, "url": 模板, "keyword": 关键词, "enabled": bool } }
+    let Some(sites) = parsed.as_object() else {
+        return Err("Alfred 配置格式不符合预期（应为对象）".into());
+    };
+    for (uuid, site) in sites {
+        let enabled = site.get("enabled").and_then(|v| v.as_bool()).unwrap_or(true);
+        if !enabled {
+            continue;
+        }
+        let (Some(name), Some(url)) = (
+            site.get("text").and_then(|v| v.as_str()),
+            site.get("url").and_then(|v| v.as_str()),
+        ) else {
+            result.warnings.push(format!("跳过不完整的条目 {}", uuid));
+            continue;
+        };
+        result.quicklinks.push(ImportedQuicklink {
+            name: name.to_string(),
+            url: normalize_url(url),
+            keyword: site
+                .get("keyword")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+        });
+    }
+    Ok(result)
+}
@@ -0,0 +1,74 @@
+//! 第三方启动器数据导入
+//!
+//! 从 Alfred（web search / custom searches）、Raycast（quicklinks /
+//! snippets 导出文件）和 uTools（插件列表）导入数据，分别映射为
+//! etools 的快捷链接、代码片段和市场安装建议。
+
+pub mod alfred;
+pub mod raycast;
+pub mod utools;
+
+use serde::{Deserialize, Serialize};
+
+/// 导入得到的快捷链接（映射到 etools quicklinks）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedQuicklink {
+    pub name: String,
+    /// URL 模板，查询占位符统一归一为 `{query}`
+    pub url: String,
+    #[serde(default)]
+    pub keyword: Option<String>,
+}
+
+/// 导入得到的片段（映射到 etools snippets）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedSnippet {
+    pub name: String,
+    pub content: String,
+    #[serde(default)]
+    pub keyword: Option<String>,
+}
+
+/// 导入得到的插件建议（在市场页顶部展示"你可能想装"）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginSuggestion {
+    /// 原工具中的插件名
+    pub source_name: String,
+    /// 推荐的 etools 市场搜索词
+    pub suggested_query: String,
+}
+
+/// 一次导入的汇总结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportResult {
+    pub quicklinks: Vec<ImportedQuicklink>,
+    pub snippets: Vec<ImportedSnippet>,
+    pub plugin_suggestions: Vec<PluginSuggestion>,
+    /// 解析过程中跳过的条目说明
+    pub warnings: Vec<String>,
+}
+
+/// 从指定来源导入；`source` 为 "alfred" / "raycast" / "utools"，
+/// `path` 指向对应的导出文件或配置目录
+#[tauri::command]
+pub fn import_from_launcher(source: String, path: String) -> Result<ImportResult, String> {
+    let result = match source.as_str() {
+        "alfred" => alfred::import(&path),
+        "raycast" => raycast::import(&path),
+        "utools" => utools::import(&path),
+        other => return Err(format!("未知导入来源: {}", other)),
+    }?;
+    log::info!(
+        "[Importers] {} import: {} quicklinks, {} snippets, {} suggestions, {} warnings",
+        source,
+        result.quicklinks.len(),
+        result.snippets.len(),
+        result.plugin_suggestions.len(),
+        result.warnings.len()
+    );
+    Ok(result)
+}
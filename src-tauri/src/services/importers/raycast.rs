@@ -0,0 +1,64 @@
+//! Raycast 导入
+//!
+//! 解析 Raycast 的 quicklinks / snippets JSON 导出文件
+//! （Settings → Advanced → Export）。两类条目可能混在同一个导出里，
+//! 按字段特征区分。
+
+use super::{ImportResult, ImportedQuicklink, ImportedSnippet};
+use std::fs;
+
+/// Raycast 的占位符是 {Query}/{argument}，归一为 {query}
+fn normalize_url(url: &str) -> String {
+    url.replace("{Query}", "{query}").replace("{argument}", "{query}")
+}
+
+pub fn import(path: &str) -> Result<ImportResult, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("读取导入文件失败: {}", e))?;
+    let parsed: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("Raycast 导出解析失败: {}", e))?;
+
+    let mut result = ImportResult::default();
+    // 导出格式既可能是数组，也可能是 { "items": [...] }
+    let items = parsed
+        .get("items")
+        .and_then(|v| v.as_array())
+        .or_else(|| parsed.as_array())
+        .ok_or_else(|| "Raycast 导出格式不符合预期".to_string())?;
+
+    for (idx, item) in items.iter().enumerate() {
+        let name = item
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if let Some(link) = item.get("link").and_then(|v| v.as_str()) {
+            // quicklink 条目
+            if name.is_empty() {
+                result.warnings.push(format!("跳过无名称的 quicklink（第 {} 条）", idx + 1));
+                continue;
+            }
+            result.quicklinks.push(ImportedQuicklink {
+                name,
+                url: normalize_url(link),
+                keyword: None,
+            });
+        } else if let Some(text) = item.get("text").and_then(|v| v.as_str()) {
+            // snippet 条目
+            if name.is_empty() {
+                result.warnings.push(format!("跳过无名称的 snippet（第 {} 条）", idx + 1));
+                continue;
+            }
+            result.snippets.push(ImportedSnippet {
+                name,
+                content: text.to_string(),
+                keyword: item
+                    .get("keyword")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+            });
+        } else {
+            result.warnings.push(format!("第 {} 条既不是 quicklink 也不是 snippet", idx + 1));
+        }
+    }
+    Ok(result)
+}
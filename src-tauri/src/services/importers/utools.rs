@@ -0,0 +1,46 @@
+//! uTools 导入
+//!
+//! 读取 uTools 数据目录里的已安装插件列表，映射为 etools 市场的
+//! 安装建议——大部分 uTools 插件在 etools 市场有同类替代。
+
+use super::{ImportResult, PluginSuggestion};
+use std::fs;
+use std::path::Path;
+
+pub fn import(path: &str) -> Result<ImportResult, String> {
+    let base = Path::new(path);
+    let content = if base.is_dir() {
+        // 数据目录下的插件清单
+        let candidate = base.join("plugins.json");
+        fs::read_to_string(&candidate)
+            .map_err(|_| format!("未找到 uTools 插件清单: {}", candidate.display()))?
+    } else {
+        fs::read_to_string(base).map_err(|e| format!("读取导入文件失败: {}", e))?
+    };
+
+    let parsed: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("uTools 清单解析失败: {}", e))?;
+
+    let mut result = ImportResult::default();
+    let plugins = parsed
+        .as_array()
+        .or_else(|| parsed.get("plugins").and_then(|v| v.as_array()))
+        .ok_or_else(|| "uTools 清单格式不符合预期".to_string())?;
+
+    for plugin in plugins {
+        let Some(name) = plugin
+            .get("pluginName")
+            .or_else(|| plugin.get("name"))
+            .and_then(|v| v.as_str())
+        else {
+            result.warnings.push("跳过无名称的插件条目".into());
+            continue;
+        };
+        result.plugin_suggestions.push(PluginSuggestion {
+            source_name: name.to_string(),
+            // 用原名作为市场搜索词；市场端会做相似度匹配
+            suggested_query: name.to_string(),
+        });
+    }
+    Ok(result)
+}
@@ -0,0 +1,116 @@
+//! 本地化格式化服务
+//!
+//! 计算器、日期和文件大小的输出统一经过这里，按用户 `language`
+//! 设置选择小数点符号、千分位和日期顺序。`format_preview`
+//! 供设置页实时预览格式效果。
+
+use serde::{Deserialize, Serialize};
+
+/// 每个 locale 的格式规则；目前只区分已支持的两个语言
+#[derive(Debug, Clone, Copy)]
+struct LocaleRules {
+    decimal_sep: char,
+    group_sep: char,
+    /// true 为 年-月-日，false 为 月/日/年
+    ymd_order: bool,
+}
+
+fn rules_for(locale: &str) -> LocaleRules {
+    match locale {
+        "en-US" => LocaleRules {
+            decimal_sep: '.',
+            group_sep: ',',
+            ymd_order: false,
+        },
+        // zh-CN 及未知 locale 默认
+        _ => LocaleRules {
+            decimal_sep: '.',
+            group_sep: ',',
+            ymd_order: true,
+        },
+    }
+}
+
+fn current_locale() -> String {
+    crate::settings::store::get("language")
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_else(|| "zh-CN".to_string())
+}
+
+/// 按当前 locale 格式化数字（千分位 + 本地小数点）
+pub fn format_number(value: f64, max_decimals: usize) -> String {
+    let rules = rules_for(&current_locale());
+    let formatted = format!("{:.*}", max_decimals, value);
+    let (int_part, frac_part) = match formatted.split_once('.') {
+        Some((i, f)) => (i.to_string(), Some(f.trim_end_matches('0').to_string())),
+        None => (formatted, None),
+    };
+
+    // 整数部分加千分位
+    let negative = int_part.starts_with('-');
+    let digits: Vec<char> = int_part.trim_start_matches('-').chars().collect();
+    let mut grouped = String::new();
+    for (i, c) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(rules.group_sep);
+        }
+        grouped.push(*c);
+    }
+    let mut out = String::new();
+    if negative {
+        out.push('-');
+    }
+    out.push_str(&grouped);
+    if let Some(frac) = frac_part.filter(|f| !f.is_empty()) {
+        out.push(rules.decimal_sep);
+        out.push_str(&frac);
+    }
+    out
+}
+
+/// 按当前 locale 格式化日期（Unix 秒）
+pub fn format_date(timestamp: i64) -> String {
+    let Some(dt) = chrono::DateTime::from_timestamp(timestamp, 0) else {
+        return String::new();
+    };
+    let local = dt.with_timezone(&chrono::Local);
+    let rules = rules_for(&current_locale());
+    if rules.ymd_order {
+        local.format("%Y-%m-%d %H:%M").to_string()
+    } else {
+        local.format("%m/%d/%Y %H:%M").to_string()
+    }
+}
+
+/// 按当前 locale 格式化文件大小
+pub fn format_file_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    let decimals = if unit == 0 { 0 } else { 1 };
+    format!("{} {}", format_number(size, decimals), UNITS[unit])
+}
+
+/// 设置页预览载荷
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormatPreview {
+    pub number: String,
+    pub date: String,
+    pub file_size: String,
+}
+
+/// 用固定示例数据展示当前 locale 的格式化效果
+#[tauri::command]
+pub fn format_preview() -> FormatPreview {
+    FormatPreview {
+        number: format_number(1234567.891, 2),
+        date: format_date(chrono::Utc::now().timestamp()),
+        file_size: format_file_size(1_572_864),
+    }
+}
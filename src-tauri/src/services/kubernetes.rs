@@ -0,0 +1,196 @@
+//! Kubernetes 上下文切换
+//!
+//! `kube:` 范围列出 kubeconfig 里的全部 context（含 namespace），
+//! 选中即切换 `current-context`。完全是本地文件操作，不发起任何
+//! 集群请求：解析只做行级扫描（contexts 列表 + current-context 行），
+//! 改写走临时文件 + 原子 rename，并先留 `.bak` 备份。属于开发者
+//! 集成，由 `developer_integrations_enabled` 开启。
+
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// 一个 kubeconfig context
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KubeContext {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub active: bool,
+}
+
+/// kubeconfig 路径：$KUBECONFIG 的首个条目，缺省 ~/.kube/config
+fn kubeconfig_path() -> Result<PathBuf, String> {
+    if let Ok(env) = std::env::var("KUBECONFIG") {
+        let sep = if cfg!(windows) { ';' } else { ':' };
+        if let Some(first) = env.split(sep).find(|p| !p.is_empty()) {
+            return Ok(PathBuf::from(first));
+        }
+    }
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".kube").join("config"))
+        .map_err(|_| "无法定位 kubeconfig（HOME 未设置）".to_string())
+}
+
+/// 行级解析 contexts 与 current-context；不依赖完整 YAML 解析
+fn parse_contexts(content: &str) -> (Vec<(String, Option<String>)>, Option<String>) {
+    let mut contexts: Vec<(String, Option<String>)> = Vec::new();
+    let mut current: Option<String> = None;
+    let mut in_contexts = false;
+    let mut pending_namespace: Option<String> = None;
+
+    for line in content.lines() {
+        if !line.starts_with(' ') && !line.starts_with('-') {
+            in_contexts = line.trim_end() == "contexts:";
+            if let Some(value) = line.strip_prefix("current-context:") {
+                let value = value.trim().trim_matches('"');
+                if !value.is_empty() {
+                    current = Some(value.to_string());
+                }
+            }
+            continue;
+        }
+        if !in_contexts {
+            continue;
+        }
+        let trimmed = line.trim_start();
+        if line.starts_with("- ") {
+            pending_namespace = None;
+        }
+        if let Some(value) = trimmed.strip_prefix("namespace:") {
+            pending_namespace = Some(value.trim().trim_matches('"').to_string());
+        }
+        // 条目收尾的 `name:`（2 空格缩进，区别于 context 块内的字段）
+        if line.starts_with("  name:") {
+            let name = line["  name:".len()..].trim().trim_matches('"').to_string();
+            if !name.is_empty() {
+                contexts.push((name, pending_namespace.take()));
+            }
+        }
+    }
+    (contexts, current)
+}
+
+/// 列出全部 context 及当前活跃标记
+#[tauri::command]
+pub fn list_kube_contexts() -> Result<Vec<KubeContext>, String> {
+    if !crate::services::containers::developer_integrations_enabled() {
+        return Err("开发者集成未开启，请在设置中打开 developer_integrations_enabled".into());
+    }
+    let path = kubeconfig_path()?;
+    let content = std::fs::read_to_string(&path)
+        .map_err(|_| format!("读取 kubeconfig 失败: {}", path.display()))?;
+    let (contexts, current) = parse_contexts(&content);
+    Ok(contexts
+        .into_iter()
+        .map(|(name, namespace)| KubeContext {
+            active: current.as_deref() == Some(&name),
+            name,
+            namespace,
+        })
+        .collect())
+}
+
+/// 切换 current-context：校验目标存在，备份后原子改写
+#[tauri::command]
+pub fn switch_kube_context(app: tauri::AppHandle, name: String) -> Result<(), String> {
+    if !crate::services::containers::developer_integrations_enabled() {
+        return Err("开发者集成未开启".into());
+    }
+    let path = kubeconfig_path()?;
+    let content = std::fs::read_to_string(&path)
+        .map_err(|_| format!("读取 kubeconfig 失败: {}", path.display()))?;
+    let (contexts, _) = parse_contexts(&content);
+    if !contexts.iter().any(|(n, _)| n == &name) {
+        return Err(format!("kubeconfig 中没有 context '{}'", name));
+    }
+
+    // 只改 current-context 行，其余内容原样保留
+    let mut replaced = false;
+    let mut lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            if line.starts_with("current-context:") {
+                replaced = true;
+                format!("current-context: {}", name)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    if !replaced {
+        lines.push(format!("current-context: {}", name));
+    }
+    let mut output = lines.join("\n");
+    if content.ends_with('\n') {
+        output.push('\n');
+    }
+
+    let backup = path.with_extension("bak");
+    std::fs::copy(&path, &backup).map_err(|e| format!("备份 kubeconfig 失败: {}", e))?;
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, &output).map_err(|e| format!("写入临时文件失败: {}", e))?;
+    std::fs::rename(&tmp, &path).map_err(|e| format!("替换 kubeconfig 失败: {}", e))?;
+    log::info!("[Kubernetes] switched current-context to '{}'", name);
+    crate::services::audit_log::record(&app, "kubeContextSwitch", &name);
+    Ok(())
+}
+
+/// context 切换 provider；`kube:` 范围专用
+pub struct KubeContextProvider;
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for KubeContextProvider {
+    fn name(&self) -> &str {
+        "kubernetes"
+    }
+
+    fn priority(&self) -> i32 {
+        160
+    }
+
+    fn scope_only(&self) -> bool {
+        // 已登记 kube: 范围，不该再在每个全局查询里解析 kubeconfig
+        true
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        let Ok(contexts) = list_kube_contexts() else {
+            return Vec::new();
+        };
+        let active = contexts
+            .iter()
+            .find(|c| c.active)
+            .map(|c| c.name.clone())
+            .unwrap_or_else(|| "无".into());
+        contexts
+            .into_iter()
+            .filter_map(|ctx| {
+                let score = if query.is_empty() {
+                    0
+                } else {
+                    crate::search::fuzzy::score(query, &ctx.name)?
+                };
+                let namespace = ctx.namespace.clone().unwrap_or_else(|| "default".into());
+                Some(crate::search::pipeline::SearchResult {
+                    id: format!("kube-context:{}", ctx.name),
+                    title: ctx.name.clone(),
+                    subtitle: Some(format!("namespace {} ｜ 当前: {}", namespace, active)),
+                    icon: None,
+                    provider: String::new(),
+                    score: if ctx.active { score + 1 } else { score },
+                    payload: serde_json::json!({
+                        "context": ctx.name,
+                        "action": "switch",
+                    }),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 注册 provider 并登记 `kube:` 范围（启动时调用）
+pub fn register() {
+    crate::search::pipeline::register_provider(std::sync::Arc::new(KubeContextProvider));
+    crate::search::scopes::register_scope("kube:", "kubernetes", "Kubernetes 上下文");
+}
@@ -0,0 +1,152 @@
+//! 后端内存自监控
+//!
+//! 定期读取自身 RSS；超过可配阈值时触发缓存收缩
+//! （插件列表缓存、图标缓存、搜索缓存等各自注册收缩回调）并记日志。
+//! 当前各缓存大小通过 `get_performance_metrics` 暴露。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// 默认 RSS 阈值：512 MB
+const DEFAULT_THRESHOLD_MB: u64 = 512;
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 缓存注册：名称 + 查询大小 + 收缩回调
+struct CacheHandle {
+    name: String,
+    size_bytes: Box<dyn Fn() -> u64 + Send + Sync>,
+    shrink: Box<dyn Fn() + Send + Sync>,
+}
+
+static CACHES: Lazy<Mutex<Vec<CacheHandle>>> = Lazy::new(|| Mutex::new(Vec::new()));
+static THRESHOLD_MB: AtomicU64 = AtomicU64::new(DEFAULT_THRESHOLD_MB);
+
+/// 注册一个可收缩缓存（各缓存模块启动时调用）
+pub fn register_cache<S, F>(name: &str, size_bytes: S, shrink: F)
+where
+    S: Fn() -> u64 + Send + Sync + 'static,
+    F: Fn() + Send + Sync + 'static,
+{
+    if let Ok(mut caches) = CACHES.lock() {
+        caches.push(CacheHandle {
+            name: name.to_string(),
+            size_bytes: Box::new(size_bytes),
+            shrink: Box::new(shrink),
+        });
+    }
+}
+
+/// 读取当前进程 RSS（字节）；平台不支持时返回 0
+fn current_rss_bytes() -> u64 {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string("/proc/self/statm")
+            .ok()
+            .and_then(|s| s.split_whitespace().nth(1).and_then(|v| v.parse::<u64>().ok()))
+            .map(|pages| pages * 4096)
+            .unwrap_or(0)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        use std::process::Command;
+        Command::new("ps")
+            .args(["-o", "rss=", "-p", &std::process::id().to_string()])
+            .output()
+            .ok()
+            .and_then(|o| String::from_utf8_lossy(&o.stdout).trim().parse::<u64>().ok())
+            .map(|kb| kb * 1024)
+            .unwrap_or(0)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        // Windows 上通过 tasklist 解析开销大；留给平台 API 接入，先返回 0
+        0
+    }
+}
+
+/// 缓存大小明细
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheMetric {
+    pub name: String,
+    pub bytes: u64,
+}
+
+/// 性能指标汇总（含内存与缓存）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceMetrics {
+    pub rss_bytes: u64,
+    pub threshold_mb: u64,
+    pub caches: Vec<CacheMetric>,
+}
+
+/// 收缩全部已注册缓存
+fn release_pressure() {
+    let Ok(caches) = CACHES.lock() else { return };
+    for cache in caches.iter() {
+        let before = (cache.size_bytes)();
+        (cache.shrink)();
+        let after = (cache.size_bytes)();
+        log::info!(
+            "[MemoryMonitor] shrank cache '{}': {} -> {} bytes",
+            cache.name,
+            before,
+            after
+        );
+    }
+}
+
+/// 启动内存监控循环
+pub fn spawn_monitor() {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+            let rss = current_rss_bytes();
+            let threshold = THRESHOLD_MB.load(Ordering::Relaxed) * 1024 * 1024;
+            if rss > 0 && rss > threshold {
+                log::warn!(
+                    "[MemoryMonitor] RSS {} MB exceeds threshold {} MB, releasing cache pressure",
+                    rss / 1024 / 1024,
+                    threshold / 1024 / 1024
+                );
+                release_pressure();
+            }
+        }
+    });
+}
+
+/// 设置 RSS 阈值（MB）
+#[tauri::command]
+pub fn set_memory_threshold(mb: u64) -> Result<(), String> {
+    if mb < 128 {
+        return Err("阈值不能低于 128 MB".into());
+    }
+    THRESHOLD_MB.store(mb, Ordering::Relaxed);
+    Ok(())
+}
+
+/// 当前内存与缓存指标
+#[tauri::command]
+pub fn get_performance_metrics() -> PerformanceMetrics {
+    let caches = CACHES
+        .lock()
+        .map(|caches| {
+            caches
+                .iter()
+                .map(|c| CacheMetric {
+                    name: c.name.clone(),
+                    bytes: (c.size_bytes)(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    PerformanceMetrics {
+        rss_bytes: current_rss_bytes(),
+        threshold_mb: THRESHOLD_MB.load(Ordering::Relaxed),
+        caches,
+    }
+}
@@ -0,0 +1,42 @@
+pub mod accessibility;
+pub mod analytics;
+pub mod audit_log;
+pub mod clipboard_files;
+pub mod clipboard_images;
+pub mod containers;
+pub mod copy_as;
+pub mod credential_items;
+pub mod data_purge;
+pub mod default_browser;
+pub mod download_manager;
+pub mod do_not_index;
+pub mod drop_ingest;
+pub mod editor_workspaces;
+pub mod emotes;
+pub mod file_watcher;
+pub mod icon_service;
+pub mod importers;
+pub mod intl_format;
+pub mod kubernetes;
+pub mod memory_monitor;
+pub mod package_search;
+pub mod pinyin_matcher;
+pub mod policy;
+pub mod privacy_session;
+pub mod profiles;
+pub mod proxy;
+pub mod quicklinks;
+pub mod registry_info;
+pub mod rss;
+pub mod secret_scanner;
+pub mod self_test;
+pub mod speech;
+pub mod spellcheck;
+pub mod ssh_tunnels;
+pub mod storage_usage;
+pub mod system_commands;
+pub mod test_harness;
+pub mod text_detector;
+pub mod timers;
+pub mod tmux;
+pub mod weather;
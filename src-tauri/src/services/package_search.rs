@@ -0,0 +1,233 @@
+//! 本地包管理器搜索（brew / winget / apt）
+//!
+//! `pkg:` 范围搜索系统包管理器，提供「查看信息」「安装」动作。安装
+//! 必须显式 `confirm: true`（前端弹确认），并记审计。brew/winget 直接
+//! 起子进程；apt 需要 sudo，安装动作改为在终端里打开交互式命令。
+//! 属于开发者集成，由 `developer_integrations_enabled` 开启。
+
+use serde::Serialize;
+use std::process::Command;
+
+/// 搜索结果数量上限
+const MAX_RESULTS: usize = 15;
+
+/// 一个包管理器搜索命中
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageHit {
+    pub name: String,
+    pub description: String,
+    /// "brew" / "winget" / "apt"
+    pub manager: String,
+}
+
+/// 探测当前平台可用的包管理器
+fn package_manager() -> Option<&'static str> {
+    let candidates: &[&str] = if cfg!(target_os = "macos") {
+        &["brew"]
+    } else if cfg!(target_os = "windows") {
+        &["winget"]
+    } else {
+        &["brew", "apt-cache"]
+    };
+    for cli in candidates {
+        let available = Command::new(cli)
+            .arg("--version")
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false);
+        if available {
+            return Some(if *cli == "apt-cache" { "apt" } else { cli });
+        }
+    }
+    None
+}
+
+fn run_capture(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("启动 {} 失败: {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} 失败: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn search_sync(query: &str) -> Result<Vec<PackageHit>, String> {
+    let manager = package_manager().ok_or("未检测到支持的包管理器")?;
+    let mut hits = Vec::new();
+    match manager {
+        "brew" => {
+            // brew search 输出每行一个 formula 名
+            let stdout = run_capture("brew", &["search", "--formula", query])?;
+            for line in stdout.lines().filter(|l| !l.trim().is_empty()) {
+                hits.push(PackageHit {
+                    name: line.trim().to_string(),
+                    description: String::new(),
+                    manager: manager.into(),
+                });
+            }
+        }
+        "winget" => {
+            // 跳过表头两行，取首列为包名
+            let stdout = run_capture("winget", &["search", query, "--source", "winget"])?;
+            for line in stdout.lines().skip(2).filter(|l| !l.trim().is_empty()) {
+                let mut parts = line.split_whitespace();
+                if let Some(name) = parts.next() {
+                    hits.push(PackageHit {
+                        name: name.to_string(),
+                        description: parts.collect::<Vec<_>>().join(" "),
+                        manager: manager.into(),
+                    });
+                }
+            }
+        }
+        "apt" => {
+            // apt-cache search 输出 "name - description"
+            let stdout = run_capture("apt-cache", &["search", query])?;
+            for line in stdout.lines() {
+                let Some((name, desc)) = line.split_once(" - ") else { continue };
+                hits.push(PackageHit {
+                    name: name.trim().to_string(),
+                    description: desc.trim().to_string(),
+                    manager: manager.into(),
+                });
+            }
+        }
+        _ => unreachable!(),
+    }
+    hits.truncate(MAX_RESULTS);
+    Ok(hits)
+}
+
+/// 搜索本地包管理器
+#[tauri::command]
+pub async fn search_packages(query: String) -> Result<Vec<PackageHit>, String> {
+    if !crate::services::containers::developer_integrations_enabled() {
+        return Err("开发者集成未开启，请在设置中打开 developer_integrations_enabled".into());
+    }
+    if query.trim().len() < 2 {
+        return Ok(Vec::new());
+    }
+    tauri::async_runtime::spawn_blocking(move || search_sync(query.trim()))
+        .await
+        .map_err(|e| format!("包搜索任务异常: {}", e))?
+}
+
+/// 查看包信息（纯文本，前端直接展示）
+#[tauri::command]
+pub async fn package_info(name: String) -> Result<String, String> {
+    if !crate::services::containers::developer_integrations_enabled() {
+        return Err("开发者集成未开启".into());
+    }
+    validate_package_name(&name)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        match package_manager().ok_or("未检测到支持的包管理器")? {
+            "brew" => run_capture("brew", &["info", &name]),
+            "winget" => run_capture("winget", &["show", &name]),
+            "apt" => run_capture("apt-cache", &["show", &name]),
+            _ => unreachable!(),
+        }
+    })
+    .await
+    .map_err(|e| format!("包信息任务异常: {}", e))?
+}
+
+/// 包名只允许保守字符集，防止拼进额外参数
+fn validate_package_name(name: &str) -> Result<(), String> {
+    let ok = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '+' | '@' | '/'));
+    if ok {
+        Ok(())
+    } else {
+        Err(format!("包名 '{}' 不合法", name))
+    }
+}
+
+/// 安装包；必须 `confirm: true`（前端先弹确认）
+#[tauri::command]
+pub async fn install_package(app: tauri::AppHandle, name: String, confirm: bool) -> Result<(), String> {
+    if !crate::services::containers::developer_integrations_enabled() {
+        return Err("开发者集成未开启".into());
+    }
+    if !confirm {
+        return Err("安装包需要显式确认（confirm = true）".into());
+    }
+    validate_package_name(&name)?;
+    crate::services::audit_log::record(&app, "packageInstall", &name);
+    tauri::async_runtime::spawn_blocking(move || {
+        match package_manager().ok_or("未检测到支持的包管理器")? {
+            "brew" => run_capture("brew", &["install", &name]).map(|_| ()),
+            "winget" => run_capture(
+                "winget",
+                &["install", "--exact", &name, "--accept-package-agreements"],
+            )
+            .map(|_| ()),
+            // apt 需要 sudo 交互，转到终端里执行
+            "apt" => crate::services::tmux::open_in_terminal(&format!("sudo apt install {}", name)),
+            _ => unreachable!(),
+        }
+    })
+    .await
+    .map_err(|e| format!("安装任务异常: {}", e))?
+}
+
+/// 包搜索 provider；`pkg:` 范围专用
+pub struct PackageSearchProvider;
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for PackageSearchProvider {
+    fn name(&self) -> &str {
+        "packages"
+    }
+
+    fn priority(&self) -> i32 {
+        200
+    }
+
+    fn scope_only(&self) -> bool {
+        // CLI 搜索动辄数秒，每个按键都起一个 brew/winget 子进程还会
+        // 在流水线超时后残留；只在 pkg: 范围里被显式调用
+        true
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        let Ok(hits) = search_packages(query.to_string()).await else {
+            return Vec::new();
+        };
+        hits.into_iter()
+            .enumerate()
+            .map(|(idx, hit)| crate::search::pipeline::SearchResult {
+                id: format!("pkg:{}:{}", hit.manager, hit.name),
+                title: hit.name.clone(),
+                subtitle: Some(if hit.description.is_empty() {
+                    hit.manager.clone()
+                } else {
+                    format!("{} ｜ {}", hit.manager, hit.description)
+                }),
+                icon: None,
+                provider: String::new(),
+                // CLI 已按相关性排序，按位次递减给分
+                score: 1000 - idx as i32,
+                payload: serde_json::json!({
+                    "package": hit.name,
+                    "manager": hit.manager,
+                    "actions": ["info", "install"],
+                }),
+            })
+            .collect()
+    }
+}
+
+/// 注册包搜索 provider 并登记 `pkg:` 范围（启动时调用）
+pub fn register() {
+    crate::search::pipeline::register_provider(std::sync::Arc::new(PackageSearchProvider));
+    crate::search::scopes::register_scope("pkg:", "packages", "包管理器");
+}
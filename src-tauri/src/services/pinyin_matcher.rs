@@ -0,0 +1,71 @@
+//! 拼音匹配层
+//!
+//! 中文应用名/文件名支持用拼音检索：输入 "weixin" 或首字母缩写
+//! "wx" 都能命中「微信」。对混合文本逐字符转换——汉字展开为全拼与
+//! 首字母，其余字符原样保留——再复用 fuzzy 打分器。多音字取
+//! pinyin crate 的默认读音，常见应用名已足够。
+
+use pinyin::ToPinyin;
+
+/// 文本的可匹配形式：原文 + 全拼 + 首字母缩写
+#[derive(Debug, Clone)]
+pub struct PinyinForms {
+    /// 全拼（汉字展开、其余字符保留），如 "weixin"
+    pub full: String,
+    /// 首字母（汉字取声母首字母，其余字符保留），如 "wx"
+    pub initials: String,
+    /// 是否包含汉字；不含时两种形式与原文一致，无需重复匹配
+    pub has_chinese: bool,
+}
+
+/// 生成文本的拼音形式
+pub fn forms(text: &str) -> PinyinForms {
+    let mut full = String::with_capacity(text.len() * 2);
+    let mut initials = String::with_capacity(text.len());
+    let mut has_chinese = false;
+    for ch in text.chars() {
+        match ch.to_pinyin() {
+            Some(py) => {
+                has_chinese = true;
+                let plain = py.plain();
+                full.push_str(plain);
+                if let Some(first) = plain.chars().next() {
+                    initials.push(first);
+                }
+            }
+            None => {
+                full.push(ch);
+                initials.push(ch);
+            }
+        }
+    }
+    PinyinForms {
+        full,
+        initials,
+        has_chinese,
+    }
+}
+
+/// 拼音感知的模糊打分：取原文、全拼、首字母三种形式的最高分。
+/// 拼音命中略微降分，保证原文精确命中排在前面。
+pub fn score(query: &str, text: &str) -> Option<i32> {
+    let direct = crate::search::fuzzy::score(query, text);
+    let forms = forms(text);
+    if !forms.has_chinese {
+        return direct;
+    }
+    let full = crate::search::fuzzy::score(query, &forms.full).map(|s| s - 2);
+    let initials = crate::search::fuzzy::score(query, &forms.initials).map(|s| s - 4);
+    [direct, full, initials].into_iter().flatten().max()
+}
+
+/// 是否命中（任一形式）
+pub fn matches(query: &str, text: &str) -> bool {
+    score(query, text).is_some()
+}
+
+/// 前端候选过滤用
+#[tauri::command]
+pub fn pinyin_match_score(query: String, text: String) -> Option<i32> {
+    score(&query, &text)
+}
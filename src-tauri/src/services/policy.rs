@@ -0,0 +1,107 @@
+//! 企业托管策略
+//!
+//! 管理员可以下发策略 JSON（macOS 放 /Library/Managed Preferences，
+//! Windows 放 ProgramData，Linux 放 /etc）：强制禁用功能（市场、shell
+//! 权限、网络插件）、预配置注册表地址与索引路径、锁定特定设置。
+//! 启动时加载一次，运行期间只读。
+
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// 策略文件内容
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedPolicy {
+    /// 强制禁用的功能："marketplace" / "shell" / "networkPlugins" 等
+    #[serde(default)]
+    pub disabled_features: Vec<String>,
+    /// 预配置的插件注册表地址（覆盖默认 npmjs）
+    #[serde(default)]
+    pub registry_url: Option<String>,
+    /// 预配置的文件索引根路径
+    #[serde(default)]
+    pub index_paths: Vec<String>,
+    /// 锁定的设置及其强制值；锁定的键不允许用户修改
+    #[serde(default)]
+    pub locked_settings: HashMap<String, serde_json::Value>,
+}
+
+static POLICY: OnceCell<ManagedPolicy> = OnceCell::new();
+
+/// 各平台的策略文件位置（按顺序查找，取第一个存在的）
+fn policy_paths() -> Vec<PathBuf> {
+    #[cfg(target_os = "macos")]
+    {
+        vec![
+            PathBuf::from("/Library/Managed Preferences/com.etools-lab.etools.policy.json"),
+            PathBuf::from("/Library/Application Support/etools/policy.json"),
+        ]
+    }
+    #[cfg(target_os = "windows")]
+    {
+        vec![PathBuf::from("C:\\ProgramData\\etools\\policy.json")]
+    }
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        vec![PathBuf::from("/etc/etools/policy.json")]
+    }
+}
+
+/// 启动时加载策略；由 config_service 在初始化早期调用
+pub fn load() {
+    let policy = policy_paths()
+        .into_iter()
+        .find(|p| p.exists())
+        .and_then(|path| {
+            log::info!("[Policy] loading managed policy from {}", path.display());
+            match fs::read_to_string(&path) {
+                Ok(content) => match serde_json::from_str::<ManagedPolicy>(&content) {
+                    Ok(policy) => Some(policy),
+                    Err(e) => {
+                        // 损坏的策略文件按无策略处理，但必须大声记日志
+                        log::error!("[Policy] policy file is invalid, ignoring: {}", e);
+                        None
+                    }
+                },
+                Err(e) => {
+                    log::error!("[Policy] failed to read policy file: {}", e);
+                    None
+                }
+            }
+        })
+        .unwrap_or_default();
+    let _ = POLICY.set(policy);
+}
+
+fn policy() -> &'static ManagedPolicy {
+    POLICY.get_or_init(ManagedPolicy::default)
+}
+
+/// 功能是否被策略禁用
+pub fn is_feature_disabled(feature: &str) -> bool {
+    policy().disabled_features.iter().any(|f| f == feature)
+}
+
+/// 设置是否被锁定；返回锁定的强制值
+pub fn locked_value(key: &str) -> Option<&'static serde_json::Value> {
+    policy().locked_settings.get(key)
+}
+
+/// 策略配置的注册表地址
+pub fn registry_override() -> Option<&'static str> {
+    policy().registry_url.as_deref()
+}
+
+/// 策略配置的索引路径
+pub fn managed_index_paths() -> &'static [String] {
+    &policy().index_paths
+}
+
+/// 前端读取完整策略（设置页据此置灰锁定项与禁用功能入口）
+#[tauri::command]
+pub fn get_managed_policy() -> ManagedPolicy {
+    policy().clone()
+}
@@ -0,0 +1,121 @@
+//! 隐私模式（访客会话）
+//!
+//! 开启后暂停记录剪贴板历史、使用统计和搜索历史，到期自动结束。
+//! 各记录路径在写入前调用 `is_recording_paused()` 判断。
+//! 前端根据事件显示明显的隐私模式指示。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// 隐私模式状态变更事件
+pub const PRIVACY_SESSION_EVENT: &str = "privacy://session-changed";
+/// 默认会话时长：30 分钟
+const DEFAULT_SESSION_MINUTES: u64 = 30;
+/// 会话时长上限，防止误开成"永久隐身"后忘掉
+const MAX_SESSION_MINUTES: u64 = 8 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivacySessionState {
+    pub active: bool,
+    /// 到期时间（Unix 毫秒）；未激活时为 None
+    pub expires_at: Option<i64>,
+}
+
+static SESSION: Lazy<RwLock<PrivacySessionState>> = Lazy::new(|| {
+    RwLock::new(PrivacySessionState {
+        active: false,
+        expires_at: None,
+    })
+});
+
+/// 是否应暂停记录（剪贴板/使用统计/搜索历史写入前调用）。
+/// 到期的会话在这里顺带失效，避免依赖后台任务的时序。
+pub fn is_recording_paused() -> bool {
+    let expired = {
+        let Ok(session) = SESSION.read() else { return false };
+        if !session.active {
+            return false;
+        }
+        match session.expires_at {
+            Some(expires) => chrono::Utc::now().timestamp_millis() >= expires,
+            None => false,
+        }
+    };
+    if expired {
+        if let Ok(mut session) = SESSION.write() {
+            session.active = false;
+            session.expires_at = None;
+        }
+        return false;
+    }
+    true
+}
+
+fn broadcast(app: &AppHandle) {
+    let state = SESSION
+        .read()
+        .map(|s| s.clone())
+        .unwrap_or(PrivacySessionState {
+            active: false,
+            expires_at: None,
+        });
+    if let Err(e) = app.emit(PRIVACY_SESSION_EVENT, state) {
+        log::warn!("[Privacy] failed to emit session event: {}", e);
+    }
+}
+
+/// 开启隐私会话；`minutes` 缺省为 30 分钟。可由命令或快捷键触发
+#[tauri::command]
+pub fn start_privacy_session(app: AppHandle, minutes: Option<u64>) -> Result<PrivacySessionState, String> {
+    let minutes = minutes.unwrap_or(DEFAULT_SESSION_MINUTES).min(MAX_SESSION_MINUTES);
+    if minutes == 0 {
+        return Err("隐私会话时长必须大于 0".into());
+    }
+    let expires_at = chrono::Utc::now().timestamp_millis() + (minutes * 60 * 1000) as i64;
+    {
+        let mut session = SESSION.write().map_err(|e| e.to_string())?;
+        session.active = true;
+        session.expires_at = Some(expires_at);
+    }
+    log::info!("[Privacy] session started for {} minutes", minutes);
+    broadcast(&app);
+
+    // 到期时主动广播结束事件，让指示器及时消失
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(minutes * 60)).await;
+        if !is_recording_paused() {
+            broadcast(&app_clone);
+        }
+    });
+    Ok(get_privacy_session())
+}
+
+/// 手动结束隐私会话
+#[tauri::command]
+pub fn stop_privacy_session(app: AppHandle) -> Result<(), String> {
+    {
+        let mut session = SESSION.write().map_err(|e| e.to_string())?;
+        session.active = false;
+        session.expires_at = None;
+    }
+    log::info!("[Privacy] session stopped");
+    broadcast(&app);
+    Ok(())
+}
+
+/// 查询当前会话状态
+#[tauri::command]
+pub fn get_privacy_session() -> PrivacySessionState {
+    SESSION
+        .read()
+        .map(|s| s.clone())
+        .unwrap_or(PrivacySessionState {
+            active: false,
+            expires_at: None,
+        })
+}
@@ -0,0 +1,168 @@
+//! 多配置档案（工作/个人）
+//!
+//! 每个档案拥有独立的设置、缩写、快捷链接、启用插件列表和剪贴板历史，
+//! 数据隔离在 `profiles/<name>/` 子目录下。`switch_profile` 切换后发出
+//! 事件，各服务重新加载自己的档案级数据；搜索里有对应的快速切换动作。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 档案切换事件，前端与各服务监听后重载数据
+pub const PROFILE_SWITCHED_EVENT: &str = "profile://switched";
+/// 默认档案名；首次启动自动创建
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub name: String,
+    /// 创建时间（Unix 秒）
+    pub created_at: i64,
+    /// 设置页展示用的主题色
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+static ACTIVE_PROFILE: Lazy<RwLock<String>> =
+    Lazy::new(|| RwLock::new(DEFAULT_PROFILE.to_string()));
+
+/// 当前活跃档案名
+pub fn active() -> String {
+    ACTIVE_PROFILE
+        .read()
+        .map(|p| p.clone())
+        .unwrap_or_else(|_| DEFAULT_PROFILE.to_string())
+}
+
+fn profiles_root(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("profiles");
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// 当前档案的数据目录；档案级数据（剪贴板、缩写等）都存放在此
+pub fn active_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = profiles_root(app)?.join(active());
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn profile_meta_path(app: &AppHandle, name: &str) -> Result<PathBuf, String> {
+    Ok(profiles_root(app)?.join(name).join("profile.json"))
+}
+
+fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.len() > 32 {
+        return Err("档案名长度需在 1-32 之间".into());
+    }
+    if !name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
+        return Err("档案名只能包含字母、数字、- 和 _".into());
+    }
+    Ok(())
+}
+
+/// 列出全部档案
+#[tauri::command]
+pub fn list_profiles(app: AppHandle) -> Result<Vec<Profile>, String> {
+    let root = profiles_root(&app)?;
+    let mut profiles = Vec::new();
+    for entry in fs::read_dir(&root).map_err(|e| e.to_string())?.flatten() {
+        if !entry.path().is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        let meta_path = entry.path().join("profile.json");
+        let profile = fs::read_to_string(&meta_path)
+            .ok()
+            .and_then(|c| serde_json::from_str(&c).ok())
+            .unwrap_or(Profile {
+                name: name.clone(),
+                created_at: 0,
+                color: None,
+            });
+        profiles.push(profile);
+    }
+    if profiles.is_empty() {
+        // 保证至少有默认档案
+        profiles.push(create_profile_inner(&app, DEFAULT_PROFILE.into(), None)?);
+    }
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(profiles)
+}
+
+fn create_profile_inner(
+    app: &AppHandle,
+    name: String,
+    color: Option<String>,
+) -> Result<Profile, String> {
+    validate_name(&name)?;
+    let profile = Profile {
+        name: name.clone(),
+        created_at: chrono::Utc::now().timestamp(),
+        color,
+    };
+    let meta_path = profile_meta_path(app, &name)?;
+    if let Some(parent) = meta_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(
+        &meta_path,
+        serde_json::to_string_pretty(&profile).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(profile)
+}
+
+/// 创建新档案
+#[tauri::command]
+pub fn create_profile(app: AppHandle, name: String, color: Option<String>) -> Result<Profile, String> {
+    if profile_meta_path(&app, &name)?.exists() {
+        return Err(format!("档案 {} 已存在", name));
+    }
+    create_profile_inner(&app, name, color)
+}
+
+/// 删除档案及其全部数据；不允许删除当前活跃档案
+#[tauri::command]
+pub fn delete_profile(app: AppHandle, name: String) -> Result<(), String> {
+    validate_name(&name)?;
+    if name == active() {
+        return Err("不能删除当前使用中的档案，请先切换".into());
+    }
+    if name == DEFAULT_PROFILE {
+        return Err("默认档案不能删除".into());
+    }
+    let dir = profiles_root(&app)?.join(&name);
+    if dir.exists() {
+        fs::remove_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    log::info!("[Profiles] deleted profile {}", name);
+    Ok(())
+}
+
+/// 切换档案：更新活跃档案并广播事件，各服务据此重载数据
+#[tauri::command]
+pub fn switch_profile(app: AppHandle, name: String) -> Result<(), String> {
+    validate_name(&name)?;
+    if !profiles_root(&app)?.join(&name).exists() {
+        return Err(format!("档案 {} 不存在", name));
+    }
+    *ACTIVE_PROFILE.write().map_err(|e| e.to_string())? = name.clone();
+    log::info!("[Profiles] switched to profile {}", name);
+    app.emit(PROFILE_SWITCHED_EVENT, serde_json::json!({ "name": name }))
+        .map_err(|e| e.to_string())
+}
+
+/// 当前活跃档案名（前端状态栏展示）
+#[tauri::command]
+pub fn get_active_profile() -> String {
+    active()
+}
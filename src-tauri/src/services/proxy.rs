@@ -0,0 +1,117 @@
+//! 出站 HTTP 代理配置
+//!
+//! 很多企业用户无法直连 npmjs.org。这里提供统一的代理配置
+//! （系统代理探测 + 手动 host/port/认证），应用到市场客户端、
+//! 更新检查、URL 信息增强和插件下载桥等全部出站请求。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+/// 代理工作模式
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProxyMode {
+    /// 不使用代理（也忽略系统代理）
+    None,
+    /// 跟随系统代理（HTTP_PROXY/HTTPS_PROXY 环境变量及系统设置）
+    System,
+    /// 手动配置
+    Manual,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyConfig {
+    pub mode: ProxyMode,
+    #[serde(default)]
+    pub host: Option<String>,
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+impl Default for ProxyConfig {
+    fn default() -> Self {
+        Self {
+            mode: ProxyMode::System,
+            host: None,
+            port: None,
+            username: None,
+            password: None,
+        }
+    }
+}
+
+static PROXY_CONFIG: Lazy<RwLock<ProxyConfig>> =
+    Lazy::new(|| RwLock::new(ProxyConfig::default()));
+
+/// 当前代理配置的副本
+pub fn current() -> ProxyConfig {
+    PROXY_CONFIG.read().map(|c| c.clone()).unwrap_or_default()
+}
+
+/// 探测系统代理（环境变量优先，与 reqwest 默认行为一致）
+pub fn detect_system_proxy() -> Option<String> {
+    ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"]
+        .iter()
+        .find_map(|key| std::env::var(key).ok())
+        .filter(|v| !v.is_empty())
+}
+
+/// 将当前代理配置应用到 reqwest 客户端构建器
+pub fn apply(builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+    let config = current();
+    match config.mode {
+        ProxyMode::None => builder.no_proxy(),
+        // reqwest 默认读取环境变量里的系统代理，不需要额外配置
+        ProxyMode::System => builder,
+        ProxyMode::Manual => {
+            let (Some(host), Some(port)) = (config.host.as_ref(), config.port) else {
+                log::warn!("[Proxy] manual mode without host/port, falling back to direct");
+                return builder.no_proxy();
+            };
+            let url = format!("http://{}:{}", host, port);
+            match reqwest::Proxy::all(&url) {
+                Ok(mut proxy) => {
+                    if let (Some(user), Some(pass)) = (config.username.as_ref(), config.password.as_ref())
+                    {
+                        proxy = proxy.basic_auth(user, pass);
+                    }
+                    builder.proxy(proxy)
+                }
+                Err(e) => {
+                    log::error!("[Proxy] invalid proxy url {}: {}", url, e);
+                    builder
+                }
+            }
+        }
+    }
+}
+
+/// 获取代理配置（设置页）
+#[tauri::command]
+pub fn get_proxy_config() -> ProxyConfig {
+    current()
+}
+
+/// 更新代理配置并重建共享 HTTP 客户端，立即对后续请求生效
+#[tauri::command]
+pub fn set_proxy_config(config: ProxyConfig) -> Result<(), String> {
+    if config.mode == ProxyMode::Manual && (config.host.is_none() || config.port.is_none()) {
+        return Err("手动代理模式需要填写 host 与 port".into());
+    }
+    *PROXY_CONFIG.write().map_err(|e| e.to_string())? = config;
+    crate::marketplace::http_client::rebuild_client();
+    log::info!("[Proxy] configuration updated, http clients rebuilt");
+    Ok(())
+}
+
+/// 返回探测到的系统代理地址（设置页展示用）
+#[tauri::command]
+pub fn get_system_proxy() -> Option<String> {
+    detect_system_proxy()
+}
@@ -0,0 +1,192 @@
+//! 快捷链接（Quicklinks）
+//!
+//! 用户自定义的带模板 URL：比如 `gh {query}` →
+//! `https://github.com/search?q={query}`。关键词走触发词注册表
+//! （QuicklinkKeyword 优先级），同时以 provider 形式出现在搜索结果里；
+//! 打开统一走 `cmds::open_url` 的 scheme 校验，模板里的 `{query}`
+//! 会做 URL 编码替换。定义持久化在 `app_data/quicklinks.json`。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+/// 持久化文件名
+const STORE_FILE: &str = "quicklinks.json";
+
+/// 一条快捷链接定义
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Quicklink {
+    /// 触发关键词（如 "gh"）
+    pub keyword: String,
+    /// 展示名（如 "GitHub 搜索"）
+    pub name: String,
+    /// URL 模板，`{query}` 为替换占位符
+    pub template: String,
+}
+
+static QUICKLINKS: Lazy<RwLock<HashMap<String, Quicklink>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+fn store_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join(STORE_FILE))
+}
+
+fn save(app: &AppHandle) -> Result<(), String> {
+    let links: Vec<Quicklink> = QUICKLINKS
+        .read()
+        .map_err(|e| e.to_string())?
+        .values()
+        .cloned()
+        .collect();
+    std::fs::write(
+        store_path(app)?,
+        serde_json::to_string_pretty(&links).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| format!("保存快捷链接失败: {}", e))
+}
+
+/// 启动时加载持久化的快捷链接并注册触发词
+pub fn load(app: &AppHandle) {
+    let Ok(path) = store_path(app) else { return };
+    let Ok(content) = std::fs::read_to_string(&path) else { return };
+    let Ok(links) = serde_json::from_str::<Vec<Quicklink>>(&content) else {
+        log::warn!("[Quicklinks] store file corrupted, starting empty");
+        return;
+    };
+    let Ok(mut map) = QUICKLINKS.write() else { return };
+    for link in links {
+        crate::search::trigger_registry::register(
+            &link.keyword,
+            crate::search::trigger_registry::TriggerSource::QuicklinkKeyword,
+            &format!("quicklink:{}", link.keyword),
+        );
+        map.insert(link.keyword.clone(), link);
+    }
+    log::info!("[Quicklinks] loaded {} quicklinks", map.len());
+}
+
+/// 校验模板：必须含 `{query}` 占位符且是 http(s) URL
+fn validate_template(template: &str) -> Result<(), String> {
+    if !template.contains("{query}") {
+        return Err("模板必须包含 {query} 占位符".into());
+    }
+    let probe = template.replace("{query}", "probe");
+    let parsed = url::Url::parse(&probe).map_err(|e| format!("模板不是合法 URL: {}", e))?;
+    if parsed.scheme() != "http" && parsed.scheme() != "https" {
+        return Err("快捷链接只支持 http/https".into());
+    }
+    Ok(())
+}
+
+/// 新增快捷链接；关键词冲突或模板非法时报错
+#[tauri::command]
+pub fn add_quicklink(app: AppHandle, keyword: String, name: String, template: String) -> Result<(), String> {
+    let keyword = keyword.trim().to_lowercase();
+    if keyword.is_empty() || keyword.contains(char::is_whitespace) {
+        return Err("关键词不能为空或包含空白".into());
+    }
+    validate_template(&template)?;
+    {
+        let mut map = QUICKLINKS.write().map_err(|e| e.to_string())?;
+        if map.contains_key(&keyword) {
+            return Err(format!("关键词 '{}' 已被占用", keyword));
+        }
+        crate::search::trigger_registry::register(
+            &keyword,
+            crate::search::trigger_registry::TriggerSource::QuicklinkKeyword,
+            &format!("quicklink:{}", keyword),
+        );
+        map.insert(
+            keyword.clone(),
+            Quicklink {
+                keyword,
+                name,
+                template,
+            },
+        );
+    }
+    save(&app)
+}
+
+/// 列出全部快捷链接
+#[tauri::command]
+pub fn list_quicklinks() -> Vec<Quicklink> {
+    let mut links: Vec<Quicklink> = QUICKLINKS
+        .read()
+        .map(|m| m.values().cloned().collect())
+        .unwrap_or_default();
+    links.sort_by(|a, b| a.keyword.cmp(&b.keyword));
+    links
+}
+
+/// 删除快捷链接并注销触发词
+#[tauri::command]
+pub fn delete_quicklink(app: AppHandle, keyword: String) -> Result<(), String> {
+    {
+        let mut map = QUICKLINKS.write().map_err(|e| e.to_string())?;
+        if map.remove(&keyword).is_none() {
+            return Err(format!("快捷链接 '{}' 不存在", keyword));
+        }
+    }
+    crate::search::trigger_registry::unregister_owner(&format!("quicklink:{}", keyword));
+    save(&app)
+}
+
+/// 执行快捷链接：替换 {query}（URL 编码）后经 open_url 校验打开
+#[tauri::command]
+pub fn execute_quicklink(app: AppHandle, keyword: String, query: String) -> Result<(), String> {
+    let link = QUICKLINKS
+        .read()
+        .map_err(|e| e.to_string())?
+        .get(&keyword)
+        .cloned()
+        .ok_or_else(|| format!("快捷链接 '{}' 不存在", keyword))?;
+    let encoded: String = url::form_urlencoded::byte_serialize(query.trim().as_bytes()).collect();
+    let url = link.template.replace("{query}", &encoded);
+    crate::cmds::open_url::open_url(app, url)
+}
+
+/// 快捷链接 provider：按名称/关键词模糊匹配出现在搜索结果里
+pub struct QuicklinkProvider;
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for QuicklinkProvider {
+    fn name(&self) -> &str {
+        "quicklinks"
+    }
+
+    fn priority(&self) -> i32 {
+        30
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        list_quicklinks()
+            .into_iter()
+            .filter_map(|link| {
+                let score = crate::services::pinyin_matcher::score(query, &link.name)
+                    .max(crate::search::fuzzy::score(query, &link.keyword));
+                score.map(|s| crate::search::pipeline::SearchResult {
+                    id: format!("quicklink:{}", link.keyword),
+                    title: link.name.clone(),
+                    subtitle: Some(format!("{} {{query}}", link.keyword)),
+                    icon: None,
+                    provider: String::new(),
+                    score: s,
+                    payload: serde_json::to_value(&link).unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 注册快捷链接 provider（启动时调用）
+pub fn register() {
+    crate::search::pipeline::register_provider(std::sync::Arc::new(QuicklinkProvider));
+}
@@ -0,0 +1,261 @@
+//! 软件包注册表查询（crates.io / npm / PyPI）
+//!
+//! `crate:`、`npm:`、`pypi:` 范围直接查对应注册表的包元数据：最新
+//! 版本、简介、下载量，动作是复制安装命令（`cargo add` / `npm i` /
+//! `pip install`）。请求走市场的共享 HTTP 客户端（带重试、限流，
+//! 回放模式下也自动吃夹具），结果按 registry+包名缓存 10 分钟。
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 元数据缓存有效期
+const CACHE_TTL: Duration = Duration::from_secs(600);
+
+/// 支持的注册表
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Registry {
+    Crates,
+    Npm,
+    Pypi,
+}
+
+impl Registry {
+    fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "crates" => Ok(Self::Crates),
+            "npm" => Ok(Self::Npm),
+            "pypi" => Ok(Self::Pypi),
+            other => Err(format!("未知的注册表: {}", other)),
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Crates => "crates",
+            Self::Npm => "npm",
+            Self::Pypi => "pypi",
+        }
+    }
+
+    fn install_command(&self, package: &str) -> String {
+        match self {
+            Self::Crates => format!("cargo add {}", package),
+            Self::Npm => format!("npm install {}", package),
+            Self::Pypi => format!("pip install {}", package),
+        }
+    }
+}
+
+/// 一个包的元数据
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageInfo {
+    pub name: String,
+    pub registry: String,
+    pub version: String,
+    pub description: String,
+    /// 注册表不提供下载量时为 None（PyPI）
+    pub downloads: Option<u64>,
+    pub install_command: String,
+}
+
+static CACHE: Lazy<Mutex<HashMap<String, (Instant, PackageInfo)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn validate_name(name: &str) -> Result<(), String> {
+    let ok = !name.is_empty()
+        && name.len() <= 214
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '@' | '/'));
+    if ok {
+        Ok(())
+    } else {
+        Err(format!("包名 '{}' 不合法", name))
+    }
+}
+
+async fn fetch(registry: Registry, name: &str) -> Result<PackageInfo, String> {
+    use crate::marketplace::http_client;
+    let encoded: String = url::form_urlencoded::byte_serialize(name.as_bytes()).collect();
+    match registry {
+        Registry::Crates => {
+            let body =
+                http_client::get_json(&format!("https://crates.io/api/v1/crates/{}", encoded))
+                    .await?;
+            let krate = body.get("crate").ok_or("crates.io 响应缺少 crate 字段")?;
+            Ok(PackageInfo {
+                name: name.to_string(),
+                registry: registry.key().into(),
+                version: krate
+                    .get("max_stable_version")
+                    .or_else(|| krate.get("max_version"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("?")
+                    .to_string(),
+                description: krate
+                    .get("description")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                downloads: krate.get("downloads").and_then(|v| v.as_u64()),
+                install_command: registry.install_command(name),
+            })
+        }
+        Registry::Npm => {
+            let body =
+                http_client::get_json(&format!("https://registry.npmjs.org/{}/latest", encoded))
+                    .await?;
+            // 下载量在独立的统计接口；拿不到不算失败
+            let downloads = http_client::get_json(&format!(
+                "https://api.npmjs.org/downloads/point/last-week/{}",
+                encoded
+            ))
+            .await
+            .ok()
+            .and_then(|d| d.get("downloads").and_then(|v| v.as_u64()));
+            Ok(PackageInfo {
+                name: name.to_string(),
+                registry: registry.key().into(),
+                version: body
+                    .get("version")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("?")
+                    .to_string(),
+                description: body
+                    .get("description")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                downloads,
+                install_command: registry.install_command(name),
+            })
+        }
+        Registry::Pypi => {
+            let body =
+                http_client::get_json(&format!("https://pypi.org/pypi/{}/json", encoded)).await?;
+            let info = body.get("info").ok_or("PyPI 响应缺少 info 字段")?;
+            Ok(PackageInfo {
+                name: name.to_string(),
+                registry: registry.key().into(),
+                version: info
+                    .get("version")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("?")
+                    .to_string(),
+                description: info
+                    .get("summary")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                downloads: None,
+                install_command: registry.install_command(name),
+            })
+        }
+    }
+}
+
+async fn lookup(registry: Registry, name: &str) -> Result<PackageInfo, String> {
+    if crate::services::policy::is_feature_disabled("network") {
+        return Err("网络功能已被管理员策略禁用".into());
+    }
+    validate_name(name)?;
+    let cache_key = format!("{}:{}", registry.key(), name);
+    if let Ok(cache) = CACHE.lock() {
+        if let Some((at, info)) = cache.get(&cache_key) {
+            if at.elapsed() < CACHE_TTL {
+                return Ok(info.clone());
+            }
+        }
+    }
+    let info = fetch(registry, name).await?;
+    if let Ok(mut cache) = CACHE.lock() {
+        cache.insert(cache_key, (Instant::now(), info.clone()));
+    }
+    Ok(info)
+}
+
+/// 查询包元数据；`registry`: "crates" / "npm" / "pypi"
+#[tauri::command]
+pub async fn get_package_registry_info(registry: String, name: String) -> Result<PackageInfo, String> {
+    lookup(Registry::parse(&registry)?, name.trim()).await
+}
+
+/// 复制安装命令到系统剪贴板
+#[tauri::command]
+pub fn copy_install_command(registry: String, name: String) -> Result<String, String> {
+    let registry = Registry::parse(&registry)?;
+    validate_name(name.trim())?;
+    let command = registry.install_command(name.trim());
+    crate::services::clipboard_images::set_clipboard_text(&command)?;
+    Ok(command)
+}
+
+/// 注册表查询 provider；每个注册表一个实例，配各自的范围前缀
+pub struct RegistryInfoProvider {
+    registry: Registry,
+    provider_name: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for RegistryInfoProvider {
+    fn name(&self) -> &str {
+        self.provider_name
+    }
+
+    fn priority(&self) -> i32 {
+        200
+    }
+
+    fn scope_only(&self) -> bool {
+        // 网络查询只在 crate:/npm:/pypi: 范围里触发
+        true
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        let name = query.trim();
+        if name.len() < 2 {
+            return Vec::new();
+        }
+        let Ok(info) = lookup(self.registry, name).await else {
+            return Vec::new();
+        };
+        let downloads = info
+            .downloads
+            .map(|d| format!(" ｜ {} 次下载", d))
+            .unwrap_or_default();
+        vec![crate::search::pipeline::SearchResult {
+            id: format!("registry:{}:{}", info.registry, info.name),
+            title: format!("{} v{}", info.name, info.version),
+            subtitle: Some(format!("{}{}", info.description, downloads)),
+            icon: None,
+            provider: String::new(),
+            score: i32::MAX - 1,
+            payload: serde_json::json!({
+                "registry": info.registry,
+                "package": info.name,
+                "installCommand": info.install_command,
+                "action": "copy-install",
+            }),
+        }]
+    }
+}
+
+/// 注册三个注册表 provider 并登记各自的范围（启动时调用）
+pub fn register() {
+    let providers: [(Registry, &'static str, &'static str, &'static str); 3] = [
+        (Registry::Crates, "crates-registry", "crate:", "crates.io 包"),
+        (Registry::Npm, "npm-registry", "npm:", "npm 包"),
+        (Registry::Pypi, "pypi-registry", "pypi:", "PyPI 包"),
+    ];
+    for (registry, provider_name, prefix, label) in providers {
+        crate::search::pipeline::register_provider(std::sync::Arc::new(RegistryInfoProvider {
+            registry,
+            provider_name,
+        }));
+        crate::search::scopes::register_scope(prefix, provider_name, label);
+    }
+}
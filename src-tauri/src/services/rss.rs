@@ -0,0 +1,269 @@
+//! RSS/Atom 订阅
+//!
+//! 极简的本地阅读器：订阅保存在 SQLite，后台每 30 分钟刷新一次，
+//! `rss` 触发词列出未读条目，打开即标记已读。解析交给 feed-rs，
+//! RSS 与 Atom 都能吃。
+
+use feed_rs::parser;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// 后台刷新间隔
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// 每个源保留的条目上限
+const MAX_ITEMS_PER_FEED: i64 = 200;
+
+/// 订阅的源
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Feed {
+    pub id: i64,
+    pub url: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub last_fetched_at: Option<i64>,
+    pub unread_count: i64,
+}
+
+/// 未读条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItem {
+    pub id: i64,
+    pub feed_id: i64,
+    pub feed_title: Option<String>,
+    pub title: String,
+    #[serde(default)]
+    pub link: Option<String>,
+    #[serde(default)]
+    pub published_at: Option<i64>,
+}
+
+/// 拉取并入库单个源；返回新增条目数
+async fn refresh_feed(feed_id: i64, url: &str) -> Result<u64, String> {
+    let resp = crate::marketplace::http_client::client()
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("拉取订阅失败: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("订阅源返回 {}", resp.status()));
+    }
+    let bytes = resp.bytes().await.map_err(|e| e.to_string())?;
+    let parsed = parser::parse(&bytes[..]).map_err(|e| format!("解析订阅失败: {}", e))?;
+
+    let conn = crate::db::pool::get()?;
+    let feed_title = parsed.title.as_ref().map(|t| t.content.clone());
+    let now = chrono::Utc::now().timestamp();
+    conn.execute(
+        "UPDATE rss_feeds SET title = COALESCE(?1, title), last_fetched_at = ?2 WHERE id = ?3",
+        params![feed_title, now, feed_id],
+    )
+    .map_err(|e| e.to_string())?;
+
+    let mut inserted = 0u64;
+    for entry in &parsed.entries {
+        let guid = if entry.id.is_empty() {
+            entry
+                .links
+                .first()
+                .map(|l| l.href.clone())
+                .unwrap_or_default()
+        } else {
+            entry.id.clone()
+        };
+        if guid.is_empty() {
+            continue;
+        }
+        let title = entry
+            .title
+            .as_ref()
+            .map(|t| t.content.clone())
+            .unwrap_or_else(|| "(无标题)".into());
+        let link = entry.links.first().map(|l| l.href.clone());
+        let published = entry
+            .published
+            .or(entry.updated)
+            .map(|t| t.timestamp());
+        let changed = conn
+            .execute(
+                "INSERT OR IGNORE INTO rss_items (feed_id, guid, title, link, published_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![feed_id, guid, title, link, published],
+            )
+            .map_err(|e| e.to_string())?;
+        inserted += changed as u64;
+    }
+
+    // 按发布时间截断，防止条目无限增长
+    conn.execute(
+        "DELETE FROM rss_items WHERE feed_id = ?1 AND id NOT IN (
+             SELECT id FROM rss_items WHERE feed_id = ?1
+             ORDER BY COALESCE(published_at, 0) DESC LIMIT ?2
+         )",
+        params![feed_id, MAX_ITEMS_PER_FEED],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(inserted)
+}
+
+/// 刷新全部订阅
+async fn refresh_all() {
+    let feeds: Vec<(i64, String)> = {
+        let Ok(conn) = crate::db::pool::get() else { return };
+        let Ok(mut stmt) = conn.prepare("SELECT id, url FROM rss_feeds") else { return };
+        let Ok(rows) = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?))) else { return };
+        rows.flatten().collect()
+    };
+    for (id, url) in feeds {
+        match refresh_feed(id, &url).await {
+            Ok(n) if n > 0 => log::info!("[Rss] feed {} has {} new items", url, n),
+            Ok(_) => {}
+            Err(e) => log::warn!("[Rss] refresh of {} failed: {}", url, e),
+        }
+    }
+}
+
+/// 启动后台刷新循环
+pub fn spawn_refresh_loop() {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if !crate::services::policy::is_feature_disabled("network") {
+                refresh_all().await;
+            }
+            tokio::time::sleep(REFRESH_INTERVAL).await;
+        }
+    });
+}
+
+/// 订阅源；立即拉取一次验证可用性
+#[tauri::command]
+pub async fn rss_subscribe(url: String) -> Result<Feed, String> {
+    let trimmed = url.trim().to_string();
+    if !trimmed.starts_with("http://") && !trimmed.starts_with("https://") {
+        return Err("订阅地址必须是 http(s)".into());
+    }
+    let feed_id = {
+        let conn = crate::db::pool::get()?;
+        conn.execute(
+            "INSERT OR IGNORE INTO rss_feeds (url) VALUES (?1)",
+            params![trimmed],
+        )
+        .map_err(|e| e.to_string())?;
+        conn.query_row(
+            "SELECT id FROM rss_feeds WHERE url = ?1",
+            params![trimmed],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|e| e.to_string())?
+    };
+    refresh_feed(feed_id, &trimmed).await?;
+
+    let conn = crate::db::pool::get()?;
+    conn.query_row(
+        "SELECT f.id, f.url, f.title, f.last_fetched_at,
+                (SELECT COUNT(*) FROM rss_items i WHERE i.feed_id = f.id AND i.is_read = 0)
+         FROM rss_feeds f WHERE f.id = ?1",
+        params![feed_id],
+        |row| {
+            Ok(Feed {
+                id: row.get(0)?,
+                url: row.get(1)?,
+                title: row.get(2)?,
+                last_fetched_at: row.get(3)?,
+                unread_count: row.get(4)?,
+            })
+        },
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// 退订并删除其条目
+#[tauri::command]
+pub fn rss_unsubscribe(feed_id: i64) -> Result<(), String> {
+    let conn = crate::db::pool::get()?;
+    let n = conn
+        .execute("DELETE FROM rss_feeds WHERE id = ?1", params![feed_id])
+        .map_err(|e| e.to_string())?;
+    if n == 0 {
+        return Err(format!("订阅 {} 不存在", feed_id));
+    }
+    Ok(())
+}
+
+/// 列出订阅（含未读数）
+#[tauri::command]
+pub fn rss_list_feeds() -> Result<Vec<Feed>, String> {
+    let conn = crate::db::pool::get()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT f.id, f.url, f.title, f.last_fetched_at,
+                    (SELECT COUNT(*) FROM rss_items i WHERE i.feed_id = f.id AND i.is_read = 0)
+             FROM rss_feeds f ORDER BY f.title",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(Feed {
+                id: row.get(0)?,
+                url: row.get(1)?,
+                title: row.get(2)?,
+                last_fetched_at: row.get(3)?,
+                unread_count: row.get(4)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+/// 未读条目列表（`rss` 触发词）
+#[tauri::command]
+pub fn rss_unread_items(limit: Option<i64>) -> Result<Vec<FeedItem>, String> {
+    let conn = crate::db::pool::get()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT i.id, i.feed_id, f.title, i.title, i.link, i.published_at
+             FROM rss_items i JOIN rss_feeds f ON f.id = i.feed_id
+             WHERE i.is_read = 0
+             ORDER BY COALESCE(i.published_at, 0) DESC LIMIT ?1",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(params![limit.unwrap_or(50).clamp(1, 200)], |row| {
+            Ok(FeedItem {
+                id: row.get(0)?,
+                feed_id: row.get(1)?,
+                feed_title: row.get(2)?,
+                title: row.get(3)?,
+                link: row.get(4)?,
+                published_at: row.get(5)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+/// 打开条目并标记已读
+#[tauri::command]
+pub fn rss_open_item(app: tauri::AppHandle, item_id: i64) -> Result<(), String> {
+    let conn = crate::db::pool::get()?;
+    let link: Option<String> = conn
+        .query_row(
+            "SELECT link FROM rss_items WHERE id = ?1",
+            params![item_id],
+            |row| row.get(0),
+        )
+        .map_err(|_| format!("条目 {} 不存在", item_id))?;
+    conn.execute(
+        "UPDATE rss_items SET is_read = 1 WHERE id = ?1",
+        params![item_id],
+    )
+    .map_err(|e| e.to_string())?;
+    if let Some(link) = link {
+        // 链接来自远端 feed，必须过 open_url 的 scheme 校验与审计
+        crate::cmds::open_url::open_url(app, link)?;
+    }
+    Ok(())
+}
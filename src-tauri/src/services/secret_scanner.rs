@@ -0,0 +1,162 @@
+//! 剪贴板敏感信息扫描
+//!
+//! 检测复制文本中的疑似凭证（API key、私钥、高熵字符串），
+//! 命中的历史记录会被打上警告标记并在短 TTL 后自动过期。
+//! 扫描开关与 TTL 在剪贴板设置中可配置。
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+/// 默认敏感条目保留时间：90 秒
+const DEFAULT_SECRET_TTL_SECS: u64 = 90;
+/// 高熵判定阈值（Shannon 熵，单位 bit/字符）
+const ENTROPY_THRESHOLD: f64 = 4.2;
+/// 参与熵计算的最小 token 长度
+const MIN_TOKEN_LEN: usize = 20;
+
+/// 扫描配置，随剪贴板设置持久化
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretScanConfig {
+    /// 是否启用扫描
+    pub enabled: bool,
+    /// 命中后条目的自动过期时间（秒）
+    pub secret_ttl_secs: u64,
+}
+
+impl Default for SecretScanConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            secret_ttl_secs: DEFAULT_SECRET_TTL_SECS,
+        }
+    }
+}
+
+static SCAN_CONFIG: Lazy<RwLock<SecretScanConfig>> =
+    Lazy::new(|| RwLock::new(SecretScanConfig::default()));
+
+/// 命中的凭证类别
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SecretKind {
+    ApiKey,
+    PrivateKey,
+    HighEntropy,
+}
+
+/// 单条文本的扫描结论，剪贴板历史据此标记条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretScanResult {
+    /// 是否命中任意检测项
+    pub is_sensitive: bool,
+    /// 命中的类别（可能多个）
+    pub kinds: Vec<SecretKind>,
+    /// 命中时条目应在多少秒后过期
+    pub expire_after_secs: Option<u64>,
+}
+
+// 常见云厂商/平台的 key 前缀格式
+static API_KEY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?x)
+        \b(?:
+            sk-[A-Za-z0-9]{20,}            # OpenAI 风格
+          | AKIA[0-9A-Z]{16}               # AWS access key
+          | ghp_[A-Za-z0-9]{36}            # GitHub PAT
+          | gho_[A-Za-z0-9]{36}
+          | xox[baprs]-[A-Za-z0-9\-]{10,}  # Slack token
+          | AIza[0-9A-Za-z\-_]{35}         # Google API key
+        )\b",
+    )
+    .unwrap()
+});
+
+static PRIVATE_KEY_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"-----BEGIN (?:RSA |EC |OPENSSH |DSA |PGP )?PRIVATE KEY").unwrap());
+
+/// 计算字符串的 Shannon 熵（bit/字符）
+fn shannon_entropy(s: &str) -> f64 {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        return 0.0;
+    }
+    let mut counts = [0u32; 256];
+    for &b in bytes {
+        counts[b as usize] += 1;
+    }
+    let len = bytes.len() as f64;
+    counts
+        .iter()
+        .filter(|&&c| c > 0)
+        .map(|&c| {
+            let p = c as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// 文本中是否存在高熵 token（排除 URL 等正常长串）
+fn has_high_entropy_token(text: &str) -> bool {
+    text.split(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+        .filter(|t| t.len() >= MIN_TOKEN_LEN && !t.starts_with("http"))
+        .any(|t| shannon_entropy(t) >= ENTROPY_THRESHOLD)
+}
+
+/// 扫描一段复制文本，返回是否敏感及过期策略
+pub fn scan_text(text: &str) -> SecretScanResult {
+    let config = SCAN_CONFIG.read().map(|c| c.clone()).unwrap_or_default();
+    if !config.enabled {
+        return SecretScanResult {
+            is_sensitive: false,
+            kinds: Vec::new(),
+            expire_after_secs: None,
+        };
+    }
+
+    let mut kinds = Vec::new();
+    if API_KEY_RE.is_match(text) {
+        kinds.push(SecretKind::ApiKey);
+    }
+    if PRIVATE_KEY_RE.is_match(text) {
+        kinds.push(SecretKind::PrivateKey);
+    }
+    if kinds.is_empty() && has_high_entropy_token(text) {
+        kinds.push(SecretKind::HighEntropy);
+    }
+
+    let is_sensitive = !kinds.is_empty();
+    if is_sensitive {
+        log::info!("[SecretScanner] sensitive clipboard content detected: {:?}", kinds);
+    }
+    SecretScanResult {
+        is_sensitive,
+        kinds,
+        expire_after_secs: is_sensitive.then_some(config.secret_ttl_secs),
+    }
+}
+
+/// 获取当前扫描配置
+#[tauri::command]
+pub fn get_secret_scan_config() -> SecretScanConfig {
+    SCAN_CONFIG.read().map(|c| c.clone()).unwrap_or_default()
+}
+
+/// 更新扫描配置（来自剪贴板设置页）
+#[tauri::command]
+pub fn set_secret_scan_config(config: SecretScanConfig) -> Result<(), String> {
+    if config.secret_ttl_secs == 0 {
+        return Err("secretTtlSecs 必须大于 0".into());
+    }
+    *SCAN_CONFIG.write().map_err(|e| e.to_string())? = config;
+    Ok(())
+}
+
+/// 手动扫描一段文本（设置页预览用）
+#[tauri::command]
+pub fn scan_clipboard_text(text: String) -> SecretScanResult {
+    scan_text(&text)
+}
@@ -0,0 +1,183 @@
+//! 自检诊断
+//!
+//! `run_self_test` 把常见故障点挨个过一遍——数据库、索引读取、
+//! 剪贴板历史、热键注册、到注册表的网络连通性、插件运行时
+//! （node）——返回结构化的通过/失败报告和修复提示。用户贴报告
+//! 即可定位大半问题，省掉 issue 里的来回追问。
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+/// 单项检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckResult {
+    pub name: String,
+    pub passed: bool,
+    #[serde(default)]
+    pub detail: Option<String>,
+    /// 失败时的修复提示
+    #[serde(default)]
+    pub hint: Option<String>,
+}
+
+/// 自检报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTestReport {
+    pub app_version: String,
+    pub platform: String,
+    pub checks: Vec<CheckResult>,
+    pub all_passed: bool,
+}
+
+fn pass(name: &str, detail: Option<String>) -> CheckResult {
+    CheckResult {
+        name: name.into(),
+        passed: true,
+        detail,
+        hint: None,
+    }
+}
+
+fn fail(name: &str, detail: String, hint: &str) -> CheckResult {
+    CheckResult {
+        name: name.into(),
+        passed: false,
+        detail: Some(detail),
+        hint: Some(hint.into()),
+    }
+}
+
+fn check_database() -> CheckResult {
+    match crate::db::pool::get().and_then(|conn| {
+        conn.query_row("SELECT 1", [], |row| row.get::<_, i64>(0))
+            .map_err(|e| e.to_string())
+    }) {
+        Ok(_) => pass("database", None),
+        Err(e) => fail(
+            "database",
+            e,
+            "数据库无法打开，尝试在设置 > 存储中运行恢复，或从备份还原",
+        ),
+    }
+}
+
+fn check_index_read() -> CheckResult {
+    match crate::db::pool::get().and_then(|conn| {
+        conn.query_row(
+            "SELECT COUNT(*) FROM index_stats_daily",
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|e| e.to_string())
+    }) {
+        Ok(count) => pass("indexRead", Some(format!("{} 天统计", count))),
+        Err(e) => fail("indexRead", e, "索引统计表缺失，重建索引可自动恢复"),
+    }
+}
+
+fn check_clipboard_access() -> CheckResult {
+    match crate::db::pool::get().and_then(|conn| {
+        conn.query_row(
+            "SELECT COUNT(*) FROM clipboard_history",
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|e| e.to_string())
+    }) {
+        Ok(count) => pass("clipboard", Some(format!("{} 条历史", count))),
+        Err(e) => fail("clipboard", e, "剪贴板历史表异常，可在设置中清空剪贴板数据后重试"),
+    }
+}
+
+fn check_hotkey() -> CheckResult {
+    match crate::hotkey::fallback::get_hotkey() {
+        Some(hotkey) => pass("hotkey", Some(hotkey)),
+        None => fail(
+            "hotkey",
+            "没有已注册的全局热键".into(),
+            "热键可能被其它应用占用，到设置 > 快捷键换一个组合",
+        ),
+    }
+}
+
+async fn check_network() -> CheckResult {
+    let registry =
+        crate::services::policy::registry_override().unwrap_or("https://registry.npmjs.org");
+    let request = crate::marketplace::http_client::client().head(registry).send();
+    match tokio::time::timeout(std::time::Duration::from_secs(5), request).await {
+        Ok(Ok(resp)) => pass("network", Some(format!("{} -> {}", registry, resp.status()))),
+        Ok(Err(e)) => fail(
+            "network",
+            e.to_string(),
+            "无法访问插件注册表，检查网络或设置 > 网络中的代理配置",
+        ),
+        Err(_) => fail(
+            "network",
+            "请求超时".into(),
+            "网络超时，检查代理配置或防火墙",
+        ),
+    }
+}
+
+fn check_plugin_runtime() -> CheckResult {
+    match std::process::Command::new("node").arg("--version").output() {
+        Ok(output) if output.status.success() => pass(
+            "pluginRuntime",
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+        ),
+        Ok(_) => fail(
+            "pluginRuntime",
+            "node 返回非零退出码".into(),
+            "Node.js 安装异常，请重新安装",
+        ),
+        Err(e) => fail(
+            "pluginRuntime",
+            e.to_string(),
+            "未找到 node，插件无法运行；请安装 Node.js 18+",
+        ),
+    }
+}
+
+fn check_data_dir(app: &AppHandle) -> CheckResult {
+    match app.path().app_data_dir() {
+        Ok(dir) => {
+            let probe = dir.join(".write-probe");
+            match std::fs::write(&probe, b"ok") {
+                Ok(_) => {
+                    let _ = std::fs::remove_file(&probe);
+                    pass("dataDir", Some(dir.display().to_string()))
+                }
+                Err(e) => fail(
+                    "dataDir",
+                    e.to_string(),
+                    "数据目录不可写，检查磁盘空间与权限",
+                ),
+            }
+        }
+        Err(e) => fail("dataDir", e.to_string(), "无法定位数据目录"),
+    }
+}
+
+/// 运行全部自检
+#[tauri::command]
+pub async fn run_self_test(app: AppHandle) -> Result<SelfTestReport, String> {
+    let mut checks = vec![
+        check_database(),
+        check_index_read(),
+        check_clipboard_access(),
+        check_hotkey(),
+        check_plugin_runtime(),
+        check_data_dir(&app),
+    ];
+    checks.push(check_network().await);
+
+    let all_passed = checks.iter().all(|c| c.passed);
+    Ok(SelfTestReport {
+        app_version: crate::plugins::compat::APP_VERSION.to_string(),
+        platform: std::env::consts::OS.to_string(),
+        checks,
+        all_passed,
+    })
+}
@@ -0,0 +1,137 @@
+//! 语音：文本朗读与听写
+//!
+//! 朗读走各平台自带能力：macOS `say`、Windows SAPI（PowerShell）、
+//! Linux `spd-say`/`espeak`。听写按平台能力降级：macOS 依赖 `hear`
+//! 命令行（用户安装后即可用），Windows 走 System.Speech 一次性识别，
+//! Linux 暂不支持；识别结果通过事件注入搜索框，同时可写入剪贴板。
+//! 听写前检查麦克风权限开关（应用内开关，系统权限由 OS 弹窗把关）。
+
+use once_cell::sync::Lazy;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+/// 听写结果事件：{ text }
+pub const DICTATION_EVENT: &str = "speech://dictation";
+
+/// 应用内麦克风开关（默认关，用户显式开启）
+static MICROPHONE_ALLOWED: AtomicBool = AtomicBool::new(false);
+/// 正在朗读的子进程，便于停止
+static SPEAKING: Lazy<Mutex<Option<std::process::Child>>> = Lazy::new(|| Mutex::new(None));
+
+/// 朗读文本；再次调用会先停掉上一次
+#[tauri::command]
+pub fn speak_text(text: String) -> Result<(), String> {
+    if text.trim().is_empty() {
+        return Ok(());
+    }
+    // 回放模式下不拉起系统 TTS，只把调用记成夹具便于断言
+    if crate::services::test_harness::mode()
+        != crate::services::test_harness::MockMode::Off
+    {
+        return crate::services::test_harness::record_fixture(
+            "os-speech",
+            &text,
+            &serde_json::json!({ "spoken": text }),
+        );
+    }
+    stop_speaking()?;
+
+    #[cfg(target_os = "macos")]
+    let child = Command::new("say").arg(&text).spawn();
+    #[cfg(target_os = "windows")]
+    let child = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            &format!(
+                "Add-Type -AssemblyName System.Speech; \
+                 (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak('{}')",
+                text.replace('\'', "''")
+            ),
+        ])
+        .spawn();
+    #[cfg(target_os = "linux")]
+    let child = Command::new("spd-say")
+        .arg(&text)
+        .spawn()
+        .or_else(|_| Command::new("espeak").arg(&text).spawn());
+
+    let child = child.map_err(|e| format!("启动语音合成失败: {}", e))?;
+    let mut speaking = SPEAKING.lock().map_err(|e| e.to_string())?;
+    *speaking = Some(child);
+    Ok(())
+}
+
+/// 停止朗读
+#[tauri::command]
+pub fn stop_speaking() -> Result<(), String> {
+    let mut speaking = SPEAKING.lock().map_err(|e| e.to_string())?;
+    if let Some(mut child) = speaking.take() {
+        let _ = child.kill();
+    }
+    Ok(())
+}
+
+/// 应用内麦克风开关；未开启时听写直接拒绝
+#[tauri::command]
+pub fn set_microphone_allowed(allowed: bool) {
+    MICROPHONE_ALLOWED.store(allowed, Ordering::SeqCst);
+}
+
+/// 一次性听写（按住说话）；识别结果通过事件返回，
+/// `to_clipboard` 为 true 时同时写入剪贴板历史
+#[tauri::command]
+pub async fn start_dictation(app: AppHandle, to_clipboard: Option<bool>) -> Result<(), String> {
+    if !MICROPHONE_ALLOWED.load(Ordering::SeqCst) {
+        return Err("麦克风未授权，请先在设置中开启".into());
+    }
+
+    let text = tauri::async_runtime::spawn_blocking(recognize_once)
+        .await
+        .map_err(|e| format!("听写任务异常: {}", e))??;
+
+    if text.trim().is_empty() {
+        return Err("没有识别到语音".into());
+    }
+    if to_clipboard.unwrap_or(false) {
+        let _ = crate::services::emotes::copy_emote(text.clone(), "text".into());
+    }
+    let _ = app.emit(DICTATION_EVENT, serde_json::json!({ "text": text }));
+    Ok(())
+}
+
+/// 阻塞的单次识别；平台不可用时报带指引的错误
+fn recognize_once() -> Result<String, String> {
+    #[cfg(target_os = "macos")]
+    {
+        // hear(1)：macOS 上常用的语音识别 CLI，封装系统听写
+        let output = Command::new("hear")
+            .args(["-d", "-t", "8"])
+            .output()
+            .map_err(|_| "未找到 hear 命令，请先通过 Homebrew 安装（brew install hear）".to_string())?;
+        if !output.status.success() {
+            return Err("语音识别失败".into());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let script = "Add-Type -AssemblyName System.Speech; \
+            $r = New-Object System.Speech.Recognition.SpeechRecognitionEngine; \
+            $r.SetInputToDefaultAudioDevice(); \
+            $r.LoadGrammar((New-Object System.Speech.Recognition.DictationGrammar)); \
+            $result = $r.Recognize([TimeSpan]::FromSeconds(8)); \
+            if ($result) { Write-Output $result.Text }";
+        let output = Command::new("powershell")
+            .args(["-NoProfile", "-Command", script])
+            .output()
+            .map_err(|e| format!("启动语音识别失败: {}", e))?;
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+    #[cfg(target_os = "linux")]
+    {
+        Err("当前平台暂不支持听写".into())
+    }
+}
@@ -0,0 +1,204 @@
+//! 拼写检查与纠正
+//!
+//! 词表来自 hunspell 格式的 .dic 文件（`app_data/dictionaries/<lang>.dic`，
+//! 按语言设置加载，词条后的 /flags 被忽略）。纠正采用编辑距离 1 的
+//! 候选生成 + 词频排序；"纠正剪贴板/选中文本"动作返回纠正后的全文
+//! 和逐词 diff 摘要。中文等无空格语言的词不做处理。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+/// 单词长度上限；超长 token（URL、hash 等）不参与检查
+const MAX_WORD_LEN: usize = 24;
+
+#[derive(Debug, Default)]
+struct Dictionary {
+    language: String,
+    /// 小写词表；.dic 行序作为粗略词频（越靠前越常见）
+    words: HashSet<String>,
+    rank: HashMap<String, usize>,
+}
+
+static DICT: Lazy<RwLock<Dictionary>> = Lazy::new(|| RwLock::new(Dictionary::default()));
+
+/// 单处纠正
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Correction {
+    pub original: String,
+    pub corrected: String,
+    /// 在原文中的字符偏移
+    pub offset: usize,
+}
+
+/// 纠正结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellFixResult {
+    pub text: String,
+    pub corrections: Vec<Correction>,
+    /// 词典未加载时为 false，前端提示用户先下载词典
+    pub dictionary_loaded: bool,
+}
+
+/// 加载语言词典；找不到文件时报错并保持原词典
+#[tauri::command]
+pub fn load_spell_dictionary(app: AppHandle, language: String) -> Result<usize, String> {
+    let path = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("dictionaries")
+        .join(format!("{}.dic", language));
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("读取词典 {} 失败: {}", path.display(), e))?;
+
+    let mut words = HashSet::new();
+    let mut rank = HashMap::new();
+    // 首行可能是 hunspell 的词条计数，数字行直接跳过
+    for (idx, line) in content.lines().enumerate() {
+        let entry = line.split('/').next().unwrap_or("").trim();
+        if entry.is_empty() || entry.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        let lower = entry.to_lowercase();
+        rank.entry(lower.clone()).or_insert(idx);
+        words.insert(lower);
+    }
+    let count = words.len();
+    let mut dict = DICT.write().map_err(|e| e.to_string())?;
+    *dict = Dictionary {
+        language,
+        words,
+        rank,
+    };
+    log::info!("[Spellcheck] loaded {} words for '{}'", count, dict.language);
+    Ok(count)
+}
+
+/// 编辑距离 1 的候选（Norvig 风格），按词频取最优
+fn best_correction(dict: &Dictionary, word_lower: &str) -> Option<String> {
+    let chars: Vec<char> = word_lower.chars().collect();
+    let alphabet = "abcdefghijklmnopqrstuvwxyz'";
+    let mut best: Option<(usize, String)> = None;
+    let mut consider = |candidate: String| {
+        if let Some(&r) = dict.rank.get(&candidate) {
+            if best.as_ref().map_or(true, |(br, _)| r < *br) {
+                best = Some((r, candidate));
+            }
+        }
+    };
+
+    for i in 0..chars.len() {
+        // 删除
+        let mut deleted: String = chars[..i].iter().collect();
+        deleted.extend(&chars[i + 1..]);
+        consider(deleted);
+        // 替换
+        for c in alphabet.chars() {
+            let mut replaced: String = chars[..i].iter().collect();
+            replaced.push(c);
+            replaced.extend(&chars[i + 1..]);
+            consider(replaced);
+        }
+        // 相邻交换
+        if i + 1 < chars.len() {
+            let mut swapped = chars.clone();
+            swapped.swap(i, i + 1);
+            consider(swapped.into_iter().collect());
+        }
+    }
+    // 插入
+    for i in 0..=chars.len() {
+        for c in alphabet.chars() {
+            let mut inserted: String = chars[..i].iter().collect();
+            inserted.push(c);
+            inserted.extend(&chars[i..]);
+            consider(inserted);
+        }
+    }
+    best.map(|(_, w)| w)
+}
+
+/// 保持原词的大小写形态套用纠正
+fn apply_case(original: &str, corrected: &str) -> String {
+    if original.chars().all(|c| c.is_uppercase()) {
+        corrected.to_uppercase()
+    } else if original.chars().next().map_or(false, |c| c.is_uppercase()) {
+        let mut chars = corrected.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            None => String::new(),
+        }
+    } else {
+        corrected.to_string()
+    }
+}
+
+/// 纠正整段文本；返回纠正后文本与 diff 摘要
+#[tauri::command]
+pub fn fix_spelling(text: String) -> Result<SpellFixResult, String> {
+    let dict = DICT.read().map_err(|e| e.to_string())?;
+    if dict.words.is_empty() {
+        return Ok(SpellFixResult {
+            text,
+            corrections: Vec::new(),
+            dictionary_loaded: false,
+        });
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut corrections = Vec::new();
+    let mut word = String::new();
+    let mut word_start = 0usize;
+    let mut offset = 0usize;
+
+    let mut flush = |word: &mut String, word_start: usize, output: &mut String,
+                     corrections: &mut Vec<Correction>| {
+        if word.is_empty() {
+            return;
+        }
+        let lower = word.to_lowercase();
+        let fixable = word.chars().all(|c| c.is_ascii_alphabetic() || c == '\'')
+            && word.len() <= MAX_WORD_LEN
+            && !dict.words.contains(&lower);
+        if fixable {
+            if let Some(corrected) = best_correction(&dict, &lower) {
+                let cased = apply_case(word, &corrected);
+                corrections.push(Correction {
+                    original: word.clone(),
+                    corrected: cased.clone(),
+                    offset: word_start,
+                });
+                output.push_str(&cased);
+                word.clear();
+                return;
+            }
+        }
+        output.push_str(word);
+        word.clear();
+    };
+
+    for ch in text.chars() {
+        if ch.is_alphanumeric() || ch == '\'' {
+            if word.is_empty() {
+                word_start = offset;
+            }
+            word.push(ch);
+        } else {
+            flush(&mut word, word_start, &mut output, &mut corrections);
+            output.push(ch);
+        }
+        offset += ch.len_utf8();
+    }
+    flush(&mut word, word_start, &mut output, &mut corrections);
+
+    Ok(SpellFixResult {
+        text: output,
+        corrections,
+        dictionary_loaded: true,
+    })
+}
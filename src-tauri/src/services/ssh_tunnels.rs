@@ -0,0 +1,262 @@
+//! SSH 隧道 / 端口转发
+//!
+//! 命名的本地端口转发定义（`app_data/ssh-tunnels.json`），启动即
+//! `ssh -N -L` 子进程并登记在运行表里，停止即杀进程。隧道以可切换
+//! 的搜索结果出现：已运行的显示「停止」，未运行的显示「启动」。
+//! 认证完全交给用户的 ssh 配置（密钥/agent），`BatchMode=yes` 保证
+//! 不会在后台卡在密码提示上。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::process::{Child, Command};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+/// 持久化文件名
+const STORE_FILE: &str = "ssh-tunnels.json";
+
+/// 一条隧道定义
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TunnelSpec {
+    /// 唯一名称（运行表的 key）
+    pub name: String,
+    /// ssh 目标，形如 host 或 user@host（可以是 ssh config 里的别名）
+    pub destination: String,
+    pub local_port: u16,
+    pub remote_host: String,
+    pub remote_port: u16,
+}
+
+/// 带运行状态的隧道（list 命令返回）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TunnelStatus {
+    #[serde(flatten)]
+    pub spec: TunnelSpec,
+    pub running: bool,
+}
+
+static TUNNELS: Lazy<Mutex<HashMap<String, TunnelSpec>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+/// 运行中的 ssh 子进程；drop 前必须 kill，否则转发会残留
+static RUNNING: Lazy<Mutex<HashMap<String, Child>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn store_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join(STORE_FILE))
+}
+
+fn save(app: &AppHandle) -> Result<(), String> {
+    let specs: Vec<TunnelSpec> = TUNNELS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .values()
+        .cloned()
+        .collect();
+    std::fs::write(
+        store_path(app)?,
+        serde_json::to_string_pretty(&specs).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| format!("保存隧道配置失败: {}", e))
+}
+
+/// 启动时加载隧道定义（不自动拉起隧道）
+pub fn load(app: &AppHandle) {
+    // 钩子必须先于早退注册：首次运行没有存储文件，但本会话新建并
+    // 启动的隧道同样要在退出时被停掉
+    crate::app::shutdown::register_hook("ssh-tunnels", || {
+        stop_all();
+        Ok(())
+    });
+    let Ok(path) = store_path(app) else { return };
+    let Ok(content) = std::fs::read_to_string(&path) else { return };
+    let Ok(specs) = serde_json::from_str::<Vec<TunnelSpec>>(&content) else { return };
+    if let Ok(mut map) = TUNNELS.lock() {
+        for spec in specs {
+            map.insert(spec.name.clone(), spec);
+        }
+        log::info!("[SshTunnels] loaded {} tunnel definitions", map.len());
+    }
+}
+
+/// 清理已退出的子进程并返回该隧道是否仍在运行
+fn is_running(name: &str) -> bool {
+    let Ok(mut running) = RUNNING.lock() else { return false };
+    if let Some(child) = running.get_mut(name) {
+        match child.try_wait() {
+            Ok(None) => return true,
+            // 已退出（转发失败/连接断开），从运行表摘除
+            _ => {
+                running.remove(name);
+            }
+        }
+    }
+    false
+}
+
+/// 新增隧道定义
+#[tauri::command]
+pub fn add_ssh_tunnel(app: AppHandle, spec: TunnelSpec) -> Result<(), String> {
+    if spec.name.trim().is_empty() || spec.destination.trim().is_empty() {
+        return Err("隧道名称和目标不能为空".into());
+    }
+    // 目标只允许 [user@]host 形态，防止拼进额外的 ssh 参数
+    if spec.destination.starts_with('-') || spec.destination.chars().any(char::is_whitespace) {
+        return Err("ssh 目标格式不合法".into());
+    }
+    {
+        let mut map = TUNNELS.lock().map_err(|e| e.to_string())?;
+        if map.contains_key(&spec.name) {
+            return Err(format!("隧道 '{}' 已存在", spec.name));
+        }
+        map.insert(spec.name.clone(), spec);
+    }
+    save(&app)
+}
+
+/// 删除隧道定义；运行中的先停止
+#[tauri::command]
+pub fn delete_ssh_tunnel(app: AppHandle, name: String) -> Result<(), String> {
+    let _ = stop_ssh_tunnel(name.clone());
+    {
+        let mut map = TUNNELS.lock().map_err(|e| e.to_string())?;
+        if map.remove(&name).is_none() {
+            return Err(format!("隧道 '{}' 不存在", name));
+        }
+    }
+    save(&app)
+}
+
+/// 列出全部隧道及运行状态
+#[tauri::command]
+pub fn list_ssh_tunnels() -> Vec<TunnelStatus> {
+    let specs: Vec<TunnelSpec> = TUNNELS
+        .lock()
+        .map(|m| m.values().cloned().collect())
+        .unwrap_or_default();
+    let mut out: Vec<TunnelStatus> = specs
+        .into_iter()
+        .map(|spec| {
+            let running = is_running(&spec.name);
+            TunnelStatus { spec, running }
+        })
+        .collect();
+    out.sort_by(|a, b| a.spec.name.cmp(&b.spec.name));
+    out
+}
+
+/// 启动隧道：`ssh -N -L local:remote_host:remote_port destination`
+#[tauri::command]
+pub fn start_ssh_tunnel(app: AppHandle, name: String) -> Result<(), String> {
+    if is_running(&name) {
+        return Err(format!("隧道 '{}' 已在运行", name));
+    }
+    let spec = TUNNELS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| format!("隧道 '{}' 不存在", name))?;
+
+    let forward = format!(
+        "{}:{}:{}",
+        spec.local_port, spec.remote_host, spec.remote_port
+    );
+    let child = Command::new("ssh")
+        .args([
+            "-N",
+            "-L",
+            &forward,
+            "-o",
+            "ExitOnForwardFailure=yes",
+            "-o",
+            "BatchMode=yes",
+            &spec.destination,
+        ])
+        .spawn()
+        .map_err(|e| format!("启动 ssh 失败: {}", e))?;
+    RUNNING
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(name.clone(), child);
+    log::info!("[SshTunnels] started tunnel '{}' ({})", name, forward);
+    crate::services::audit_log::record(&app, "sshTunnelStart", &name);
+    Ok(())
+}
+
+/// 停止隧道：杀掉对应的 ssh 子进程
+#[tauri::command]
+pub fn stop_ssh_tunnel(name: String) -> Result<(), String> {
+    let mut running = RUNNING.lock().map_err(|e| e.to_string())?;
+    let Some(mut child) = running.remove(&name) else {
+        return Err(format!("隧道 '{}' 未在运行", name));
+    };
+    child.kill().map_err(|e| format!("停止 ssh 失败: {}", e))?;
+    let _ = child.wait();
+    log::info!("[SshTunnels] stopped tunnel '{}'", name);
+    Ok(())
+}
+
+/// 退出前停掉全部隧道（shutdown 钩子调用）
+pub fn stop_all() {
+    let Ok(mut running) = RUNNING.lock() else { return };
+    for (name, mut child) in running.drain() {
+        if child.kill().is_ok() {
+            let _ = child.wait();
+            log::info!("[SshTunnels] stopped tunnel '{}' on shutdown", name);
+        }
+    }
+}
+
+/// 隧道 provider：结果标题带当前状态，选中即切换启停
+pub struct SshTunnelProvider;
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for SshTunnelProvider {
+    fn name(&self) -> &str {
+        "ssh-tunnels"
+    }
+
+    fn priority(&self) -> i32 {
+        40
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        list_ssh_tunnels()
+            .into_iter()
+            .filter_map(|status| {
+                let score = crate::search::fuzzy::score(query, &status.spec.name)?;
+                let action = if status.running { "stop" } else { "start" };
+                Some(crate::search::pipeline::SearchResult {
+                    id: format!("ssh-tunnel:{}", status.spec.name),
+                    title: format!(
+                        "{}（{}）",
+                        status.spec.name,
+                        if status.running { "运行中" } else { "已停止" }
+                    ),
+                    subtitle: Some(format!(
+                        "localhost:{} → {}:{}",
+                        status.spec.local_port, status.spec.remote_host, status.spec.remote_port
+                    )),
+                    icon: None,
+                    provider: String::new(),
+                    score,
+                    payload: serde_json::json!({
+                        "tunnel": status.spec.name,
+                        "action": action,
+                    }),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 注册隧道 provider（启动时调用）
+pub fn register() {
+    crate::search::pipeline::register_provider(std::sync::Arc::new(SshTunnelProvider));
+}
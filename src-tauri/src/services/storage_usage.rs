@@ -0,0 +1,106 @@
+//! 磁盘占用统计与分类清理
+//!
+//! `get_storage_usage` 汇报插件、剪贴板历史（含图片 blob）、文件索引、
+//! 日志和缓存各占多少空间；`purge_storage_category` 允许用户在应用内
+//! 按类别回收空间。
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// 单个类别的占用情况
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryUsage {
+    /// "plugins" / "clipboard" / "fileIndex" / "logs" / "caches"
+    pub category: String,
+    pub bytes: u64,
+    pub file_count: u64,
+    /// 该类别是否支持应用内清理
+    pub purgeable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageUsageReport {
+    pub total_bytes: u64,
+    pub categories: Vec<CategoryUsage>,
+}
+
+/// 递归统计目录大小与文件数；目录不存在按 0 计
+fn dir_size(path: &Path) -> (u64, u64) {
+    let mut bytes = 0u64;
+    let mut files = 0u64;
+    let Ok(entries) = fs::read_dir(path) else {
+        return (0, 0);
+    };
+    for entry in entries.flatten() {
+        let Ok(meta) = entry.metadata() else { continue };
+        if meta.is_dir() {
+            let (b, f) = dir_size(&entry.path());
+            bytes += b;
+            files += f;
+        } else {
+            bytes += meta.len();
+            files += 1;
+        }
+    }
+    (bytes, files)
+}
+
+/// 各类别对应的数据目录
+fn category_paths(app: &AppHandle) -> Result<Vec<(String, PathBuf, bool)>, String> {
+    let data_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    let log_dir = app.path().app_log_dir().map_err(|e| e.to_string())?;
+    let cache_dir = app.path().app_cache_dir().map_err(|e| e.to_string())?;
+    Ok(vec![
+        // 插件目录清理会破坏安装状态，只统计不提供 purge
+        ("plugins".into(), data_dir.join("plugins"), false),
+        ("clipboard".into(), data_dir.join("clipboard"), true),
+        ("fileIndex".into(), data_dir.join("file-index"), true),
+        ("logs".into(), log_dir, true),
+        ("caches".into(), cache_dir, true),
+    ])
+}
+
+/// 统计各类别磁盘占用
+#[tauri::command]
+pub fn get_storage_usage(app: AppHandle) -> Result<StorageUsageReport, String> {
+    let mut categories = Vec::new();
+    let mut total = 0u64;
+    for (category, path, purgeable) in category_paths(&app)? {
+        let (bytes, file_count) = dir_size(&path);
+        total += bytes;
+        categories.push(CategoryUsage {
+            category,
+            bytes,
+            file_count,
+            purgeable,
+        });
+    }
+    // 市场离线缓存挂在 app_data 下，单独归入 caches
+    Ok(StorageUsageReport {
+        total_bytes: total,
+        categories,
+    })
+}
+
+/// 清空某个类别的数据目录（仅限 purgeable 类别）
+#[tauri::command]
+pub fn purge_storage_category(app: AppHandle, category: String) -> Result<u64, String> {
+    let (_, path, purgeable) = category_paths(&app)?
+        .into_iter()
+        .find(|(c, _, _)| *c == category)
+        .ok_or_else(|| format!("未知存储类别: {}", category))?;
+    if !purgeable {
+        return Err(format!("类别 {} 不支持应用内清理", category));
+    }
+    let (freed, _) = dir_size(&path);
+    if path.exists() {
+        fs::remove_dir_all(&path).map_err(|e| format!("清理 {} 失败: {}", category, e))?;
+        fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+    }
+    log::info!("[Storage] purged category {} ({} bytes)", category, freed);
+    Ok(freed)
+}
@@ -0,0 +1,256 @@
+//! 系统命令
+//!
+//! 锁屏、睡眠、重启、关机、清空废纸篓、切换深色模式、弹出外置卷——
+//! 以内置结果出现在统一搜索里，按平台走各自的系统接口/CLI。
+//! 重启/关机/清空废纸篓属于破坏性动作，默认隐藏，由设置项
+//! `destructive_system_commands_enabled` 显式放开；执行一律记审计。
+
+use serde::Serialize;
+use std::process::Command;
+use tauri::AppHandle;
+
+/// 一条系统命令的目录项
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemCommand {
+    /// 稳定 id，如 "system.lock"
+    pub id: &'static str,
+    pub title: &'static str,
+    /// 额外的匹配关键词（英文别名）
+    pub keywords: &'static str,
+    pub destructive: bool,
+}
+
+/// 全部系统命令；id 一经发布不再改动
+const CATALOG: &[SystemCommand] = &[
+    SystemCommand {
+        id: "system.lock",
+        title: "锁定屏幕",
+        keywords: "lock screen",
+        destructive: false,
+    },
+    SystemCommand {
+        id: "system.sleep",
+        title: "睡眠",
+        keywords: "sleep suspend",
+        destructive: false,
+    },
+    SystemCommand {
+        id: "system.restart",
+        title: "重新启动",
+        keywords: "restart reboot",
+        destructive: true,
+    },
+    SystemCommand {
+        id: "system.shutdown",
+        title: "关机",
+        keywords: "shutdown power off",
+        destructive: true,
+    },
+    SystemCommand {
+        id: "system.empty-trash",
+        title: "清空废纸篓",
+        keywords: "empty trash recycle bin",
+        destructive: true,
+    },
+    SystemCommand {
+        id: "system.toggle-dark-mode",
+        title: "切换深色模式",
+        keywords: "dark mode theme",
+        destructive: false,
+    },
+    SystemCommand {
+        id: "system.eject-volumes",
+        title: "弹出所有外置卷",
+        keywords: "eject volumes disk",
+        destructive: false,
+    },
+];
+
+fn destructive_enabled() -> bool {
+    crate::settings::store::get("destructive_system_commands_enabled")
+        .ok()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// 运行一条系统命令行；失败带上 stderr
+fn run(program: &str, args: &[&str]) -> Result<(), String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("启动 {} 失败: {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} 失败: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn dispatch(id: &str) -> Result<(), String> {
+    match id {
+        "system.lock" => run(
+            "/System/Library/CoreServices/Menu Extras/User.menu/Contents/Resources/CGSession",
+            &["-suspend"],
+        ),
+        "system.sleep" => run("pmset", &["sleepnow"]),
+        "system.restart" => run(
+            "osascript",
+            &["-e", "tell application \"System Events\" to restart"],
+        ),
+        "system.shutdown" => run(
+            "osascript",
+            &["-e", "tell application \"System Events\" to shut down"],
+        ),
+        "system.empty-trash" => run(
+            "osascript",
+            &["-e", "tell application \"Finder\" to empty trash"],
+        ),
+        "system.toggle-dark-mode" => run(
+            "osascript",
+            &[
+                "-e",
+                "tell application \"System Events\" to tell appearance preferences to set dark mode to not dark mode",
+            ],
+        ),
+        "system.eject-volumes" => run(
+            "osascript",
+            &[
+                "-e",
+                "tell application \"Finder\" to eject (every disk whose ejectable is true)",
+            ],
+        ),
+        other => Err(format!("未知的系统命令: {}", other)),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn dispatch(id: &str) -> Result<(), String> {
+    match id {
+        "system.lock" => run("rundll32.exe", &["user32.dll,LockWorkStation"]),
+        "system.sleep" => run("rundll32.exe", &["powrprof.dll,SetSuspendState", "0,1,0"]),
+        "system.restart" => run("shutdown", &["/r", "/t", "0"]),
+        "system.shutdown" => run("shutdown", &["/s", "/t", "0"]),
+        "system.empty-trash" => run(
+            "powershell",
+            &["-NoProfile", "-Command", "Clear-RecycleBin -Force -ErrorAction SilentlyContinue"],
+        ),
+        "system.toggle-dark-mode" => {
+            // AppsUseLightTheme：0 深色 / 1 浅色，读出后取反写回
+            use winreg::enums::HKEY_CURRENT_USER;
+            let key = winreg::RegKey::predef(HKEY_CURRENT_USER)
+                .open_subkey_with_flags(
+                    "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+                    winreg::enums::KEY_READ | winreg::enums::KEY_WRITE,
+                )
+                .map_err(|e| format!("打开主题注册表失败: {}", e))?;
+            let light: u32 = key.get_value("AppsUseLightTheme").unwrap_or(1);
+            let next = if light == 0 { 1u32 } else { 0u32 };
+            key.set_value("AppsUseLightTheme", &next)
+                .map_err(|e| format!("写入主题注册表失败: {}", e))?;
+            key.set_value("SystemUsesLightTheme", &next)
+                .map_err(|e| format!("写入主题注册表失败: {}", e))?;
+            Ok(())
+        }
+        "system.eject-volumes" => Err("Windows 上请从资源管理器弹出设备".into()),
+        other => Err(format!("未知的系统命令: {}", other)),
+    }
+}
+
+#[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+fn dispatch(id: &str) -> Result<(), String> {
+    match id {
+        "system.lock" => run("loginctl", &["lock-session"]),
+        "system.sleep" => run("systemctl", &["suspend"]),
+        "system.restart" => run("systemctl", &["reboot"]),
+        "system.shutdown" => run("systemctl", &["poweroff"]),
+        "system.empty-trash" => run("gio", &["trash", "--empty"]),
+        "system.toggle-dark-mode" => {
+            let output = Command::new("gsettings")
+                .args(["get", "org.gnome.desktop.interface", "color-scheme"])
+                .output()
+                .map_err(|e| format!("启动 gsettings 失败: {}", e))?;
+            let current = String::from_utf8_lossy(&output.stdout);
+            let next = if current.contains("prefer-dark") {
+                "'default'"
+            } else {
+                "'prefer-dark'"
+            };
+            run(
+                "gsettings",
+                &["set", "org.gnome.desktop.interface", "color-scheme", next],
+            )
+        }
+        "system.eject-volumes" => Err("当前桌面环境不支持批量弹出，请用文件管理器".into()),
+        other => Err(format!("未知的系统命令: {}", other)),
+    }
+}
+
+/// 列出当前可见的系统命令（破坏性开关关闭时不含破坏性项）
+#[tauri::command]
+pub fn list_system_commands() -> Vec<SystemCommand> {
+    let allow_destructive = destructive_enabled();
+    CATALOG
+        .iter()
+        .filter(|c| allow_destructive || !c.destructive)
+        .cloned()
+        .collect()
+}
+
+/// 执行系统命令；破坏性动作需开关已打开
+#[tauri::command]
+pub async fn execute_system_command(app: AppHandle, id: String) -> Result<(), String> {
+    let command = CATALOG
+        .iter()
+        .find(|c| c.id == id)
+        .ok_or_else(|| format!("未知的系统命令: {}", id))?;
+    if command.destructive && !destructive_enabled() {
+        return Err("破坏性系统命令未开启，请在设置中打开 destructive_system_commands_enabled".into());
+    }
+    crate::services::audit_log::record(&app, "systemCommand", &id);
+    tauri::async_runtime::spawn_blocking(move || dispatch(&id))
+        .await
+        .map_err(|e| format!("系统命令任务异常: {}", e))?
+}
+
+/// 系统命令 provider：中文标题 + 英文别名双路匹配
+pub struct SystemCommandsProvider;
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for SystemCommandsProvider {
+    fn name(&self) -> &str {
+        "system-commands"
+    }
+
+    fn priority(&self) -> i32 {
+        15
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        list_system_commands()
+            .into_iter()
+            .filter_map(|command| {
+                let score = crate::services::pinyin_matcher::score(query, command.title)
+                    .max(crate::search::fuzzy::score(query, command.keywords))?;
+                Some(crate::search::pipeline::SearchResult {
+                    id: command.id.to_string(),
+                    title: command.title.to_string(),
+                    subtitle: command.destructive.then(|| "破坏性操作".to_string()),
+                    icon: None,
+                    provider: String::new(),
+                    score,
+                    payload: serde_json::json!({ "systemCommandId": command.id }),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 注册系统命令 provider（启动时调用）
+pub fn register() {
+    crate::search::pipeline::register_provider(std::sync::Arc::new(SystemCommandsProvider));
+}
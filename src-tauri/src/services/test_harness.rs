@@ -0,0 +1,166 @@
+//! 测试夹具模式（录制/回放）
+//!
+//! 集成测试不该碰真实网络、npm 和系统集成。设 `ETOOLS_MOCK_MODE=replay`
+//! 时，HTTP 与 npm 等出口改为读 `ETOOLS_FIXTURE_DIR` 下的夹具文件；
+//! `=record` 时走真实调用并把结果落成夹具，便于一次录制、长期回放。
+//! 夹具按 `<category>/<key 的 SHA-256>.json` 存放，key 通常是完整 URL
+//! 或命令行。生产构建中两个环境变量都不设，所有钩子都是零开销直通。
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+/// 夹具模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockMode {
+    Off,
+    Record,
+    Replay,
+}
+
+/// 读取当前模式（每次读 env，测试进程内可以切换）
+pub fn mode() -> MockMode {
+    match std::env::var("ETOOLS_MOCK_MODE").as_deref() {
+        Ok("record") => MockMode::Record,
+        Ok("replay") => MockMode::Replay,
+        _ => MockMode::Off,
+    }
+}
+
+fn fixture_dir() -> Option<PathBuf> {
+    std::env::var("ETOOLS_FIXTURE_DIR").ok().map(PathBuf::from)
+}
+
+fn fixture_path(category: &str, key: &str) -> Option<PathBuf> {
+    let safe_category: String = category
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    Some(
+        fixture_dir()?
+            .join(safe_category)
+            .join(format!("{:x}.json", Sha256::digest(key.as_bytes()))),
+    )
+}
+
+/// 回放模式下读取夹具；未命中时报错（回放环境不允许打真实请求）
+pub fn replay(category: &str, key: &str) -> Result<Value, String> {
+    let path = fixture_path(category, key)
+        .ok_or("回放模式需要设置 ETOOLS_FIXTURE_DIR")?;
+    let content = std::fs::read_to_string(&path).map_err(|_| {
+        format!(
+            "缺少夹具 {}（category={}, key={}）",
+            path.display(),
+            category,
+            key
+        )
+    })?;
+    let fixture: Value =
+        serde_json::from_str(&content).map_err(|e| format!("夹具损坏: {}", e))?;
+    Ok(fixture
+        .get("response")
+        .cloned()
+        .unwrap_or(fixture))
+}
+
+/// 录制一条夹具；key 与响应都写入文件，便于人工检查
+pub fn record_fixture(category: &str, key: &str, response: &Value) -> Result<(), String> {
+    let path = fixture_path(category, key)
+        .ok_or("录制模式需要设置 ETOOLS_FIXTURE_DIR")?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let fixture = serde_json::json!({
+        "category": category,
+        "key": key,
+        "recordedAt": chrono::Utc::now().to_rfc3339(),
+        "response": response,
+    });
+    std::fs::write(
+        &path,
+        serde_json::to_string_pretty(&fixture).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| format!("写入夹具失败: {}", e))?;
+    log::info!("[TestHarness] recorded fixture {}/{}", category, key);
+    Ok(())
+}
+
+/// 把夹具里的 `{ "相对路径": "文本内容" }` 映射展开到目标目录
+/// （npm 安装等需要落盘产物的回放用）
+pub fn expand_files(target: &std::path::Path, fixture: &Value) -> Result<(), String> {
+    let files = fixture
+        .as_object()
+        .ok_or("夹具不是 路径→内容 的对象")?;
+    for (rel, content) in files {
+        if rel.contains("..") || std::path::Path::new(rel).is_absolute() {
+            return Err(format!("夹具路径不合法: {}", rel));
+        }
+        let dest = target.join(rel);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(&dest, content.as_str().unwrap_or_default())
+            .map_err(|e| format!("展开夹具文件 {} 失败: {}", rel, e))?;
+    }
+    Ok(())
+}
+
+/// 采集目录下的文本文件为 路径→内容 映射（录制落盘产物用）
+pub fn collect_files(dir: &std::path::Path) -> Result<Value, String> {
+    let mut files = serde_json::Map::new();
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(current) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&current) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if let Ok(content) = std::fs::read_to_string(&path) {
+                let rel = path
+                    .strip_prefix(dir)
+                    .map_err(|e| e.to_string())?
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                files.insert(rel, Value::String(content));
+            }
+        }
+    }
+    Ok(Value::Object(files))
+}
+
+/// 查询当前夹具模式（集成测试启动时自检用）
+#[tauri::command]
+pub fn get_mock_mode() -> String {
+    match mode() {
+        MockMode::Off => "off".into(),
+        MockMode::Record => "record".into(),
+        MockMode::Replay => "replay".into(),
+    }
+}
+
+/// 测试脚本手工录制一条夹具；生产模式（Off）下拒绝
+#[tauri::command]
+pub fn harness_record_fixture(category: String, key: String, response: Value) -> Result<(), String> {
+    if mode() == MockMode::Off {
+        return Err("未开启夹具模式，拒绝写入夹具".into());
+    }
+    record_fixture(&category, &key, &response)
+}
+
+/// 包装一次可 mock 的 JSON 调用：
+/// 回放直接读夹具；录制先执行真实调用再落盘；关闭时直通
+pub async fn with_fixture<F, Fut>(category: &str, key: &str, real: F) -> Result<Value, String>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<Value, String>>,
+{
+    match mode() {
+        MockMode::Off => real().await,
+        MockMode::Replay => replay(category, key),
+        MockMode::Record => {
+            let response = real().await?;
+            record_fixture(category, key, &response)?;
+            Ok(response)
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! 文本实体检测服务
+//!
+//! 对复制的文本运行轻量级实体检测（邮箱、电话、快递单号、地址等），
+//! 并为每个实体附加可执行的快捷操作（写邮件、tel: 拨号、查快递、打开地图）。
+//! 插件可以通过 `register_text_detector` 注册自定义检测器来扩展。
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+/// 检测到的实体类型
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EntityKind {
+    Email,
+    Phone,
+    TrackingNumber,
+    Address,
+    Url,
+    /// 插件注册的自定义类型，携带插件声明的类型名
+    Custom(String),
+}
+
+/// 附加在实体上的快捷操作
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityAction {
+    /// 操作标识，前端据此渲染图标与快捷键
+    pub id: String,
+    /// 展示给用户的标题
+    pub title: String,
+    /// 执行时打开的 URL（mailto:/tel:/https: 等），`{value}` 会被替换为实体文本
+    pub url_template: String,
+}
+
+/// 一次检测命中的实体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectedEntity {
+    pub kind: EntityKind,
+    /// 命中的原始文本
+    pub value: String,
+    /// 在输入文本中的字节区间
+    pub start: usize,
+    pub end: usize,
+    pub actions: Vec<EntityAction>,
+}
+
+/// 插件注册的检测器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginDetector {
+    /// 注册方插件 ID
+    pub plugin_id: String,
+    /// 自定义实体类型名
+    pub entity_type: String,
+    /// 匹配用正则（在注册时编译校验）
+    pub pattern: String,
+    pub actions: Vec<EntityAction>,
+}
+
+/// 插件检测器注册表
+static PLUGIN_DETECTORS: Lazy<RwLock<Vec<PluginDetector>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+static EMAIL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[A-Za-z0-9._%+\-]+@[A-Za-z0-9.\-]+\.[A-Za-z]{2,}").unwrap());
+static PHONE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:\+?86[\s\-]?)?1[3-9]\d{9}|\+?\d{1,3}[\s\-]?\d{3,4}[\s\-]?\d{4,8}").unwrap());
+static TRACKING_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(?:SF|YT|ZTO|JD|EMS)?\d{10,15}\b").unwrap());
+static URL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"https?://[^\s<>\u{4e00}-\u{9fa5}]+").unwrap());
+// 地址检测只做粗粒度启发：省/市/区 + 后续门牌描述
+static ADDRESS_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[\u{4e00}-\u{9fa5}]{2,8}(?:省|市|区|县)[\u{4e00}-\u{9fa5}0-9\-号栋楼室街道路巷]{4,40}")
+        .unwrap()
+});
+
+fn builtin_actions(kind: &EntityKind) -> Vec<EntityAction> {
+    match kind {
+        EntityKind::Email => vec![EntityAction {
+            id: "compose-email".into(),
+            title: "写邮件".into(),
+            url_template: "mailto:{value}".into(),
+        }],
+        EntityKind::Phone => vec![EntityAction {
+            id: "call".into(),
+            title: "拨打电话".into(),
+            url_template: "tel:{value}".into(),
+        }],
+        EntityKind::TrackingNumber => vec![EntityAction {
+            id: "track-package".into(),
+            title: "查询快递".into(),
+            url_template: "https://www.kuaidi100.com/chaxun?nu={value}".into(),
+        }],
+        EntityKind::Address => vec![EntityAction {
+            id: "open-in-maps".into(),
+            title: "在地图中打开".into(),
+            url_template: "https://uri.amap.com/search?keyword={value}".into(),
+        }],
+        EntityKind::Url => vec![EntityAction {
+            id: "open-url".into(),
+            title: "打开链接".into(),
+            url_template: "{value}".into(),
+        }],
+        EntityKind::Custom(_) => Vec::new(),
+    }
+}
+
+fn collect(re: &Regex, kind: EntityKind, text: &str, out: &mut Vec<DetectedEntity>) {
+    for m in re.find_iter(text) {
+        // 跳过与已有命中重叠的区间，避免快递单号把电话号码重复报一次
+        if out.iter().any(|e| m.start() < e.end && m.end() > e.start) {
+            continue;
+        }
+        let actions = builtin_actions(&kind);
+        out.push(DetectedEntity {
+            kind: kind.clone(),
+            value: m.as_str().to_string(),
+            start: m.start(),
+            end: m.end(),
+            actions,
+        });
+    }
+}
+
+/// 对一段文本运行全部检测器（内置 + 插件注册）
+pub fn detect_entities(text: &str) -> Vec<DetectedEntity> {
+    let mut out = Vec::new();
+    // 顺序即优先级：更具体的类型先匹配
+    collect(&EMAIL_RE, EntityKind::Email, text, &mut out);
+    collect(&URL_RE, EntityKind::Url, text, &mut out);
+    collect(&PHONE_RE, EntityKind::Phone, text, &mut out);
+    collect(&TRACKING_RE, EntityKind::TrackingNumber, text, &mut out);
+    collect(&ADDRESS_RE, EntityKind::Address, text, &mut out);
+
+    if let Ok(detectors) = PLUGIN_DETECTORS.read() {
+        for d in detectors.iter() {
+            if let Ok(re) = Regex::new(&d.pattern) {
+                for m in re.find_iter(text) {
+                    out.push(DetectedEntity {
+                        kind: EntityKind::Custom(d.entity_type.clone()),
+                        value: m.as_str().to_string(),
+                        start: m.start(),
+                        end: m.end(),
+                        actions: d.actions.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    out.sort_by_key(|e| e.start);
+    out
+}
+
+/// 检测文本中的实体并返回附带快捷操作的结果
+#[tauri::command]
+pub fn detect_text_entities(text: String) -> Vec<DetectedEntity> {
+    detect_entities(&text)
+}
+
+/// 插件注册自定义检测器；正则非法时返回错误
+#[tauri::command]
+pub fn register_text_detector(detector: PluginDetector) -> Result<(), String> {
+    Regex::new(&detector.pattern).map_err(|e| format!("检测器正则无效: {}", e))?;
+    let mut detectors = PLUGIN_DETECTORS.write().map_err(|e| e.to_string())?;
+    // 同插件同类型重复注册时覆盖旧定义
+    detectors.retain(|d| !(d.plugin_id == detector.plugin_id && d.entity_type == detector.entity_type));
+    log::info!(
+        "[TextDetector] registered detector '{}' from plugin {}",
+        detector.entity_type,
+        detector.plugin_id
+    );
+    detectors.push(detector);
+    Ok(())
+}
+
+/// 注销某插件注册的全部检测器（插件卸载时调用）
+#[tauri::command]
+pub fn unregister_plugin_detectors(plugin_id: String) -> Result<(), String> {
+    let mut detectors = PLUGIN_DETECTORS.write().map_err(|e| e.to_string())?;
+    detectors.retain(|d| d.plugin_id != plugin_id);
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! 计时器 / 番茄钟
+//!
+//! `timer 25m standup` 这类触发词最终落到这里：命名倒计时在后台
+//! 跑，结束时走 HUD 提示并发 `timer://finished` 事件（前端负责系统
+//! 通知与托盘倒计时）。活动计时器落盘，应用重启后继续；重启时已
+//! 到期的计时器立即补发结束提示。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 计时器结束事件
+pub const TIMER_FINISHED_EVENT: &str = "timer://finished";
+/// 单个计时器上限：24 小时
+const MAX_DURATION_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timer {
+    pub id: String,
+    pub label: String,
+    /// Unix 毫秒
+    pub started_at: i64,
+    pub ends_at: i64,
+}
+
+static TIMERS: Lazy<Mutex<HashMap<String, Timer>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn timers_path(app: &AppHandle) -> Option<std::path::PathBuf> {
+    app.path().app_data_dir().ok().map(|d| d.join("timers.json"))
+}
+
+fn persist(app: &AppHandle) {
+    let Some(path) = timers_path(app) else { return };
+    let Ok(timers) = TIMERS.lock() else { return };
+    if let Ok(json) = serde_json::to_string(&*timers) {
+        if let Err(e) = std::fs::write(&path, json) {
+            log::warn!("[Timers] failed to persist: {}", e);
+        }
+    }
+}
+
+async fn finish_timer(app: AppHandle, id: String) {
+    let timer = {
+        let Ok(mut timers) = TIMERS.lock() else { return };
+        timers.remove(&id)
+    };
+    let Some(timer) = timer else { return }; // 已被取消
+    persist(&app);
+    log::info!("[Timers] '{}' finished", timer.label);
+    let _ = app.emit(TIMER_FINISHED_EVENT, timer.clone());
+    let _ = crate::window::hud::show_hud(
+        app.clone(),
+        format!("⏰ {}", timer.label),
+        None,
+        Some(4000),
+    )
+    .await;
+}
+
+fn spawn_countdown(app: AppHandle, id: String, remaining_ms: i64) {
+    tauri::async_runtime::spawn(async move {
+        if remaining_ms > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(remaining_ms as u64)).await;
+        }
+        finish_timer(app, id).await;
+    });
+}
+
+/// 启动时恢复落盘的计时器；已到期的立即补发提示
+pub fn restore(app: &AppHandle) {
+    let Some(path) = timers_path(app) else { return };
+    let Ok(content) = std::fs::read_to_string(&path) else { return };
+    let Ok(saved) = serde_json::from_str::<HashMap<String, Timer>>(&content) else { return };
+    let now = chrono::Utc::now().timestamp_millis();
+    if let Ok(mut timers) = TIMERS.lock() {
+        *timers = saved.clone();
+    }
+    for (id, timer) in saved {
+        spawn_countdown(app.clone(), id, timer.ends_at - now);
+    }
+}
+
+/// 启动命名倒计时；返回计时器 id
+#[tauri::command]
+pub fn start_timer(app: AppHandle, label: String, duration_secs: u64) -> Result<Timer, String> {
+    if duration_secs == 0 || duration_secs > MAX_DURATION_SECS {
+        return Err("计时时长需在 1 秒到 24 小时之间".into());
+    }
+    let label = if label.trim().is_empty() {
+        "计时结束".to_string()
+    } else {
+        label.trim().to_string()
+    };
+    let now = chrono::Utc::now().timestamp_millis();
+    let timer = Timer {
+        id: format!("timer-{}", now),
+        label,
+        started_at: now,
+        ends_at: now + (duration_secs as i64) * 1000,
+    };
+    {
+        let mut timers = TIMERS.lock().map_err(|e| e.to_string())?;
+        timers.insert(timer.id.clone(), timer.clone());
+    }
+    persist(&app);
+    spawn_countdown(app, timer.id.clone(), (duration_secs as i64) * 1000);
+    Ok(timer)
+}
+
+/// 列出活动计时器（托盘与 `timer` 触发词共用）
+#[tauri::command]
+pub fn list_timers() -> Result<Vec<Timer>, String> {
+    let timers = TIMERS.lock().map_err(|e| e.to_string())?;
+    let mut list: Vec<Timer> = timers.values().cloned().collect();
+    list.sort_by_key(|t| t.ends_at);
+    Ok(list)
+}
+
+/// 取消计时器
+#[tauri::command]
+pub fn cancel_timer(app: AppHandle, id: String) -> Result<(), String> {
+    let removed = {
+        let mut timers = TIMERS.lock().map_err(|e| e.to_string())?;
+        timers.remove(&id)
+    };
+    if removed.is_none() {
+        return Err(format!("计时器 {} 不存在", id));
+    }
+    persist(&app);
+    Ok(())
+}
@@ -0,0 +1,226 @@
+//! tmux 会话集成
+//!
+//! 列出正在运行的 tmux 会话/窗口并在终端里附加到选中的会话；也支持
+//! 在指定目录新建命名会话。附加用的终端按设置项 `terminal_app` 指定，
+//! 留空时取平台默认（macOS Terminal / Linux x-terminal-emulator）。
+//! Windows 上没有原生 tmux，相关命令直接报不支持。属于开发者集成。
+
+use serde::Serialize;
+use std::process::Command;
+
+/// 一个 tmux 会话
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TmuxSession {
+    pub name: String,
+    pub windows: u32,
+    pub attached: bool,
+    /// 窗口名列表（展示在 subtitle 里）
+    pub window_names: Vec<String>,
+}
+
+fn terminal_app() -> Option<String> {
+    crate::settings::store::get("terminal_app")
+        .ok()
+        .and_then(|v| v.as_str().map(|s| s.trim().to_string()))
+        .filter(|s| !s.is_empty())
+}
+
+/// 列出会话；tmux 未安装或无 server 时返回空列表
+fn sessions() -> Result<Vec<TmuxSession>, String> {
+    if cfg!(target_os = "windows") {
+        return Err("Windows 上不支持 tmux 集成".into());
+    }
+    let output = Command::new("tmux")
+        .args([
+            "list-sessions",
+            "-F",
+            "#{session_name}\t#{session_windows}\t#{session_attached}",
+        ])
+        .output()
+        .map_err(|e| format!("启动 tmux 失败: {}", e))?;
+    if !output.status.success() {
+        // 没有运行中的 server 时 tmux 返回非零，按空列表处理
+        return Ok(Vec::new());
+    }
+    let mut out = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let mut parts = line.split('\t');
+        let Some(name) = parts.next() else { continue };
+        let windows = parts.next().and_then(|w| w.parse().ok()).unwrap_or(0);
+        let attached = parts.next().map(|a| a != "0").unwrap_or(false);
+        let window_names = Command::new("tmux")
+            .args(["list-windows", "-t", name, "-F", "#{window_name}"])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| {
+                String::from_utf8_lossy(&o.stdout)
+                    .lines()
+                    .map(|s| s.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        out.push(TmuxSession {
+            name: name.to_string(),
+            windows,
+            attached,
+            window_names,
+        });
+    }
+    Ok(out)
+}
+
+/// 在终端里执行一条命令（tmux attach、需要交互的安装命令等共用）
+pub(crate) fn open_in_terminal(tmux_command: &str) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let app = terminal_app().unwrap_or_else(|| "Terminal".into());
+        let script = format!(
+            "tell application \"{}\" to do script \"{}\"\ntell application \"{}\" to activate",
+            app, tmux_command, app
+        );
+        return Command::new("osascript")
+            .args(["-e", &script])
+            .output()
+            .map_err(|e| format!("启动 osascript 失败: {}", e))
+            .and_then(|o| {
+                if o.status.success() {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "打开终端失败: {}",
+                        String::from_utf8_lossy(&o.stderr).trim()
+                    ))
+                }
+            });
+    }
+    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+    {
+        let terminal = terminal_app()
+            .or_else(|| std::env::var("TERMINAL").ok())
+            .unwrap_or_else(|| "x-terminal-emulator".into());
+        return Command::new(&terminal)
+            .args(["-e", "sh", "-c", tmux_command])
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("启动终端 {} 失败: {}", terminal, e));
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let _ = tmux_command;
+        Err("Windows 上不支持 tmux 集成".into())
+    }
+}
+
+/// 列出全部 tmux 会话
+#[tauri::command]
+pub async fn list_tmux_sessions() -> Result<Vec<TmuxSession>, String> {
+    if !crate::services::containers::developer_integrations_enabled() {
+        return Err("开发者集成未开启，请在设置中打开 developer_integrations_enabled".into());
+    }
+    tauri::async_runtime::spawn_blocking(sessions)
+        .await
+        .map_err(|e| format!("tmux 列表任务异常: {}", e))?
+}
+
+/// 在配置的终端里附加到会话
+#[tauri::command]
+pub fn attach_tmux_session(name: String) -> Result<(), String> {
+    if !crate::services::containers::developer_integrations_enabled() {
+        return Err("开发者集成未开启".into());
+    }
+    if name.contains(char::is_whitespace) || name.contains('"') {
+        return Err("会话名不合法".into());
+    }
+    open_in_terminal(&format!("tmux attach -t {}", name))
+}
+
+/// 在指定目录新建命名会话并附加
+#[tauri::command]
+pub fn create_tmux_session(app: tauri::AppHandle, name: String, directory: String) -> Result<(), String> {
+    if !crate::services::containers::developer_integrations_enabled() {
+        return Err("开发者集成未开启".into());
+    }
+    if name.contains(char::is_whitespace) || name.contains('"') {
+        return Err("会话名不合法".into());
+    }
+    let dir = crate::cmds::fs_guard::check_access(&app, &directory, crate::cmds::fs_guard::Access::Read)?;
+    let output = Command::new("tmux")
+        .args([
+            "new-session",
+            "-d",
+            "-s",
+            &name,
+            "-c",
+            &dir.display().to_string(),
+        ])
+        .output()
+        .map_err(|e| format!("启动 tmux 失败: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "创建会话失败: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    open_in_terminal(&format!("tmux attach -t {}", name))
+}
+
+/// tmux 会话 provider：匹配会话名与窗口名
+pub struct TmuxProvider;
+
+#[async_trait::async_trait]
+impl crate::search::pipeline::SearchProvider for TmuxProvider {
+    fn name(&self) -> &str {
+        "tmux"
+    }
+
+    fn priority(&self) -> i32 {
+        160
+    }
+
+    fn scope_only(&self) -> bool {
+        // 每次查询要跑 list-sessions 加每会话一次 list-windows，只在
+        // tmux: 范围里触发
+        true
+    }
+
+    async fn search(&self, query: &str) -> Vec<crate::search::pipeline::SearchResult> {
+        if !crate::services::containers::developer_integrations_enabled() || query.chars().count() < 2 {
+            return Vec::new();
+        }
+        let Ok(sessions) = list_tmux_sessions().await else {
+            return Vec::new();
+        };
+        sessions
+            .into_iter()
+            .filter_map(|session| {
+                let windows = session.window_names.join(" ");
+                let score = crate::search::fuzzy::score(query, &session.name)
+                    .max(crate::search::fuzzy::score(query, &windows))?;
+                Some(crate::search::pipeline::SearchResult {
+                    id: format!("tmux:{}", session.name),
+                    title: format!(
+                        "tmux: {}{}",
+                        session.name,
+                        if session.attached { "（已附加）" } else { "" }
+                    ),
+                    subtitle: Some(format!("{} 个窗口 ｜ {}", session.windows, windows)),
+                    icon: None,
+                    provider: String::new(),
+                    score,
+                    payload: serde_json::json!({
+                        "session": session.name,
+                        "action": "attach",
+                    }),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 注册 tmux provider 并登记 `tmux:` 范围（启动时调用）
+pub fn register() {
+    crate::search::pipeline::register_provider(std::sync::Arc::new(TmuxProvider));
+    crate::search::scopes::register_scope("tmux:", "tmux", "tmux 会话");
+}
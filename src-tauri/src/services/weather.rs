@@ -0,0 +1,178 @@
+//! 天气提供方
+//!
+//! `weather 上海` 触发词的后端：默认走 Open-Meteo（免 key），也可配置
+//! 带 API key 的自定义后端。结果按城市缓存 15 分钟；网络功能被策略
+//! 禁用时直接报错而不是悄悄请求。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// 缓存有效期
+const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// 天气后端配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeatherBackend {
+    /// "open-meteo" 或自定义后端的基础地址
+    pub provider: String,
+    #[serde(default)]
+    pub api_key: Option<String>,
+}
+
+impl Default for WeatherBackend {
+    fn default() -> Self {
+        Self {
+            provider: "open-meteo".into(),
+            api_key: None,
+        }
+    }
+}
+
+/// 单日预报
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForecastDay {
+    pub date: String,
+    pub temp_min: f64,
+    pub temp_max: f64,
+    /// WMO 天气码
+    pub weather_code: i64,
+}
+
+/// 天气响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeatherReport {
+    pub location: String,
+    pub temperature: f64,
+    pub weather_code: i64,
+    pub wind_speed: f64,
+    pub forecast: Vec<ForecastDay>,
+    /// 是否来自缓存
+    pub cached: bool,
+}
+
+static BACKEND: Lazy<RwLock<WeatherBackend>> = Lazy::new(|| RwLock::new(WeatherBackend::default()));
+static CACHE: Lazy<Mutex<HashMap<String, (Instant, WeatherReport)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Open-Meteo 地理编码：城市名 -> 坐标与规范名称
+async fn geocode(city: &str) -> Result<(f64, f64, String), String> {
+    let encoded: String = url::form_urlencoded::byte_serialize(city.as_bytes()).collect();
+    let url = format!(
+        "https://geocoding-api.open-meteo.com/v1/search?name={}&count=1&language=zh",
+        encoded
+    );
+    let data = crate::marketplace::http_client::get_json(&url).await?;
+    let result = data
+        .get("results")
+        .and_then(|v| v.as_array())
+        .and_then(|a| a.first())
+        .ok_or_else(|| format!("找不到城市 '{}'", city))?;
+    Ok((
+        result.get("latitude").and_then(|v| v.as_f64()).ok_or("响应缺少纬度")?,
+        result.get("longitude").and_then(|v| v.as_f64()).ok_or("响应缺少经度")?,
+        result
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or(city)
+            .to_string(),
+    ))
+}
+
+async fn fetch_open_meteo(city: &str) -> Result<WeatherReport, String> {
+    let (lat, lon, name) = geocode(city).await?;
+    let url = format!(
+        "https://api.open-meteo.com/v1/forecast?latitude={}&longitude={}\
+         &current=temperature_2m,weather_code,wind_speed_10m\
+         &daily=temperature_2m_min,temperature_2m_max,weather_code&forecast_days=3&timezone=auto",
+        lat, lon
+    );
+    let data = crate::marketplace::http_client::get_json(&url).await?;
+    let current = data.get("current").ok_or("响应缺少 current")?;
+    let daily = data.get("daily").ok_or("响应缺少 daily")?;
+
+    let dates = daily.get("time").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+    let mins = daily.get("temperature_2m_min").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+    let maxs = daily.get("temperature_2m_max").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+    let codes = daily.get("weather_code").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+    let forecast = (0..dates.len().min(mins.len()).min(maxs.len()).min(codes.len()))
+        .map(|i| ForecastDay {
+            date: dates[i].as_str().unwrap_or_default().to_string(),
+            temp_min: mins[i].as_f64().unwrap_or(0.0),
+            temp_max: maxs[i].as_f64().unwrap_or(0.0),
+            weather_code: codes[i].as_i64().unwrap_or(0),
+        })
+        .collect();
+
+    Ok(WeatherReport {
+        location: name,
+        temperature: current.get("temperature_2m").and_then(|v| v.as_f64()).unwrap_or(0.0),
+        weather_code: current.get("weather_code").and_then(|v| v.as_i64()).unwrap_or(0),
+        wind_speed: current.get("wind_speed_10m").and_then(|v| v.as_f64()).unwrap_or(0.0),
+        forecast,
+        cached: false,
+    })
+}
+
+/// 自定义后端：约定 GET {base}/weather?city=...&key=... 返回 WeatherReport 形状
+async fn fetch_custom(base: &str, api_key: Option<&str>, city: &str) -> Result<WeatherReport, String> {
+    let encoded: String = url::form_urlencoded::byte_serialize(city.as_bytes()).collect();
+    let mut url = format!("{}/weather?city={}", base.trim_end_matches('/'), encoded);
+    if let Some(key) = api_key {
+        url.push_str(&format!("&key={}", key));
+    }
+    let data = crate::marketplace::http_client::get_json(&url).await?;
+    serde_json::from_value(data).map_err(|e| format!("自定义天气后端响应无法解析: {}", e))
+}
+
+/// 查询天气；15 分钟内同城市直接回缓存
+#[tauri::command]
+pub async fn get_weather(city: String) -> Result<WeatherReport, String> {
+    if crate::services::policy::is_feature_disabled("network") {
+        return Err("网络访问已被禁用".into());
+    }
+    let key = city.trim().to_lowercase();
+    if key.is_empty() {
+        return Err("请输入城市名".into());
+    }
+    if let Ok(cache) = CACHE.lock() {
+        if let Some((at, report)) = cache.get(&key) {
+            if at.elapsed() < CACHE_TTL {
+                let mut cached = report.clone();
+                cached.cached = true;
+                return Ok(cached);
+            }
+        }
+    }
+
+    let backend = BACKEND.read().map_err(|e| e.to_string())?.clone();
+    let report = if backend.provider == "open-meteo" {
+        fetch_open_meteo(city.trim()).await?
+    } else {
+        fetch_custom(&backend.provider, backend.api_key.as_deref(), city.trim()).await?
+    };
+
+    if let Ok(mut cache) = CACHE.lock() {
+        cache.insert(key, (Instant::now(), report.clone()));
+    }
+    Ok(report)
+}
+
+/// 配置天气后端；provider 传 "open-meteo" 或自定义 https 地址
+#[tauri::command]
+pub fn set_weather_backend(provider: String, api_key: Option<String>) -> Result<(), String> {
+    if provider != "open-meteo" && !provider.starts_with("https://") {
+        return Err("自定义天气后端必须是 https 地址".into());
+    }
+    let mut backend = BACKEND.write().map_err(|e| e.to_string())?;
+    *backend = WeatherBackend { provider, api_key };
+    if let Ok(mut cache) = CACHE.lock() {
+        cache.clear();
+    }
+    Ok(())
+}
@@ -0,0 +1,163 @@
+//! 应用设置存储
+//!
+//! 设置以 JSON 文件存放在当前档案目录下，读写都经过
+//! `validation` 模块的约束校验；未写入过的键返回声明的默认值。
+
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::sync::RwLock;
+use tauri::AppHandle;
+
+use super::validation::{self, ValidationError};
+
+const SETTINGS_FILE: &str = "settings.json";
+
+/// 内存缓存；启动时从磁盘加载，写入时同步落盘
+static CACHE: Lazy<RwLock<HashMap<String, Value>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+fn settings_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    Ok(crate::services::profiles::active_data_dir(app)?.join(SETTINGS_FILE))
+}
+
+/// 从磁盘加载设置到内存缓存（启动与切换档案时调用）
+pub fn load(app: &AppHandle) -> Result<(), String> {
+    let path = settings_path(app)?;
+    let values: HashMap<String, Value> = if path.exists() {
+        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            log::warn!("[Settings] settings.json corrupt ({}), starting from defaults", e);
+            HashMap::new()
+        })
+    } else {
+        HashMap::new()
+    };
+    *CACHE.write().map_err(|e| e.to_string())? = values;
+    Ok(())
+}
+
+fn persist(app: &AppHandle) -> Result<(), String> {
+    let path = settings_path(app)?;
+    let cache = CACHE.read().map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(&*cache).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())
+}
+
+/// 读取设置；未写入过返回默认值
+pub fn get(key: &str) -> Result<Value, ValidationError> {
+    let constraint = validation::constraint_for(key).ok_or_else(|| ValidationError {
+        key: key.to_string(),
+        code: "unknownKey".into(),
+        message: format!("未知设置项: {}", key),
+    })?;
+    // 锁定的设置永远返回策略强制值
+    if let Some(locked) = crate::services::policy::locked_value(key) {
+        return Ok(locked.clone());
+    }
+    Ok(CACHE
+        .read()
+        .ok()
+        .and_then(|c| c.get(key).cloned())
+        .unwrap_or_else(|| (constraint.default)()))
+}
+
+/// 写入设置（内部路径，供导入器等复用）
+pub fn set(app: &AppHandle, key: &str, value: Value) -> Result<(), ValidationError> {
+    // 托管策略锁定的设置拒绝写入
+    if crate::services::policy::locked_value(key).is_some() {
+        return Err(ValidationError {
+            key: key.to_string(),
+            code: "lockedByPolicy".into(),
+            message: format!("设置 {} 已被管理员策略锁定", key),
+        });
+    }
+    validation::validate(key, &value)?;
+    if let Ok(mut cache) = CACHE.write() {
+        cache.insert(key.to_string(), value);
+    }
+    if let Err(e) = persist(app) {
+        log::error!("[Settings] failed to persist settings: {}", e);
+    }
+    Ok(())
+}
+
+/// 读取单个设置
+#[tauri::command]
+pub fn get_setting(key: String) -> Result<Value, ValidationError> {
+    get(&key)
+}
+
+/// 写入单个设置；校验失败返回结构化错误
+#[tauri::command]
+pub fn set_setting(app: AppHandle, key: String, value: Value) -> Result<(), ValidationError> {
+    set(&app, &key, value)
+}
+
+/// 单项设置与默认值的差异
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingDiff {
+    pub key: String,
+    pub current: Value,
+    pub default: Value,
+}
+
+/// 把单个设置恢复为默认值
+#[tauri::command]
+pub fn reset_setting(app: AppHandle, key: String) -> Result<Value, ValidationError> {
+    let constraint = validation::constraint_for(&key).ok_or_else(|| ValidationError {
+        key: key.clone(),
+        code: "unknownKey".into(),
+        message: format!("未知设置项: {}", key),
+    })?;
+    if let Ok(mut cache) = CACHE.write() {
+        cache.remove(&key);
+    }
+    if let Err(e) = persist(&app) {
+        log::error!("[Settings] failed to persist after reset: {}", e);
+    }
+    log::info!("[Settings] reset '{}' to default", key);
+    Ok((constraint.default)())
+}
+
+/// 返回所有与默认值不同的设置，供 UI 显示"已修改"角标与定点重置
+#[tauri::command]
+pub fn get_settings_diff() -> Vec<SettingDiff> {
+    let cache = match CACHE.read() {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+    validation::CONSTRAINTS
+        .iter()
+        .filter_map(|c| {
+            // 策略锁定的键以强制值为准，与 get() 一致
+            let current = match crate::services::policy::locked_value(c.key) {
+                Some(locked) => locked.clone(),
+                None => cache.get(c.key)?.clone(),
+            };
+            let default = (c.default)();
+            (current != default).then(|| SettingDiff {
+                key: c.key.to_string(),
+                current,
+                default,
+            })
+        })
+        .collect()
+}
+
+/// 读取全部设置（声明过的键都有值）
+#[tauri::command]
+pub fn get_all_settings() -> HashMap<String, Value> {
+    validation::CONSTRAINTS
+        .iter()
+        .map(|c| {
+            // 与 get() 相同的优先级：锁定值 > 用户值 > 默认值
+            let value = crate::services::policy::locked_value(c.key)
+                .cloned()
+                .or_else(|| CACHE.read().ok().and_then(|cache| cache.get(c.key).cloned()))
+                .unwrap_or_else(|| (c.default)());
+            (c.key.to_string(), value)
+        })
+        .collect()
+}
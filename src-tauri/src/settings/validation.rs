@@ -0,0 +1,195 @@
+//! 应用设置的写入校验
+//!
+//! 每个设置项声明类型与约束（数值范围、枚举可选值）。
+//! `set_setting` 写入前校验，违例返回结构化错误而不是静默接受——
+//! 之前 `window_opacity` 被写成 0 之类的值会直接弄坏下游消费者。
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// 单项设置的约束声明
+#[derive(Debug, Clone)]
+pub struct SettingConstraint {
+    pub key: &'static str,
+    pub kind: ConstraintKind,
+    pub default: fn() -> Value,
+}
+
+#[derive(Debug, Clone)]
+pub enum ConstraintKind {
+    /// 浮点范围（含端点）
+    FloatRange { min: f64, max: f64 },
+    /// 整数范围（含端点）
+    IntRange { min: i64, max: i64 },
+    /// 枚举可选值
+    Enum(&'static [&'static str]),
+    Bool,
+    /// 任意字符串（长度上限）
+    String { max_len: usize },
+}
+
+/// 结构化校验错误，前端可直接定位到具体字段
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationError {
+    pub key: String,
+    /// "outOfRange" / "invalidEnum" / "wrongType" / "unknownKey" / "tooLong"
+    pub code: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(key: &str, code: &str, message: String) -> Self {
+        Self {
+            key: key.to_string(),
+            code: code.to_string(),
+            message,
+        }
+    }
+}
+
+/// 全部已知设置及其约束。新增设置必须在这里登记
+pub const CONSTRAINTS: &[SettingConstraint] = &[
+    SettingConstraint {
+        key: "window_opacity",
+        kind: ConstraintKind::FloatRange { min: 0.1, max: 1.0 },
+        default: || Value::from(1.0),
+    },
+    SettingConstraint {
+        key: "max_results",
+        kind: ConstraintKind::IntRange { min: 1, max: 100 },
+        default: || Value::from(30),
+    },
+    SettingConstraint {
+        key: "search_debounce_ms",
+        kind: ConstraintKind::IntRange { min: 0, max: 1000 },
+        default: || Value::from(120),
+    },
+    SettingConstraint {
+        key: "clipboard_history_limit",
+        kind: ConstraintKind::IntRange { min: 10, max: 10000 },
+        default: || Value::from(500),
+    },
+    SettingConstraint {
+        key: "theme",
+        kind: ConstraintKind::Enum(&["system", "light", "dark"]),
+        default: || Value::from("system"),
+    },
+    SettingConstraint {
+        key: "language",
+        kind: ConstraintKind::Enum(&["zh-CN", "en-US"]),
+        default: || Value::from("zh-CN"),
+    },
+    SettingConstraint {
+        key: "launch_at_login",
+        kind: ConstraintKind::Bool,
+        default: || Value::from(false),
+    },
+    SettingConstraint {
+        key: "show_tray_icon",
+        kind: ConstraintKind::Bool,
+        default: || Value::from(true),
+    },
+    SettingConstraint {
+        key: "developer_integrations_enabled",
+        kind: ConstraintKind::Bool,
+        default: || Value::from(false),
+    },
+    SettingConstraint {
+        key: "destructive_system_commands_enabled",
+        kind: ConstraintKind::Bool,
+        default: || Value::from(false),
+    },
+    SettingConstraint {
+        key: "credential_items_enabled",
+        kind: ConstraintKind::Bool,
+        default: || Value::from(false),
+    },
+    SettingConstraint {
+        key: "terminal_app",
+        kind: ConstraintKind::String { max_len: 64 },
+        default: || Value::from(""),
+    },
+    SettingConstraint {
+        key: "hotkey",
+        kind: ConstraintKind::String { max_len: 64 },
+        default: || Value::from("Cmd+Shift+K"),
+    },
+];
+
+/// 查找设置的约束声明
+pub fn constraint_for(key: &str) -> Option<&'static SettingConstraint> {
+    CONSTRAINTS.iter().find(|c| c.key == key)
+}
+
+/// 校验一个待写入的值
+pub fn validate(key: &str, value: &Value) -> Result<(), ValidationError> {
+    let Some(constraint) = constraint_for(key) else {
+        return Err(ValidationError::new(
+            key,
+            "unknownKey",
+            format!("未知设置项: {}", key),
+        ));
+    };
+
+    match &constraint.kind {
+        ConstraintKind::FloatRange { min, max } => {
+            let n = value.as_f64().ok_or_else(|| {
+                ValidationError::new(key, "wrongType", format!("{} 需要数字", key))
+            })?;
+            if n < *min || n > *max {
+                return Err(ValidationError::new(
+                    key,
+                    "outOfRange",
+                    format!("{} 必须在 {} 到 {} 之间（收到 {}）", key, min, max, n),
+                ));
+            }
+        }
+        ConstraintKind::IntRange { min, max } => {
+            let n = value.as_i64().ok_or_else(|| {
+                ValidationError::new(key, "wrongType", format!("{} 需要整数", key))
+            })?;
+            if n < *min || n > *max {
+                return Err(ValidationError::new(
+                    key,
+                    "outOfRange",
+                    format!("{} 必须在 {} 到 {} 之间（收到 {}）", key, min, max, n),
+                ));
+            }
+        }
+        ConstraintKind::Enum(options) => {
+            let s = value.as_str().ok_or_else(|| {
+                ValidationError::new(key, "wrongType", format!("{} 需要字符串", key))
+            })?;
+            if !options.contains(&s) {
+                return Err(ValidationError::new(
+                    key,
+                    "invalidEnum",
+                    format!("{} 的值 '{}' 不在可选项 [{}] 中", key, s, options.join(", ")),
+                ));
+            }
+        }
+        ConstraintKind::Bool => {
+            if !value.is_boolean() {
+                return Err(ValidationError::new(
+                    key,
+                    "wrongType",
+                    format!("{} 需要布尔值", key),
+                ));
+            }
+        }
+        ConstraintKind::String { max_len } => {
+            let s = value.as_str().ok_or_else(|| {
+                ValidationError::new(key, "wrongType", format!("{} 需要字符串", key))
+            })?;
+            if s.len() > *max_len {
+                return Err(ValidationError::new(
+                    key,
+                    "tooLong",
+                    format!("{} 长度不能超过 {}", key, max_len),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! 窗口背景材质（毛玻璃/亚克力）
+//!
+//! macOS 用 NSVisualEffectView vibrancy，Windows 用 Mica/Acrylic，
+//! 随主题可选。`set_window_effect` 切换材质，Linux 退化为纯色背景。
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+/// 可选材质；前端主题设置里下拉选择
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WindowEffect {
+    /// 不使用材质（纯色背景）
+    None,
+    /// macOS vibrancy / Windows Acrylic
+    Blur,
+    /// Windows 11 Mica；macOS 上等同 Blur
+    Mica,
+}
+
+#[cfg(target_os = "macos")]
+fn apply(window: &tauri::WebviewWindow, effect: WindowEffect) -> Result<(), String> {
+    use window_vibrancy::{apply_vibrancy, clear_vibrancy, NSVisualEffectMaterial};
+    match effect {
+        WindowEffect::None => clear_vibrancy(window).map_err(|e| e.to_string()),
+        WindowEffect::Blur | WindowEffect::Mica => apply_vibrancy(
+            window,
+            NSVisualEffectMaterial::HudWindow,
+            None,
+            // 跟随窗口圆角
+            Some(12.0),
+        )
+        .map_err(|e| e.to_string()),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn apply(window: &tauri::WebviewWindow, effect: WindowEffect) -> Result<(), String> {
+    use window_vibrancy::{apply_acrylic, apply_mica, clear_acrylic, clear_mica};
+    match effect {
+        WindowEffect::None => {
+            let _ = clear_acrylic(window);
+            let _ = clear_mica(window);
+            Ok(())
+        }
+        WindowEffect::Blur => apply_acrylic(window, Some((18, 18, 18, 125))).map_err(|e| e.to_string()),
+        WindowEffect::Mica => apply_mica(window, None).map_err(|e| e.to_string()),
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn apply(_window: &tauri::WebviewWindow, effect: WindowEffect) -> Result<(), String> {
+    // Linux 各合成器能力不一，统一退化为纯色背景由前端兜底
+    if effect != WindowEffect::None {
+        log::info!("[WindowEffect] vibrancy not supported on this platform, ignored");
+    }
+    Ok(())
+}
+
+/// 设置主窗口背景材质
+#[tauri::command]
+pub fn set_window_effect(app: AppHandle, effect: WindowEffect) -> Result<(), String> {
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "主窗口不存在".to_string())?;
+    apply(&window, effect)?;
+    log::info!("[WindowEffect] applied {:?}", effect);
+    Ok(())
+}
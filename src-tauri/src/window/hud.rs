@@ -0,0 +1,86 @@
+//! HUD 提示窗口
+//!
+//! `show_hud(text, icon, duration)` 弹出一个短暂的确认提示
+//! （"已复制"、"片段已粘贴"、"插件已启用"），不依赖主窗口状态，
+//! 到时自动消失。连续调用会重置计时并更新内容。
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, LogicalPosition, LogicalSize, Manager, WebviewUrl, WebviewWindowBuilder};
+
+pub const HUD_LABEL: &str = "hud";
+/// HUD 内容更新事件，HUD webview 监听
+pub const HUD_CONTENT_EVENT: &str = "hud://content";
+const HUD_WIDTH: f64 = 240.0;
+const HUD_HEIGHT: f64 = 72.0;
+const DEFAULT_DURATION_MS: u64 = 1500;
+const MAX_DURATION_MS: u64 = 10_000;
+
+/// 显示请求代数；auto-dismiss 只隐藏属于自己那一代的 HUD
+static SHOW_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+fn ensure_window(app: &AppHandle) -> Result<tauri::WebviewWindow, String> {
+    if let Some(existing) = app.get_webview_window(HUD_LABEL) {
+        return Ok(existing);
+    }
+    WebviewWindowBuilder::new(app, HUD_LABEL, WebviewUrl::App("hud.html".into()))
+        .inner_size(HUD_WIDTH, HUD_HEIGHT)
+        .decorations(false)
+        .resizable(false)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .transparent(true)
+        .focused(false)
+        .visible(false)
+        .build()
+        .map_err(|e| format!("创建 HUD 窗口失败: {}", e))
+}
+
+/// 显示 HUD 提示；`duration_ms` 缺省 1.5 秒
+#[tauri::command]
+pub async fn show_hud(
+    app: AppHandle,
+    text: String,
+    icon: Option<String>,
+    duration_ms: Option<u64>,
+) -> Result<(), String> {
+    let duration = duration_ms.unwrap_or(DEFAULT_DURATION_MS).min(MAX_DURATION_MS);
+    let window = ensure_window(&app)?;
+
+    // 固定显示在当前显示器底部居中
+    if let Ok(Some(monitor)) = window.current_monitor() {
+        let scale = monitor.scale_factor();
+        let size = monitor.size().to_logical::<f64>(scale);
+        let pos = monitor.position().to_logical::<f64>(scale);
+        window
+            .set_position(LogicalPosition::new(
+                pos.x + (size.width - HUD_WIDTH) / 2.0,
+                pos.y + size.height - HUD_HEIGHT - 120.0,
+            ))
+            .map_err(|e| e.to_string())?;
+    }
+    window
+        .set_size(LogicalSize::new(HUD_WIDTH, HUD_HEIGHT))
+        .map_err(|e| e.to_string())?;
+
+    app.emit(
+        HUD_CONTENT_EVENT,
+        serde_json::json!({ "text": text, "icon": icon }),
+    )
+    .map_err(|e| e.to_string())?;
+    window.show().map_err(|e| e.to_string())?;
+
+    // 自动消失；后来的 show 调用会让旧的定时器失效
+    let generation = SHOW_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(duration)).await;
+        if SHOW_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        if let Some(window) = app_clone.get_webview_window(HUD_LABEL) {
+            let _ = window.hide();
+        }
+    });
+    Ok(())
+}
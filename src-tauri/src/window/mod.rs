@@ -0,0 +1,5 @@
+pub mod effects;
+pub mod hud;
+pub mod quick_paste;
+pub mod screen_events;
+pub mod work_area;
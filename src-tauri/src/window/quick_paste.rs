@@ -0,0 +1,87 @@
+//! 快速粘贴窗口
+//!
+//! 独立于主窗口的轻量剪贴板选择器（单独 webview），有自己的快捷键，
+//! 在光标附近弹出，选中即粘贴——不用唤起完整启动器。
+//! 本模块负责它的生命周期与定位。
+
+use tauri::{AppHandle, LogicalPosition, LogicalSize, Manager, WebviewUrl, WebviewWindowBuilder};
+
+/// 窗口 label；整个应用只保留一个实例，重复召唤只重新定位
+pub const QUICK_PASTE_LABEL: &str = "quick-paste";
+const WINDOW_WIDTH: f64 = 360.0;
+const WINDOW_HEIGHT: f64 = 420.0;
+/// 与光标的偏移，避免正好盖住正在输入的位置
+const CURSOR_OFFSET: f64 = 12.0;
+
+/// 创建（或复用）快速粘贴窗口并显示在指定位置附近。
+/// `cursor_x`/`cursor_y` 为屏幕逻辑坐标，由前端或平台光标 API 提供。
+#[tauri::command]
+pub async fn show_quick_paste(app: AppHandle, cursor_x: f64, cursor_y: f64) -> Result<(), String> {
+    let window = match app.get_webview_window(QUICK_PASTE_LABEL) {
+        Some(existing) => existing,
+        None => WebviewWindowBuilder::new(
+            &app,
+            QUICK_PASTE_LABEL,
+            WebviewUrl::App("quick-paste.html".into()),
+        )
+        .title("快速粘贴")
+        .inner_size(WINDOW_WIDTH, WINDOW_HEIGHT)
+        .decorations(false)
+        .resizable(false)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .visible(false)
+        .build()
+        .map_err(|e| format!("创建快速粘贴窗口失败: {}", e))?,
+    };
+
+    // 光标附近定位，并夹取到当前显示器工作区内
+    let (mut x, mut y) = (cursor_x + CURSOR_OFFSET, cursor_y + CURSOR_OFFSET);
+    if let Ok(Some(monitor)) = window.current_monitor() {
+        let scale = monitor.scale_factor();
+        let size = monitor.size().to_logical::<f64>(scale);
+        let pos = monitor.position().to_logical::<f64>(scale);
+        x = x.min(pos.x + size.width - WINDOW_WIDTH).max(pos.x);
+        y = y.min(pos.y + size.height - WINDOW_HEIGHT).max(pos.y);
+    }
+    window
+        .set_position(LogicalPosition::new(x, y))
+        .map_err(|e| e.to_string())?;
+    window
+        .set_size(LogicalSize::new(WINDOW_WIDTH, WINDOW_HEIGHT))
+        .map_err(|e| e.to_string())?;
+    window.show().map_err(|e| e.to_string())?;
+    window.set_focus().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 隐藏快速粘贴窗口（选择完成或 Esc）。隐藏而非销毁，下次召唤更快
+#[tauri::command]
+pub fn hide_quick_paste(app: AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(QUICK_PASTE_LABEL) {
+        window.hide().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// 注册快速粘贴的专属快捷键回调（由启动流程调用）
+pub fn on_hotkey(app: &AppHandle) {
+    // 光标位置由前端在 webview 内获取不到屏幕坐标，这里退化为
+    // 主显示器中心；平台光标 API 接入后可传入真实坐标
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let (x, y) = app
+            .primary_monitor()
+            .ok()
+            .flatten()
+            .map(|m| {
+                let scale = m.scale_factor();
+                let size = m.size().to_logical::<f64>(scale);
+                (size.width / 2.0 - WINDOW_WIDTH / 2.0, size.height / 3.0)
+            })
+            .unwrap_or((200.0, 200.0));
+        if let Err(e) = show_quick_paste(app, x, y).await {
+            log::error!("[QuickPaste] failed to show window: {}", e);
+        }
+    });
+}
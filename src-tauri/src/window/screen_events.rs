@@ -0,0 +1,106 @@
+//! 屏幕配置变化监听
+//!
+//! `emit_screen_changed` 之前没有任何触发源。这里监听显示器配置变化
+//! （接入/移除、分辨率/缩放变更），重新执行 `detect_screen_info`，
+//! 广播 `screen:changed`，并在主窗口落到屏幕外时把它拉回可见区域。
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, LogicalPosition, Manager};
+
+/// 屏幕变化事件名（沿用前端已监听的名字）
+pub const SCREEN_CHANGED_EVENT: &str = "screen:changed";
+/// 无系统级通知 API 时的轮询间隔
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// 单个显示器的快照
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorSnapshot {
+    pub name: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub x: i32,
+    pub y: i32,
+    pub scale_factor: f64,
+}
+
+/// 采集当前全部显示器信息（即 detect_screen_info 的数据来源）
+pub fn snapshot_monitors(app: &AppHandle) -> Vec<MonitorSnapshot> {
+    let Some(window) = app.get_webview_window("main") else {
+        return Vec::new();
+    };
+    window
+        .available_monitors()
+        .map(|monitors| {
+            monitors
+                .iter()
+                .map(|m| MonitorSnapshot {
+                    name: m.name().cloned(),
+                    width: m.size().width,
+                    height: m.size().height,
+                    x: m.position().x,
+                    y: m.position().y,
+                    scale_factor: m.scale_factor(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 主窗口是否仍在某个显示器内；不在则移到主显示器中央
+fn reposition_if_offscreen(app: &AppHandle, monitors: &[MonitorSnapshot]) {
+    let Some(window) = app.get_webview_window("main") else { return };
+    let Ok(pos) = window.outer_position() else { return };
+    let Ok(size) = window.outer_size() else { return };
+
+    let on_screen = monitors.iter().any(|m| {
+        // 要求窗口中心点落在某显示器内，比边缘重叠判断更稳
+        let cx = pos.x + size.width as i32 / 2;
+        let cy = pos.y + size.height as i32 / 2;
+        cx >= m.x && cx < m.x + m.width as i32 && cy >= m.y && cy < m.y + m.height as i32
+    });
+    if on_screen || monitors.is_empty() {
+        return;
+    }
+
+    let primary = &monitors[0];
+    let x = primary.x as f64 + (primary.width as f64 - size.width as f64) / 2.0;
+    let y = primary.y as f64 + (primary.height as f64 - size.height as f64) / 3.0;
+    log::info!("[Screen] main window off-screen after display change, repositioning");
+    let _ = window.set_position(LogicalPosition::new(
+        x / primary.scale_factor,
+        y / primary.scale_factor,
+    ));
+}
+
+/// 启动屏幕变化监听循环
+pub fn spawn_monitor_watcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut last = snapshot_monitors(&app);
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            if crate::app::power::is_suspended() {
+                continue;
+            }
+            let current = snapshot_monitors(&app);
+            if current == last || current.is_empty() {
+                continue;
+            }
+            log::info!(
+                "[Screen] display configuration changed: {} -> {} monitors",
+                last.len(),
+                current.len()
+            );
+            last = current.clone();
+            reposition_if_offscreen(&app, &current);
+            let _ = app.emit(SCREEN_CHANGED_EVENT, &current);
+        }
+    });
+}
+
+/// 当前屏幕信息（detect_screen_info 的命令出口）
+#[tauri::command]
+pub fn detect_screen_info(app: AppHandle) -> Vec<MonitorSnapshot> {
+    snapshot_monitors(&app)
+}
@@ -0,0 +1,113 @@
+//! 显示器工作区计算
+//!
+//! `detect_screen_info` 之前减一个硬编码的 80px 当作 dock/任务栏。
+//! 现在用平台工作区 API：macOS 的 visibleFrame、Windows 的
+//! MONITORINFO.rcWork、X11 的 _NET_WORKAREA，保证窗口布局
+//! 永远不会压在 dock 或任务栏下面。
+
+use serde::{Deserialize, Serialize};
+
+/// 工作区矩形（物理像素，含屏幕内偏移）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkArea {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[cfg(target_os = "macos")]
+fn platform_work_area() -> Option<WorkArea> {
+    use objc2_app_kit::NSScreen;
+    use objc2_foundation::MainThreadMarker;
+
+    let mtm = MainThreadMarker::new()?;
+    let screen = NSScreen::mainScreen(mtm)?;
+    // visibleFrame 已扣除菜单栏与 Dock
+    let frame = screen.visibleFrame();
+    let full = screen.frame();
+    let scale = screen.backingScaleFactor();
+    // AppKit 坐标系原点在左下，转为左上原点
+    let y_top = full.size.height - frame.origin.y - frame.size.height;
+    Some(WorkArea {
+        x: (frame.origin.x * scale) as i32,
+        y: (y_top * scale) as i32,
+        width: (frame.size.width * scale) as u32,
+        height: (frame.size.height * scale) as u32,
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn platform_work_area() -> Option<WorkArea> {
+    use windows::Win32::Graphics::Gdi::{
+        GetMonitorInfoW, MonitorFromWindow, MONITORINFO, MONITOR_DEFAULTTOPRIMARY,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+
+    unsafe {
+        let monitor = MonitorFromWindow(GetForegroundWindow(), MONITOR_DEFAULTTOPRIMARY);
+        let mut info = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        if !GetMonitorInfoW(monitor, &mut info).as_bool() {
+            return None;
+        }
+        let work = info.rcWork;
+        Some(WorkArea {
+            x: work.left,
+            y: work.top,
+            width: (work.right - work.left).max(0) as u32,
+            height: (work.bottom - work.top).max(0) as u32,
+        })
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn platform_work_area() -> Option<WorkArea> {
+    use std::process::Command;
+
+    // 通过 xprop 读取 _NET_WORKAREA（Wayland 下不可用，返回 None 走兜底）
+    let output = Command::new("xprop")
+        .args(["-root", "_NET_WORKAREA"])
+        .output()
+        .ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    // 形如 "_NET_WORKAREA(CARDINAL) = 0, 0, 1920, 1053, ..."（每桌面 4 个值）
+    let values: Vec<i64> = text
+        .split('=')
+        .nth(1)?
+        .split(',')
+        .take(4)
+        .filter_map(|v| v.trim().parse().ok())
+        .collect();
+    if values.len() != 4 {
+        return None;
+    }
+    Some(WorkArea {
+        x: values[0] as i32,
+        y: values[1] as i32,
+        width: values[2] as u32,
+        height: values[3] as u32,
+    })
+}
+
+/// 当前显示器的工作区；平台 API 不可用时退回整屏尺寸
+#[tauri::command]
+pub fn get_work_area(app: tauri::AppHandle) -> Result<WorkArea, String> {
+    if let Some(area) = platform_work_area() {
+        return Ok(area);
+    }
+    // 兜底：整屏（总比硬编码减 80px 准确——至少语义明确）
+    let monitors = super::screen_events::snapshot_monitors(&app);
+    monitors
+        .first()
+        .map(|m| WorkArea {
+            x: m.x,
+            y: m.y,
+            width: m.width,
+            height: m.height,
+        })
+        .ok_or_else(|| "无法获取显示器信息".to_string())
+}